@@ -30,6 +30,7 @@ use protobuf::ProtobufEnum as ProtobufEnum_imported_for_functions;
 pub struct GetRequest {
     // message fields
     pub key: ::std::vec::Vec<u8>,
+    pub read_ts: u64,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -71,6 +72,21 @@ impl GetRequest {
     pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
         ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
     }
+
+    // uint64 read_ts = 2;
+
+
+    pub fn get_read_ts(&self) -> u64 {
+        self.read_ts
+    }
+    pub fn clear_read_ts(&mut self) {
+        self.read_ts = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_read_ts(&mut self, v: u64) {
+        self.read_ts = v;
+    }
 }
 
 impl ::protobuf::Message for GetRequest {
@@ -85,6 +101,13 @@ impl ::protobuf::Message for GetRequest {
                 1 => {
                     ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
                 },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.read_ts = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -100,6 +123,9 @@ impl ::protobuf::Message for GetRequest {
         if !self.key.is_empty() {
             my_size += ::protobuf::rt::bytes_size(1, &self.key);
         }
+        if self.read_ts != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.read_ts, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -109,6 +135,9 @@ impl ::protobuf::Message for GetRequest {
         if !self.key.is_empty() {
             os.write_bytes(1, &self.key)?;
         }
+        if self.read_ts != 0 {
+            os.write_uint64(2, self.read_ts)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -153,6 +182,11 @@ impl ::protobuf::Message for GetRequest {
                     |m: &GetRequest| { &m.key },
                     |m: &mut GetRequest| { &mut m.key },
                 ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "read_ts",
+                    |m: &GetRequest| { &m.read_ts },
+                    |m: &mut GetRequest| { &mut m.read_ts },
+                ));
                 ::protobuf::reflect::MessageDescriptor::new_pb_name::<GetRequest>(
                     "GetRequest",
                     fields,
@@ -173,6 +207,7 @@ impl ::protobuf::Message for GetRequest {
 impl ::protobuf::Clear for GetRequest {
     fn clear(&mut self) {
         self.key.clear();
+        self.read_ts = 0;
         self.unknown_fields.clear();
     }
 }
@@ -195,6 +230,8 @@ pub struct GetResponse {
     pub success: bool,
     pub errorMessage: ::std::string::String,
     pub value: ::std::vec::Vec<u8>,
+    pub version: u64,
+    pub read_ts: u64,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -277,6 +314,36 @@ impl GetResponse {
     pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
         ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
     }
+
+    // uint64 version = 4;
+
+
+    pub fn get_version(&self) -> u64 {
+        self.version
+    }
+    pub fn clear_version(&mut self) {
+        self.version = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_version(&mut self, v: u64) {
+        self.version = v;
+    }
+
+    // uint64 read_ts = 5;
+
+
+    pub fn get_read_ts(&self) -> u64 {
+        self.read_ts
+    }
+    pub fn clear_read_ts(&mut self) {
+        self.read_ts = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_read_ts(&mut self, v: u64) {
+        self.read_ts = v;
+    }
 }
 
 impl ::protobuf::Message for GetResponse {
@@ -301,6 +368,20 @@ impl ::protobuf::Message for GetResponse {
                 3 => {
                     ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
                 },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.version = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.read_ts = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -322,6 +403,12 @@ impl ::protobuf::Message for GetResponse {
         if !self.value.is_empty() {
             my_size += ::protobuf::rt::bytes_size(3, &self.value);
         }
+        if self.version != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.version, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.read_ts != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.read_ts, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -337,6 +424,12 @@ impl ::protobuf::Message for GetResponse {
         if !self.value.is_empty() {
             os.write_bytes(3, &self.value)?;
         }
+        if self.version != 0 {
+            os.write_uint64(4, self.version)?;
+        }
+        if self.read_ts != 0 {
+            os.write_uint64(5, self.read_ts)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -391,6 +484,16 @@ impl ::protobuf::Message for GetResponse {
                     |m: &GetResponse| { &m.value },
                     |m: &mut GetResponse| { &mut m.value },
                 ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "version",
+                    |m: &GetResponse| { &m.version },
+                    |m: &mut GetResponse| { &mut m.version },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "read_ts",
+                    |m: &GetResponse| { &m.read_ts },
+                    |m: &mut GetResponse| { &mut m.read_ts },
+                ));
                 ::protobuf::reflect::MessageDescriptor::new_pb_name::<GetResponse>(
                     "GetResponse",
                     fields,
@@ -413,6 +516,8 @@ impl ::protobuf::Clear for GetResponse {
         self.success = false;
         self.errorMessage.clear();
         self.value.clear();
+        self.version = 0;
+        self.read_ts = 0;
         self.unknown_fields.clear();
     }
 }
@@ -430,50 +535,40 @@ impl ::protobuf::reflect::ProtobufValue for GetResponse {
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct PutRequest {
+pub struct Condition {
     // message fields
-    pub key: ::std::vec::Vec<u8>,
+    pub kind: Condition_Kind,
     pub value: ::std::vec::Vec<u8>,
+    pub version: u64,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a PutRequest {
-    fn default() -> &'a PutRequest {
-        <PutRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a Condition {
+    fn default() -> &'a Condition {
+        <Condition as ::protobuf::Message>::default_instance()
     }
 }
 
-impl PutRequest {
-    pub fn new() -> PutRequest {
+impl Condition {
+    pub fn new() -> Condition {
         ::std::default::Default::default()
     }
 
-    // bytes key = 1;
+    // .Condition.Kind kind = 1;
 
 
-    pub fn get_key(&self) -> &[u8] {
-        &self.key
+    pub fn get_kind(&self) -> Condition_Kind {
+        self.kind
     }
-    pub fn clear_key(&mut self) {
-        self.key.clear();
+    pub fn clear_kind(&mut self) {
+        self.kind = Condition_Kind::NONE;
     }
 
     // Param is passed by value, moved
-    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
-        self.key = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.key
-    }
-
-    // Take field
-    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    pub fn set_kind(&mut self, v: Condition_Kind) {
+        self.kind = v;
     }
 
     // bytes value = 2;
@@ -501,9 +596,24 @@ impl PutRequest {
     pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
         ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
     }
+
+    // uint64 version = 3;
+
+
+    pub fn get_version(&self) -> u64 {
+        self.version
+    }
+    pub fn clear_version(&mut self) {
+        self.version = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_version(&mut self, v: u64) {
+        self.version = v;
+    }
 }
 
-impl ::protobuf::Message for PutRequest {
+impl ::protobuf::Message for Condition {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -513,11 +623,18 @@ impl ::protobuf::Message for PutRequest {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.kind, 1, &mut self.unknown_fields)?
                 },
                 2 => {
                     ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
                 },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.version = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -530,24 +647,30 @@ impl ::protobuf::Message for PutRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.key.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.key);
+        if self.kind != Condition_Kind::NONE {
+            my_size += ::protobuf::rt::enum_size(1, self.kind);
         }
         if !self.value.is_empty() {
             my_size += ::protobuf::rt::bytes_size(2, &self.value);
         }
+        if self.version != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.version, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if !self.key.is_empty() {
-            os.write_bytes(1, &self.key)?;
+        if self.kind != Condition_Kind::NONE {
+            os.write_enum(1, self.kind.value())?;
         }
         if !self.value.is_empty() {
             os.write_bytes(2, &self.value)?;
         }
+        if self.version != 0 {
+            os.write_uint64(3, self.version)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -578,8 +701,8 @@ impl ::protobuf::Message for PutRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> PutRequest {
-        PutRequest::new()
+    fn new() -> Condition {
+        Condition::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -587,18 +710,23 @@ impl ::protobuf::Message for PutRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "key",
-                    |m: &PutRequest| { &m.key },
-                    |m: &mut PutRequest| { &mut m.key },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Condition_Kind>>(
+                    "kind",
+                    |m: &Condition| { &m.kind },
+                    |m: &mut Condition| { &mut m.kind },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
                     "value",
-                    |m: &PutRequest| { &m.value },
-                    |m: &mut PutRequest| { &mut m.value },
+                    |m: &Condition| { &m.value },
+                    |m: &mut Condition| { &mut m.value },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new_pb_name::<PutRequest>(
-                    "PutRequest",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "version",
+                    |m: &Condition| { &m.version },
+                    |m: &mut Condition| { &mut m.version },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<Condition>(
+                    "Condition",
                     fields,
                     file_descriptor_proto()
                 )
@@ -606,99 +734,224 @@ impl ::protobuf::Message for PutRequest {
         }
     }
 
-    fn default_instance() -> &'static PutRequest {
-        static mut instance: ::protobuf::lazy::Lazy<PutRequest> = ::protobuf::lazy::Lazy::INIT;
+    fn default_instance() -> &'static Condition {
+        static mut instance: ::protobuf::lazy::Lazy<Condition> = ::protobuf::lazy::Lazy::INIT;
         unsafe {
-            instance.get(PutRequest::new)
+            instance.get(Condition::new)
         }
     }
 }
 
-impl ::protobuf::Clear for PutRequest {
+impl ::protobuf::Clear for Condition {
     fn clear(&mut self) {
-        self.key.clear();
+        self.kind = Condition_Kind::NONE;
         self.value.clear();
+        self.version = 0;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for PutRequest {
+impl ::std::fmt::Debug for Condition {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for PutRequest {
+impl ::protobuf::reflect::ProtobufValue for Condition {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum Condition_Kind {
+    NONE = 0,
+    ABSENT = 1,
+    EQUAL = 2,
+    VERSION = 3,
+}
+
+impl ::protobuf::ProtobufEnum for Condition_Kind {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Condition_Kind> {
+        match value {
+            0 => ::std::option::Option::Some(Condition_Kind::NONE),
+            1 => ::std::option::Option::Some(Condition_Kind::ABSENT),
+            2 => ::std::option::Option::Some(Condition_Kind::EQUAL),
+            3 => ::std::option::Option::Some(Condition_Kind::VERSION),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [Condition_Kind] = &[
+            Condition_Kind::NONE,
+            Condition_Kind::ABSENT,
+            Condition_Kind::EQUAL,
+            Condition_Kind::VERSION,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new_pb_name::<Condition_Kind>("Condition.Kind", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for Condition_Kind {
+}
+
+impl ::std::default::Default for Condition_Kind {
+    fn default() -> Self {
+        Condition_Kind::NONE
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Condition_Kind {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(self.descriptor())
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
-pub struct PutResponse {
+pub struct PutRequest {
     // message fields
-    pub success: bool,
-    pub errorMessage: ::std::string::String,
+    pub key: ::std::vec::Vec<u8>,
+    pub value: ::std::vec::Vec<u8>,
+    pub condition: ::protobuf::SingularPtrField<Condition>,
+    pub ttl_ms: u64,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a PutResponse {
-    fn default() -> &'a PutResponse {
-        <PutResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a PutRequest {
+    fn default() -> &'a PutRequest {
+        <PutRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl PutResponse {
-    pub fn new() -> PutResponse {
+impl PutRequest {
+    pub fn new() -> PutRequest {
         ::std::default::Default::default()
     }
 
-    // bool success = 1;
+    // bytes key = 1;
 
 
-    pub fn get_success(&self) -> bool {
-        self.success
+    pub fn get_key(&self) -> &[u8] {
+        &self.key
     }
-    pub fn clear_success(&mut self) {
-        self.success = false;
+    pub fn clear_key(&mut self) {
+        self.key.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_success(&mut self, v: bool) {
-        self.success = v;
+    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key = v;
     }
 
-    // string errorMessage = 2;
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key
+    }
 
+    // Take field
+    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    }
 
-    pub fn get_errorMessage(&self) -> &str {
-        &self.errorMessage
+    // bytes value = 2;
+
+
+    pub fn get_value(&self) -> &[u8] {
+        &self.value
     }
-    pub fn clear_errorMessage(&mut self) {
-        self.errorMessage.clear();
+    pub fn clear_value(&mut self) {
+        self.value.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_errorMessage(&mut self, v: ::std::string::String) {
-        self.errorMessage = v;
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_errorMessage(&mut self) -> &mut ::std::string::String {
-        &mut self.errorMessage
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.value
     }
 
     // Take field
-    pub fn take_errorMessage(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.errorMessage, ::std::string::String::new())
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    }
+
+    // .Condition condition = 3;
+
+
+    pub fn get_condition(&self) -> &Condition {
+        self.condition.as_ref().unwrap_or_else(|| Condition::default_instance())
+    }
+    pub fn clear_condition(&mut self) {
+        self.condition.clear();
+    }
+
+    pub fn has_condition(&self) -> bool {
+        self.condition.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_condition(&mut self, v: Condition) {
+        self.condition = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_condition(&mut self) -> &mut Condition {
+        if self.condition.is_none() {
+            self.condition.set_default();
+        }
+        self.condition.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_condition(&mut self) -> Condition {
+        self.condition.take().unwrap_or_else(|| Condition::new())
+    }
+
+    // uint64 ttl_ms = 4;
+
+
+    pub fn get_ttl_ms(&self) -> u64 {
+        self.ttl_ms
+    }
+    pub fn clear_ttl_ms(&mut self) {
+        self.ttl_ms = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ttl_ms(&mut self, v: u64) {
+        self.ttl_ms = v;
     }
 }
 
-impl ::protobuf::Message for PutResponse {
+impl ::protobuf::Message for PutRequest {
     fn is_initialized(&self) -> bool {
+        for v in &self.condition {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -707,14 +960,20 @@ impl ::protobuf::Message for PutResponse {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.condition)?;
+                },
+                4 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_bool()?;
-                    self.success = tmp;
-                },
-                2 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.errorMessage)?;
+                    let tmp = is.read_uint64()?;
+                    self.ttl_ms = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -728,11 +987,10238 @@ impl ::protobuf::Message for PutResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.success != false {
-            my_size += 2;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.key);
         }
-        if !self.errorMessage.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.errorMessage);
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.value);
+        }
+        if let Some(ref v) = self.condition.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.ttl_ms != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.ttl_ms, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_bytes(1, &self.key)?;
+        }
+        if !self.value.is_empty() {
+            os.write_bytes(2, &self.value)?;
+        }
+        if let Some(ref v) = self.condition.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.ttl_ms != 0 {
+            os.write_uint64(4, self.ttl_ms)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PutRequest {
+        PutRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "key",
+                    |m: &PutRequest| { &m.key },
+                    |m: &mut PutRequest| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "value",
+                    |m: &PutRequest| { &m.value },
+                    |m: &mut PutRequest| { &mut m.value },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Condition>>(
+                    "condition",
+                    |m: &PutRequest| { &m.condition },
+                    |m: &mut PutRequest| { &mut m.condition },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "ttl_ms",
+                    |m: &PutRequest| { &m.ttl_ms },
+                    |m: &mut PutRequest| { &mut m.ttl_ms },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<PutRequest>(
+                    "PutRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static PutRequest {
+        static mut instance: ::protobuf::lazy::Lazy<PutRequest> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(PutRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for PutRequest {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.value.clear();
+        self.condition.clear();
+        self.ttl_ms = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PutRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PutRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PutResponse {
+    // message fields
+    pub success: bool,
+    pub errorMessage: ::std::string::String,
+    pub condition_failed: bool,
+    pub exists: bool,
+    pub current_value: ::std::vec::Vec<u8>,
+    pub version: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PutResponse {
+    fn default() -> &'a PutResponse {
+        <PutResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PutResponse {
+    pub fn new() -> PutResponse {
+        ::std::default::Default::default()
+    }
+
+    // bool success = 1;
+
+
+    pub fn get_success(&self) -> bool {
+        self.success
+    }
+    pub fn clear_success(&mut self) {
+        self.success = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_success(&mut self, v: bool) {
+        self.success = v;
+    }
+
+    // string errorMessage = 2;
+
+
+    pub fn get_errorMessage(&self) -> &str {
+        &self.errorMessage
+    }
+    pub fn clear_errorMessage(&mut self) {
+        self.errorMessage.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_errorMessage(&mut self, v: ::std::string::String) {
+        self.errorMessage = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_errorMessage(&mut self) -> &mut ::std::string::String {
+        &mut self.errorMessage
+    }
+
+    // Take field
+    pub fn take_errorMessage(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.errorMessage, ::std::string::String::new())
+    }
+
+    // bool condition_failed = 3;
+
+
+    pub fn get_condition_failed(&self) -> bool {
+        self.condition_failed
+    }
+    pub fn clear_condition_failed(&mut self) {
+        self.condition_failed = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_condition_failed(&mut self, v: bool) {
+        self.condition_failed = v;
+    }
+
+    // bool exists = 4;
+
+
+    pub fn get_exists(&self) -> bool {
+        self.exists
+    }
+    pub fn clear_exists(&mut self) {
+        self.exists = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exists(&mut self, v: bool) {
+        self.exists = v;
+    }
+
+    // bytes current_value = 5;
+
+
+    pub fn get_current_value(&self) -> &[u8] {
+        &self.current_value
+    }
+    pub fn clear_current_value(&mut self) {
+        self.current_value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_current_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.current_value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_current_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.current_value
+    }
+
+    // Take field
+    pub fn take_current_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.current_value, ::std::vec::Vec::new())
+    }
+
+    // uint64 version = 6;
+
+
+    pub fn get_version(&self) -> u64 {
+        self.version
+    }
+    pub fn clear_version(&mut self) {
+        self.version = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_version(&mut self, v: u64) {
+        self.version = v;
+    }
+}
+
+impl ::protobuf::Message for PutResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.success = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.errorMessage)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.condition_failed = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.exists = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.current_value)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.version = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.success != false {
+            my_size += 2;
+        }
+        if !self.errorMessage.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.errorMessage);
+        }
+        if self.condition_failed != false {
+            my_size += 2;
+        }
+        if self.exists != false {
+            my_size += 2;
+        }
+        if !self.current_value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(5, &self.current_value);
+        }
+        if self.version != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.version, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.success != false {
+            os.write_bool(1, self.success)?;
+        }
+        if !self.errorMessage.is_empty() {
+            os.write_string(2, &self.errorMessage)?;
+        }
+        if self.condition_failed != false {
+            os.write_bool(3, self.condition_failed)?;
+        }
+        if self.exists != false {
+            os.write_bool(4, self.exists)?;
+        }
+        if !self.current_value.is_empty() {
+            os.write_bytes(5, &self.current_value)?;
+        }
+        if self.version != 0 {
+            os.write_uint64(6, self.version)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PutResponse {
+        PutResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "success",
+                    |m: &PutResponse| { &m.success },
+                    |m: &mut PutResponse| { &mut m.success },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "errorMessage",
+                    |m: &PutResponse| { &m.errorMessage },
+                    |m: &mut PutResponse| { &mut m.errorMessage },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "condition_failed",
+                    |m: &PutResponse| { &m.condition_failed },
+                    |m: &mut PutResponse| { &mut m.condition_failed },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "exists",
+                    |m: &PutResponse| { &m.exists },
+                    |m: &mut PutResponse| { &mut m.exists },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "current_value",
+                    |m: &PutResponse| { &m.current_value },
+                    |m: &mut PutResponse| { &mut m.current_value },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "version",
+                    |m: &PutResponse| { &m.version },
+                    |m: &mut PutResponse| { &mut m.version },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<PutResponse>(
+                    "PutResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static PutResponse {
+        static mut instance: ::protobuf::lazy::Lazy<PutResponse> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(PutResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for PutResponse {
+    fn clear(&mut self) {
+        self.success = false;
+        self.errorMessage.clear();
+        self.condition_failed = false;
+        self.exists = false;
+        self.current_value.clear();
+        self.version = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PutResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PutResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DeleteRequest {
+    // message fields
+    pub key: ::std::vec::Vec<u8>,
+    pub condition: ::protobuf::SingularPtrField<Condition>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DeleteRequest {
+    fn default() -> &'a DeleteRequest {
+        <DeleteRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DeleteRequest {
+    pub fn new() -> DeleteRequest {
+        ::std::default::Default::default()
+    }
+
+    // bytes key = 1;
+
+
+    pub fn get_key(&self) -> &[u8] {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    }
+
+    // .Condition condition = 2;
+
+
+    pub fn get_condition(&self) -> &Condition {
+        self.condition.as_ref().unwrap_or_else(|| Condition::default_instance())
+    }
+    pub fn clear_condition(&mut self) {
+        self.condition.clear();
+    }
+
+    pub fn has_condition(&self) -> bool {
+        self.condition.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_condition(&mut self, v: Condition) {
+        self.condition = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_condition(&mut self) -> &mut Condition {
+        if self.condition.is_none() {
+            self.condition.set_default();
+        }
+        self.condition.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_condition(&mut self) -> Condition {
+        self.condition.take().unwrap_or_else(|| Condition::new())
+    }
+}
+
+impl ::protobuf::Message for DeleteRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.condition {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.condition)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.key);
+        }
+        if let Some(ref v) = self.condition.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_bytes(1, &self.key)?;
+        }
+        if let Some(ref v) = self.condition.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DeleteRequest {
+        DeleteRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "key",
+                    |m: &DeleteRequest| { &m.key },
+                    |m: &mut DeleteRequest| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Condition>>(
+                    "condition",
+                    |m: &DeleteRequest| { &m.condition },
+                    |m: &mut DeleteRequest| { &mut m.condition },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<DeleteRequest>(
+                    "DeleteRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static DeleteRequest {
+        static mut instance: ::protobuf::lazy::Lazy<DeleteRequest> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(DeleteRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for DeleteRequest {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.condition.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DeleteRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeleteRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DeleteResponse {
+    // message fields
+    pub success: bool,
+    pub errorMessage: ::std::string::String,
+    pub condition_failed: bool,
+    pub exists: bool,
+    pub current_value: ::std::vec::Vec<u8>,
+    pub version: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DeleteResponse {
+    fn default() -> &'a DeleteResponse {
+        <DeleteResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DeleteResponse {
+    pub fn new() -> DeleteResponse {
+        ::std::default::Default::default()
+    }
+
+    // bool success = 1;
+
+
+    pub fn get_success(&self) -> bool {
+        self.success
+    }
+    pub fn clear_success(&mut self) {
+        self.success = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_success(&mut self, v: bool) {
+        self.success = v;
+    }
+
+    // string errorMessage = 2;
+
+
+    pub fn get_errorMessage(&self) -> &str {
+        &self.errorMessage
+    }
+    pub fn clear_errorMessage(&mut self) {
+        self.errorMessage.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_errorMessage(&mut self, v: ::std::string::String) {
+        self.errorMessage = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_errorMessage(&mut self) -> &mut ::std::string::String {
+        &mut self.errorMessage
+    }
+
+    // Take field
+    pub fn take_errorMessage(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.errorMessage, ::std::string::String::new())
+    }
+
+    // bool condition_failed = 3;
+
+
+    pub fn get_condition_failed(&self) -> bool {
+        self.condition_failed
+    }
+    pub fn clear_condition_failed(&mut self) {
+        self.condition_failed = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_condition_failed(&mut self, v: bool) {
+        self.condition_failed = v;
+    }
+
+    // bool exists = 4;
+
+
+    pub fn get_exists(&self) -> bool {
+        self.exists
+    }
+    pub fn clear_exists(&mut self) {
+        self.exists = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exists(&mut self, v: bool) {
+        self.exists = v;
+    }
+
+    // bytes current_value = 5;
+
+
+    pub fn get_current_value(&self) -> &[u8] {
+        &self.current_value
+    }
+    pub fn clear_current_value(&mut self) {
+        self.current_value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_current_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.current_value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_current_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.current_value
+    }
+
+    // Take field
+    pub fn take_current_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.current_value, ::std::vec::Vec::new())
+    }
+
+    // uint64 version = 6;
+
+
+    pub fn get_version(&self) -> u64 {
+        self.version
+    }
+    pub fn clear_version(&mut self) {
+        self.version = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_version(&mut self, v: u64) {
+        self.version = v;
+    }
+}
+
+impl ::protobuf::Message for DeleteResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.success = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.errorMessage)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.condition_failed = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.exists = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.current_value)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.version = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.success != false {
+            my_size += 2;
+        }
+        if !self.errorMessage.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.errorMessage);
+        }
+        if self.condition_failed != false {
+            my_size += 2;
+        }
+        if self.exists != false {
+            my_size += 2;
+        }
+        if !self.current_value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(5, &self.current_value);
+        }
+        if self.version != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.version, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.success != false {
+            os.write_bool(1, self.success)?;
+        }
+        if !self.errorMessage.is_empty() {
+            os.write_string(2, &self.errorMessage)?;
+        }
+        if self.condition_failed != false {
+            os.write_bool(3, self.condition_failed)?;
+        }
+        if self.exists != false {
+            os.write_bool(4, self.exists)?;
+        }
+        if !self.current_value.is_empty() {
+            os.write_bytes(5, &self.current_value)?;
+        }
+        if self.version != 0 {
+            os.write_uint64(6, self.version)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DeleteResponse {
+        DeleteResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "success",
+                    |m: &DeleteResponse| { &m.success },
+                    |m: &mut DeleteResponse| { &mut m.success },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "errorMessage",
+                    |m: &DeleteResponse| { &m.errorMessage },
+                    |m: &mut DeleteResponse| { &mut m.errorMessage },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "condition_failed",
+                    |m: &DeleteResponse| { &m.condition_failed },
+                    |m: &mut DeleteResponse| { &mut m.condition_failed },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "exists",
+                    |m: &DeleteResponse| { &m.exists },
+                    |m: &mut DeleteResponse| { &mut m.exists },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "current_value",
+                    |m: &DeleteResponse| { &m.current_value },
+                    |m: &mut DeleteResponse| { &mut m.current_value },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "version",
+                    |m: &DeleteResponse| { &m.version },
+                    |m: &mut DeleteResponse| { &mut m.version },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<DeleteResponse>(
+                    "DeleteResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static DeleteResponse {
+        static mut instance: ::protobuf::lazy::Lazy<DeleteResponse> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(DeleteResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for DeleteResponse {
+    fn clear(&mut self) {
+        self.success = false;
+        self.errorMessage.clear();
+        self.condition_failed = false;
+        self.exists = false;
+        self.current_value.clear();
+        self.version = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DeleteResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeleteResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CompareAndSwapRequest {
+    // message fields
+    pub key: ::std::vec::Vec<u8>,
+    pub expected: ::protobuf::SingularPtrField<Condition>,
+    pub new_value: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CompareAndSwapRequest {
+    fn default() -> &'a CompareAndSwapRequest {
+        <CompareAndSwapRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CompareAndSwapRequest {
+    pub fn new() -> CompareAndSwapRequest {
+        ::std::default::Default::default()
+    }
+
+    // bytes key = 1;
+
+
+    pub fn get_key(&self) -> &[u8] {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    }
+
+    // .Condition expected = 2;
+
+
+    pub fn get_expected(&self) -> &Condition {
+        self.expected.as_ref().unwrap_or_else(|| Condition::default_instance())
+    }
+    pub fn clear_expected(&mut self) {
+        self.expected.clear();
+    }
+
+    pub fn has_expected(&self) -> bool {
+        self.expected.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_expected(&mut self, v: Condition) {
+        self.expected = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_expected(&mut self) -> &mut Condition {
+        if self.expected.is_none() {
+            self.expected.set_default();
+        }
+        self.expected.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_expected(&mut self) -> Condition {
+        self.expected.take().unwrap_or_else(|| Condition::new())
+    }
+
+    // bytes new_value = 3;
+
+
+    pub fn get_new_value(&self) -> &[u8] {
+        &self.new_value
+    }
+    pub fn clear_new_value(&mut self) {
+        self.new_value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_new_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.new_value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_new_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.new_value
+    }
+
+    // Take field
+    pub fn take_new_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.new_value, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for CompareAndSwapRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.expected {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.expected)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.new_value)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.key);
+        }
+        if let Some(ref v) = self.expected.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.new_value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.new_value);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_bytes(1, &self.key)?;
+        }
+        if let Some(ref v) = self.expected.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.new_value.is_empty() {
+            os.write_bytes(3, &self.new_value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CompareAndSwapRequest {
+        CompareAndSwapRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "key",
+                    |m: &CompareAndSwapRequest| { &m.key },
+                    |m: &mut CompareAndSwapRequest| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Condition>>(
+                    "expected",
+                    |m: &CompareAndSwapRequest| { &m.expected },
+                    |m: &mut CompareAndSwapRequest| { &mut m.expected },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "new_value",
+                    |m: &CompareAndSwapRequest| { &m.new_value },
+                    |m: &mut CompareAndSwapRequest| { &mut m.new_value },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<CompareAndSwapRequest>(
+                    "CompareAndSwapRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static CompareAndSwapRequest {
+        static mut instance: ::protobuf::lazy::Lazy<CompareAndSwapRequest> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(CompareAndSwapRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for CompareAndSwapRequest {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.expected.clear();
+        self.new_value.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CompareAndSwapRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CompareAndSwapRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CompareAndSwapResponse {
+    // message fields
+    pub success: bool,
+    pub errorMessage: ::std::string::String,
+    pub swapped: bool,
+    pub exists: bool,
+    pub current_value: ::std::vec::Vec<u8>,
+    pub version: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CompareAndSwapResponse {
+    fn default() -> &'a CompareAndSwapResponse {
+        <CompareAndSwapResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CompareAndSwapResponse {
+    pub fn new() -> CompareAndSwapResponse {
+        ::std::default::Default::default()
+    }
+
+    // bool success = 1;
+
+
+    pub fn get_success(&self) -> bool {
+        self.success
+    }
+    pub fn clear_success(&mut self) {
+        self.success = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_success(&mut self, v: bool) {
+        self.success = v;
+    }
+
+    // string errorMessage = 2;
+
+
+    pub fn get_errorMessage(&self) -> &str {
+        &self.errorMessage
+    }
+    pub fn clear_errorMessage(&mut self) {
+        self.errorMessage.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_errorMessage(&mut self, v: ::std::string::String) {
+        self.errorMessage = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_errorMessage(&mut self) -> &mut ::std::string::String {
+        &mut self.errorMessage
+    }
+
+    // Take field
+    pub fn take_errorMessage(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.errorMessage, ::std::string::String::new())
+    }
+
+    // bool swapped = 3;
+
+
+    pub fn get_swapped(&self) -> bool {
+        self.swapped
+    }
+    pub fn clear_swapped(&mut self) {
+        self.swapped = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_swapped(&mut self, v: bool) {
+        self.swapped = v;
+    }
+
+    // bool exists = 4;
+
+
+    pub fn get_exists(&self) -> bool {
+        self.exists
+    }
+    pub fn clear_exists(&mut self) {
+        self.exists = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exists(&mut self, v: bool) {
+        self.exists = v;
+    }
+
+    // bytes current_value = 5;
+
+
+    pub fn get_current_value(&self) -> &[u8] {
+        &self.current_value
+    }
+    pub fn clear_current_value(&mut self) {
+        self.current_value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_current_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.current_value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_current_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.current_value
+    }
+
+    // Take field
+    pub fn take_current_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.current_value, ::std::vec::Vec::new())
+    }
+
+    // uint64 version = 6;
+
+
+    pub fn get_version(&self) -> u64 {
+        self.version
+    }
+    pub fn clear_version(&mut self) {
+        self.version = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_version(&mut self, v: u64) {
+        self.version = v;
+    }
+}
+
+impl ::protobuf::Message for CompareAndSwapResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.success = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.errorMessage)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.swapped = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.exists = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.current_value)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.version = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.success != false {
+            my_size += 2;
+        }
+        if !self.errorMessage.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.errorMessage);
+        }
+        if self.swapped != false {
+            my_size += 2;
+        }
+        if self.exists != false {
+            my_size += 2;
+        }
+        if !self.current_value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(5, &self.current_value);
+        }
+        if self.version != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.version, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.success != false {
+            os.write_bool(1, self.success)?;
+        }
+        if !self.errorMessage.is_empty() {
+            os.write_string(2, &self.errorMessage)?;
+        }
+        if self.swapped != false {
+            os.write_bool(3, self.swapped)?;
+        }
+        if self.exists != false {
+            os.write_bool(4, self.exists)?;
+        }
+        if !self.current_value.is_empty() {
+            os.write_bytes(5, &self.current_value)?;
+        }
+        if self.version != 0 {
+            os.write_uint64(6, self.version)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CompareAndSwapResponse {
+        CompareAndSwapResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "success",
+                    |m: &CompareAndSwapResponse| { &m.success },
+                    |m: &mut CompareAndSwapResponse| { &mut m.success },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "errorMessage",
+                    |m: &CompareAndSwapResponse| { &m.errorMessage },
+                    |m: &mut CompareAndSwapResponse| { &mut m.errorMessage },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "swapped",
+                    |m: &CompareAndSwapResponse| { &m.swapped },
+                    |m: &mut CompareAndSwapResponse| { &mut m.swapped },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "exists",
+                    |m: &CompareAndSwapResponse| { &m.exists },
+                    |m: &mut CompareAndSwapResponse| { &mut m.exists },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "current_value",
+                    |m: &CompareAndSwapResponse| { &m.current_value },
+                    |m: &mut CompareAndSwapResponse| { &mut m.current_value },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "version",
+                    |m: &CompareAndSwapResponse| { &m.version },
+                    |m: &mut CompareAndSwapResponse| { &mut m.version },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<CompareAndSwapResponse>(
+                    "CompareAndSwapResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static CompareAndSwapResponse {
+        static mut instance: ::protobuf::lazy::Lazy<CompareAndSwapResponse> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(CompareAndSwapResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for CompareAndSwapResponse {
+    fn clear(&mut self) {
+        self.success = false;
+        self.errorMessage.clear();
+        self.swapped = false;
+        self.exists = false;
+        self.current_value.clear();
+        self.version = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CompareAndSwapResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CompareAndSwapResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ScanRequest {
+    // message fields
+    pub cursor: u64,
+    pub count: u32,
+    pub with_values: bool,
+    pub read_ts: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ScanRequest {
+    fn default() -> &'a ScanRequest {
+        <ScanRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ScanRequest {
+    pub fn new() -> ScanRequest {
+        ::std::default::Default::default()
+    }
+
+    // uint64 cursor = 1;
+
+
+    pub fn get_cursor(&self) -> u64 {
+        self.cursor
+    }
+    pub fn clear_cursor(&mut self) {
+        self.cursor = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cursor(&mut self, v: u64) {
+        self.cursor = v;
+    }
+
+    // uint32 count = 2;
+
+
+    pub fn get_count(&self) -> u32 {
+        self.count
+    }
+    pub fn clear_count(&mut self) {
+        self.count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: u32) {
+        self.count = v;
+    }
+
+    // bool with_values = 3;
+
+
+    pub fn get_with_values(&self) -> bool {
+        self.with_values
+    }
+    pub fn clear_with_values(&mut self) {
+        self.with_values = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_with_values(&mut self, v: bool) {
+        self.with_values = v;
+    }
+
+    // uint64 read_ts = 4;
+
+
+    pub fn get_read_ts(&self) -> u64 {
+        self.read_ts
+    }
+    pub fn clear_read_ts(&mut self) {
+        self.read_ts = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_read_ts(&mut self, v: u64) {
+        self.read_ts = v;
+    }
+}
+
+impl ::protobuf::Message for ScanRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.cursor = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.count = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.with_values = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.read_ts = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.cursor != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.cursor, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.count != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.with_values != false {
+            my_size += 2;
+        }
+        if self.read_ts != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.read_ts, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.cursor != 0 {
+            os.write_uint64(1, self.cursor)?;
+        }
+        if self.count != 0 {
+            os.write_uint32(2, self.count)?;
+        }
+        if self.with_values != false {
+            os.write_bool(3, self.with_values)?;
+        }
+        if self.read_ts != 0 {
+            os.write_uint64(4, self.read_ts)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ScanRequest {
+        ScanRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "cursor",
+                    |m: &ScanRequest| { &m.cursor },
+                    |m: &mut ScanRequest| { &mut m.cursor },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "count",
+                    |m: &ScanRequest| { &m.count },
+                    |m: &mut ScanRequest| { &mut m.count },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "with_values",
+                    |m: &ScanRequest| { &m.with_values },
+                    |m: &mut ScanRequest| { &mut m.with_values },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "read_ts",
+                    |m: &ScanRequest| { &m.read_ts },
+                    |m: &mut ScanRequest| { &mut m.read_ts },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<ScanRequest>(
+                    "ScanRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ScanRequest {
+        static mut instance: ::protobuf::lazy::Lazy<ScanRequest> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(ScanRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ScanRequest {
+    fn clear(&mut self) {
+        self.cursor = 0;
+        self.count = 0;
+        self.with_values = false;
+        self.read_ts = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ScanRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ScanRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ScanResponse {
+    // message fields
+    pub cursor: u64,
+    pub result: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    pub success: bool,
+    pub errorMessage: ::std::string::String,
+    pub values: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    pub read_ts: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ScanResponse {
+    fn default() -> &'a ScanResponse {
+        <ScanResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ScanResponse {
+    pub fn new() -> ScanResponse {
+        ::std::default::Default::default()
+    }
+
+    // uint64 cursor = 1;
+
+
+    pub fn get_cursor(&self) -> u64 {
+        self.cursor
+    }
+    pub fn clear_cursor(&mut self) {
+        self.cursor = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cursor(&mut self, v: u64) {
+        self.cursor = v;
+    }
+
+    // repeated bytes result = 2;
+
+
+    pub fn get_result(&self) -> &[::std::vec::Vec<u8>] {
+        &self.result
+    }
+    pub fn clear_result(&mut self) {
+        self.result.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_result(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.result = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_result(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.result
+    }
+
+    // Take field
+    pub fn take_result(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.result, ::protobuf::RepeatedField::new())
+    }
+
+    // bool success = 3;
+
+
+    pub fn get_success(&self) -> bool {
+        self.success
+    }
+    pub fn clear_success(&mut self) {
+        self.success = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_success(&mut self, v: bool) {
+        self.success = v;
+    }
+
+    // string errorMessage = 4;
+
+
+    pub fn get_errorMessage(&self) -> &str {
+        &self.errorMessage
+    }
+    pub fn clear_errorMessage(&mut self) {
+        self.errorMessage.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_errorMessage(&mut self, v: ::std::string::String) {
+        self.errorMessage = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_errorMessage(&mut self) -> &mut ::std::string::String {
+        &mut self.errorMessage
+    }
+
+    // Take field
+    pub fn take_errorMessage(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.errorMessage, ::std::string::String::new())
+    }
+
+    // repeated bytes values = 5;
+
+
+    pub fn get_values(&self) -> &[::std::vec::Vec<u8>] {
+        &self.values
+    }
+    pub fn clear_values(&mut self) {
+        self.values.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_values(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.values = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_values(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.values
+    }
+
+    // Take field
+    pub fn take_values(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.values, ::protobuf::RepeatedField::new())
+    }
+
+    // uint64 read_ts = 6;
+
+
+    pub fn get_read_ts(&self) -> u64 {
+        self.read_ts
+    }
+    pub fn clear_read_ts(&mut self) {
+        self.read_ts = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_read_ts(&mut self, v: u64) {
+        self.read_ts = v;
+    }
+}
+
+impl ::protobuf::Message for ScanResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.cursor = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.result)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.success = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.errorMessage)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.values)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.read_ts = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.cursor != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.cursor, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.result {
+            my_size += ::protobuf::rt::bytes_size(2, &value);
+        };
+        if self.success != false {
+            my_size += 2;
+        }
+        if !self.errorMessage.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.errorMessage);
+        }
+        for value in &self.values {
+            my_size += ::protobuf::rt::bytes_size(5, &value);
+        };
+        if self.read_ts != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.read_ts, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.cursor != 0 {
+            os.write_uint64(1, self.cursor)?;
+        }
+        for v in &self.result {
+            os.write_bytes(2, &v)?;
+        };
+        if self.success != false {
+            os.write_bool(3, self.success)?;
+        }
+        if !self.errorMessage.is_empty() {
+            os.write_string(4, &self.errorMessage)?;
+        }
+        for v in &self.values {
+            os.write_bytes(5, &v)?;
+        };
+        if self.read_ts != 0 {
+            os.write_uint64(6, self.read_ts)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ScanResponse {
+        ScanResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "cursor",
+                    |m: &ScanResponse| { &m.cursor },
+                    |m: &mut ScanResponse| { &mut m.cursor },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "result",
+                    |m: &ScanResponse| { &m.result },
+                    |m: &mut ScanResponse| { &mut m.result },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "success",
+                    |m: &ScanResponse| { &m.success },
+                    |m: &mut ScanResponse| { &mut m.success },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "errorMessage",
+                    |m: &ScanResponse| { &m.errorMessage },
+                    |m: &mut ScanResponse| { &mut m.errorMessage },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "values",
+                    |m: &ScanResponse| { &m.values },
+                    |m: &mut ScanResponse| { &mut m.values },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "read_ts",
+                    |m: &ScanResponse| { &m.read_ts },
+                    |m: &mut ScanResponse| { &mut m.read_ts },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<ScanResponse>(
+                    "ScanResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ScanResponse {
+        static mut instance: ::protobuf::lazy::Lazy<ScanResponse> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(ScanResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ScanResponse {
+    fn clear(&mut self) {
+        self.cursor = 0;
+        self.result.clear();
+        self.success = false;
+        self.errorMessage.clear();
+        self.values.clear();
+        self.read_ts = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ScanResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ScanResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RangeScanRequest {
+    // message fields
+    pub start_key: ::std::vec::Vec<u8>,
+    pub end_key: ::std::vec::Vec<u8>,
+    pub limit: u32,
+    pub reverse: bool,
+    pub read_ts: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RangeScanRequest {
+    fn default() -> &'a RangeScanRequest {
+        <RangeScanRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RangeScanRequest {
+    pub fn new() -> RangeScanRequest {
+        ::std::default::Default::default()
+    }
+
+    // bytes start_key = 1;
+
+
+    pub fn get_start_key(&self) -> &[u8] {
+        &self.start_key
+    }
+    pub fn clear_start_key(&mut self) {
+        self.start_key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.start_key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_start_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.start_key
+    }
+
+    // Take field
+    pub fn take_start_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.start_key, ::std::vec::Vec::new())
+    }
+
+    // bytes end_key = 2;
+
+
+    pub fn get_end_key(&self) -> &[u8] {
+        &self.end_key
+    }
+    pub fn clear_end_key(&mut self) {
+        self.end_key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_end_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.end_key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_end_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.end_key
+    }
+
+    // Take field
+    pub fn take_end_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.end_key, ::std::vec::Vec::new())
+    }
+
+    // uint32 limit = 3;
+
+
+    pub fn get_limit(&self) -> u32 {
+        self.limit
+    }
+    pub fn clear_limit(&mut self) {
+        self.limit = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limit(&mut self, v: u32) {
+        self.limit = v;
+    }
+
+    // bool reverse = 4;
+
+
+    pub fn get_reverse(&self) -> bool {
+        self.reverse
+    }
+    pub fn clear_reverse(&mut self) {
+        self.reverse = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reverse(&mut self, v: bool) {
+        self.reverse = v;
+    }
+
+    // uint64 read_ts = 5;
+
+
+    pub fn get_read_ts(&self) -> u64 {
+        self.read_ts
+    }
+    pub fn clear_read_ts(&mut self) {
+        self.read_ts = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_read_ts(&mut self, v: u64) {
+        self.read_ts = v;
+    }
+}
+
+impl ::protobuf::Message for RangeScanRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.start_key)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.end_key)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.limit = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.reverse = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.read_ts = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.start_key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.start_key);
+        }
+        if !self.end_key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.end_key);
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.limit, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.reverse != false {
+            my_size += 2;
+        }
+        if self.read_ts != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.read_ts, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.start_key.is_empty() {
+            os.write_bytes(1, &self.start_key)?;
+        }
+        if !self.end_key.is_empty() {
+            os.write_bytes(2, &self.end_key)?;
+        }
+        if self.limit != 0 {
+            os.write_uint32(3, self.limit)?;
+        }
+        if self.reverse != false {
+            os.write_bool(4, self.reverse)?;
+        }
+        if self.read_ts != 0 {
+            os.write_uint64(5, self.read_ts)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RangeScanRequest {
+        RangeScanRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "start_key",
+                    |m: &RangeScanRequest| { &m.start_key },
+                    |m: &mut RangeScanRequest| { &mut m.start_key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "end_key",
+                    |m: &RangeScanRequest| { &m.end_key },
+                    |m: &mut RangeScanRequest| { &mut m.end_key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "limit",
+                    |m: &RangeScanRequest| { &m.limit },
+                    |m: &mut RangeScanRequest| { &mut m.limit },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "reverse",
+                    |m: &RangeScanRequest| { &m.reverse },
+                    |m: &mut RangeScanRequest| { &mut m.reverse },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "read_ts",
+                    |m: &RangeScanRequest| { &m.read_ts },
+                    |m: &mut RangeScanRequest| { &mut m.read_ts },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<RangeScanRequest>(
+                    "RangeScanRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static RangeScanRequest {
+        static mut instance: ::protobuf::lazy::Lazy<RangeScanRequest> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(RangeScanRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for RangeScanRequest {
+    fn clear(&mut self) {
+        self.start_key.clear();
+        self.end_key.clear();
+        self.limit = 0;
+        self.reverse = false;
+        self.read_ts = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RangeScanRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RangeScanRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct KvPair {
+    // message fields
+    pub key: ::std::vec::Vec<u8>,
+    pub value: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a KvPair {
+    fn default() -> &'a KvPair {
+        <KvPair as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl KvPair {
+    pub fn new() -> KvPair {
+        ::std::default::Default::default()
+    }
+
+    // bytes key = 1;
+
+
+    pub fn get_key(&self) -> &[u8] {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    }
+
+    // bytes value = 2;
+
+
+    pub fn get_value(&self) -> &[u8] {
+        &self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for KvPair {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.key);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.value);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_bytes(1, &self.key)?;
+        }
+        if !self.value.is_empty() {
+            os.write_bytes(2, &self.value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> KvPair {
+        KvPair::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "key",
+                    |m: &KvPair| { &m.key },
+                    |m: &mut KvPair| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "value",
+                    |m: &KvPair| { &m.value },
+                    |m: &mut KvPair| { &mut m.value },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<KvPair>(
+                    "KvPair",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static KvPair {
+        static mut instance: ::protobuf::lazy::Lazy<KvPair> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(KvPair::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for KvPair {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.value.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for KvPair {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for KvPair {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RangeScanResponse {
+    // message fields
+    pub success: bool,
+    pub errorMessage: ::std::string::String,
+    pub pairs: ::protobuf::RepeatedField<KvPair>,
+    pub next_key: ::std::vec::Vec<u8>,
+    pub read_ts: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RangeScanResponse {
+    fn default() -> &'a RangeScanResponse {
+        <RangeScanResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RangeScanResponse {
+    pub fn new() -> RangeScanResponse {
+        ::std::default::Default::default()
+    }
+
+    // bool success = 1;
+
+
+    pub fn get_success(&self) -> bool {
+        self.success
+    }
+    pub fn clear_success(&mut self) {
+        self.success = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_success(&mut self, v: bool) {
+        self.success = v;
+    }
+
+    // string errorMessage = 2;
+
+
+    pub fn get_errorMessage(&self) -> &str {
+        &self.errorMessage
+    }
+    pub fn clear_errorMessage(&mut self) {
+        self.errorMessage.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_errorMessage(&mut self, v: ::std::string::String) {
+        self.errorMessage = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_errorMessage(&mut self) -> &mut ::std::string::String {
+        &mut self.errorMessage
+    }
+
+    // Take field
+    pub fn take_errorMessage(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.errorMessage, ::std::string::String::new())
+    }
+
+    // repeated .KvPair pairs = 3;
+
+
+    pub fn get_pairs(&self) -> &[KvPair] {
+        &self.pairs
+    }
+    pub fn clear_pairs(&mut self) {
+        self.pairs.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pairs(&mut self, v: ::protobuf::RepeatedField<KvPair>) {
+        self.pairs = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_pairs(&mut self) -> &mut ::protobuf::RepeatedField<KvPair> {
+        &mut self.pairs
+    }
+
+    // Take field
+    pub fn take_pairs(&mut self) -> ::protobuf::RepeatedField<KvPair> {
+        ::std::mem::replace(&mut self.pairs, ::protobuf::RepeatedField::new())
+    }
+
+    // bytes next_key = 4;
+
+
+    pub fn get_next_key(&self) -> &[u8] {
+        &self.next_key
+    }
+    pub fn clear_next_key(&mut self) {
+        self.next_key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_next_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.next_key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_next_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.next_key
+    }
+
+    // Take field
+    pub fn take_next_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.next_key, ::std::vec::Vec::new())
+    }
+
+    // uint64 read_ts = 5;
+
+
+    pub fn get_read_ts(&self) -> u64 {
+        self.read_ts
+    }
+    pub fn clear_read_ts(&mut self) {
+        self.read_ts = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_read_ts(&mut self, v: u64) {
+        self.read_ts = v;
+    }
+}
+
+impl ::protobuf::Message for RangeScanResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.pairs {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.success = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.errorMessage)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.pairs)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.next_key)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.read_ts = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.success != false {
+            my_size += 2;
+        }
+        if !self.errorMessage.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.errorMessage);
+        }
+        for value in &self.pairs {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if !self.next_key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.next_key);
+        }
+        if self.read_ts != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.read_ts, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.success != false {
+            os.write_bool(1, self.success)?;
+        }
+        if !self.errorMessage.is_empty() {
+            os.write_string(2, &self.errorMessage)?;
+        }
+        for v in &self.pairs {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if !self.next_key.is_empty() {
+            os.write_bytes(4, &self.next_key)?;
+        }
+        if self.read_ts != 0 {
+            os.write_uint64(5, self.read_ts)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RangeScanResponse {
+        RangeScanResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "success",
+                    |m: &RangeScanResponse| { &m.success },
+                    |m: &mut RangeScanResponse| { &mut m.success },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "errorMessage",
+                    |m: &RangeScanResponse| { &m.errorMessage },
+                    |m: &mut RangeScanResponse| { &mut m.errorMessage },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<KvPair>>(
+                    "pairs",
+                    |m: &RangeScanResponse| { &m.pairs },
+                    |m: &mut RangeScanResponse| { &mut m.pairs },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "next_key",
+                    |m: &RangeScanResponse| { &m.next_key },
+                    |m: &mut RangeScanResponse| { &mut m.next_key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "read_ts",
+                    |m: &RangeScanResponse| { &m.read_ts },
+                    |m: &mut RangeScanResponse| { &mut m.read_ts },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<RangeScanResponse>(
+                    "RangeScanResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static RangeScanResponse {
+        static mut instance: ::protobuf::lazy::Lazy<RangeScanResponse> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(RangeScanResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for RangeScanResponse {
+    fn clear(&mut self) {
+        self.success = false;
+        self.errorMessage.clear();
+        self.pairs.clear();
+        self.next_key.clear();
+        self.read_ts = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RangeScanResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RangeScanResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PrefixScanRequest {
+    // message fields
+    pub prefix: ::std::vec::Vec<u8>,
+    pub start_key: ::std::vec::Vec<u8>,
+    pub limit: u32,
+    pub with_values: bool,
+    pub read_ts: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PrefixScanRequest {
+    fn default() -> &'a PrefixScanRequest {
+        <PrefixScanRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PrefixScanRequest {
+    pub fn new() -> PrefixScanRequest {
+        ::std::default::Default::default()
+    }
+
+    // bytes prefix = 1;
+
+
+    pub fn get_prefix(&self) -> &[u8] {
+        &self.prefix
+    }
+    pub fn clear_prefix(&mut self) {
+        self.prefix.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_prefix(&mut self, v: ::std::vec::Vec<u8>) {
+        self.prefix = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_prefix(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.prefix
+    }
+
+    // Take field
+    pub fn take_prefix(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.prefix, ::std::vec::Vec::new())
+    }
+
+    // bytes start_key = 2;
+
+
+    pub fn get_start_key(&self) -> &[u8] {
+        &self.start_key
+    }
+    pub fn clear_start_key(&mut self) {
+        self.start_key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.start_key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_start_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.start_key
+    }
+
+    // Take field
+    pub fn take_start_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.start_key, ::std::vec::Vec::new())
+    }
+
+    // uint32 limit = 3;
+
+
+    pub fn get_limit(&self) -> u32 {
+        self.limit
+    }
+    pub fn clear_limit(&mut self) {
+        self.limit = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limit(&mut self, v: u32) {
+        self.limit = v;
+    }
+
+    // bool with_values = 4;
+
+
+    pub fn get_with_values(&self) -> bool {
+        self.with_values
+    }
+    pub fn clear_with_values(&mut self) {
+        self.with_values = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_with_values(&mut self, v: bool) {
+        self.with_values = v;
+    }
+
+    // uint64 read_ts = 5;
+
+
+    pub fn get_read_ts(&self) -> u64 {
+        self.read_ts
+    }
+    pub fn clear_read_ts(&mut self) {
+        self.read_ts = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_read_ts(&mut self, v: u64) {
+        self.read_ts = v;
+    }
+}
+
+impl ::protobuf::Message for PrefixScanRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.prefix)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.start_key)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.limit = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.with_values = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.read_ts = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.prefix.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.prefix);
+        }
+        if !self.start_key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.start_key);
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.limit, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.with_values != false {
+            my_size += 2;
+        }
+        if self.read_ts != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.read_ts, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.prefix.is_empty() {
+            os.write_bytes(1, &self.prefix)?;
+        }
+        if !self.start_key.is_empty() {
+            os.write_bytes(2, &self.start_key)?;
+        }
+        if self.limit != 0 {
+            os.write_uint32(3, self.limit)?;
+        }
+        if self.with_values != false {
+            os.write_bool(4, self.with_values)?;
+        }
+        if self.read_ts != 0 {
+            os.write_uint64(5, self.read_ts)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PrefixScanRequest {
+        PrefixScanRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "prefix",
+                    |m: &PrefixScanRequest| { &m.prefix },
+                    |m: &mut PrefixScanRequest| { &mut m.prefix },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "start_key",
+                    |m: &PrefixScanRequest| { &m.start_key },
+                    |m: &mut PrefixScanRequest| { &mut m.start_key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "limit",
+                    |m: &PrefixScanRequest| { &m.limit },
+                    |m: &mut PrefixScanRequest| { &mut m.limit },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "with_values",
+                    |m: &PrefixScanRequest| { &m.with_values },
+                    |m: &mut PrefixScanRequest| { &mut m.with_values },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "read_ts",
+                    |m: &PrefixScanRequest| { &m.read_ts },
+                    |m: &mut PrefixScanRequest| { &mut m.read_ts },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<PrefixScanRequest>(
+                    "PrefixScanRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static PrefixScanRequest {
+        static mut instance: ::protobuf::lazy::Lazy<PrefixScanRequest> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(PrefixScanRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for PrefixScanRequest {
+    fn clear(&mut self) {
+        self.prefix.clear();
+        self.start_key.clear();
+        self.limit = 0;
+        self.with_values = false;
+        self.read_ts = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PrefixScanRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PrefixScanRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PrefixScanResponse {
+    // message fields
+    pub success: bool,
+    pub errorMessage: ::std::string::String,
+    pub pairs: ::protobuf::RepeatedField<KvPair>,
+    pub next_key: ::std::vec::Vec<u8>,
+    pub read_ts: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PrefixScanResponse {
+    fn default() -> &'a PrefixScanResponse {
+        <PrefixScanResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PrefixScanResponse {
+    pub fn new() -> PrefixScanResponse {
+        ::std::default::Default::default()
+    }
+
+    // bool success = 1;
+
+
+    pub fn get_success(&self) -> bool {
+        self.success
+    }
+    pub fn clear_success(&mut self) {
+        self.success = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_success(&mut self, v: bool) {
+        self.success = v;
+    }
+
+    // string errorMessage = 2;
+
+
+    pub fn get_errorMessage(&self) -> &str {
+        &self.errorMessage
+    }
+    pub fn clear_errorMessage(&mut self) {
+        self.errorMessage.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_errorMessage(&mut self, v: ::std::string::String) {
+        self.errorMessage = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_errorMessage(&mut self) -> &mut ::std::string::String {
+        &mut self.errorMessage
+    }
+
+    // Take field
+    pub fn take_errorMessage(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.errorMessage, ::std::string::String::new())
+    }
+
+    // repeated .KvPair pairs = 3;
+
+
+    pub fn get_pairs(&self) -> &[KvPair] {
+        &self.pairs
+    }
+    pub fn clear_pairs(&mut self) {
+        self.pairs.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pairs(&mut self, v: ::protobuf::RepeatedField<KvPair>) {
+        self.pairs = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_pairs(&mut self) -> &mut ::protobuf::RepeatedField<KvPair> {
+        &mut self.pairs
+    }
+
+    // Take field
+    pub fn take_pairs(&mut self) -> ::protobuf::RepeatedField<KvPair> {
+        ::std::mem::replace(&mut self.pairs, ::protobuf::RepeatedField::new())
+    }
+
+    // bytes next_key = 4;
+
+
+    pub fn get_next_key(&self) -> &[u8] {
+        &self.next_key
+    }
+    pub fn clear_next_key(&mut self) {
+        self.next_key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_next_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.next_key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_next_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.next_key
+    }
+
+    // Take field
+    pub fn take_next_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.next_key, ::std::vec::Vec::new())
+    }
+
+    // uint64 read_ts = 5;
+
+
+    pub fn get_read_ts(&self) -> u64 {
+        self.read_ts
+    }
+    pub fn clear_read_ts(&mut self) {
+        self.read_ts = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_read_ts(&mut self, v: u64) {
+        self.read_ts = v;
+    }
+}
+
+impl ::protobuf::Message for PrefixScanResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.pairs {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.success = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.errorMessage)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.pairs)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.next_key)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.read_ts = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.success != false {
+            my_size += 2;
+        }
+        if !self.errorMessage.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.errorMessage);
+        }
+        for value in &self.pairs {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if !self.next_key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.next_key);
+        }
+        if self.read_ts != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.read_ts, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.success != false {
+            os.write_bool(1, self.success)?;
+        }
+        if !self.errorMessage.is_empty() {
+            os.write_string(2, &self.errorMessage)?;
+        }
+        for v in &self.pairs {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if !self.next_key.is_empty() {
+            os.write_bytes(4, &self.next_key)?;
+        }
+        if self.read_ts != 0 {
+            os.write_uint64(5, self.read_ts)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PrefixScanResponse {
+        PrefixScanResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "success",
+                    |m: &PrefixScanResponse| { &m.success },
+                    |m: &mut PrefixScanResponse| { &mut m.success },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "errorMessage",
+                    |m: &PrefixScanResponse| { &m.errorMessage },
+                    |m: &mut PrefixScanResponse| { &mut m.errorMessage },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<KvPair>>(
+                    "pairs",
+                    |m: &PrefixScanResponse| { &m.pairs },
+                    |m: &mut PrefixScanResponse| { &mut m.pairs },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "next_key",
+                    |m: &PrefixScanResponse| { &m.next_key },
+                    |m: &mut PrefixScanResponse| { &mut m.next_key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "read_ts",
+                    |m: &PrefixScanResponse| { &m.read_ts },
+                    |m: &mut PrefixScanResponse| { &mut m.read_ts },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<PrefixScanResponse>(
+                    "PrefixScanResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static PrefixScanResponse {
+        static mut instance: ::protobuf::lazy::Lazy<PrefixScanResponse> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(PrefixScanResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for PrefixScanResponse {
+    fn clear(&mut self) {
+        self.success = false;
+        self.errorMessage.clear();
+        self.pairs.clear();
+        self.next_key.clear();
+        self.read_ts = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PrefixScanResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PrefixScanResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BatchGetRequest {
+    // message fields
+    pub keys: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    pub read_ts: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BatchGetRequest {
+    fn default() -> &'a BatchGetRequest {
+        <BatchGetRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BatchGetRequest {
+    pub fn new() -> BatchGetRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated bytes keys = 1;
+
+
+    pub fn get_keys(&self) -> &[::std::vec::Vec<u8>] {
+        &self.keys
+    }
+    pub fn clear_keys(&mut self) {
+        self.keys.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_keys(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.keys = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_keys(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.keys
+    }
+
+    // Take field
+    pub fn take_keys(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.keys, ::protobuf::RepeatedField::new())
+    }
+
+    // uint64 read_ts = 2;
+
+
+    pub fn get_read_ts(&self) -> u64 {
+        self.read_ts
+    }
+    pub fn clear_read_ts(&mut self) {
+        self.read_ts = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_read_ts(&mut self, v: u64) {
+        self.read_ts = v;
+    }
+}
+
+impl ::protobuf::Message for BatchGetRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.keys)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.read_ts = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.keys {
+            my_size += ::protobuf::rt::bytes_size(1, &value);
+        };
+        if self.read_ts != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.read_ts, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.keys {
+            os.write_bytes(1, &v)?;
+        };
+        if self.read_ts != 0 {
+            os.write_uint64(2, self.read_ts)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BatchGetRequest {
+        BatchGetRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "keys",
+                    |m: &BatchGetRequest| { &m.keys },
+                    |m: &mut BatchGetRequest| { &mut m.keys },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "read_ts",
+                    |m: &BatchGetRequest| { &m.read_ts },
+                    |m: &mut BatchGetRequest| { &mut m.read_ts },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<BatchGetRequest>(
+                    "BatchGetRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static BatchGetRequest {
+        static mut instance: ::protobuf::lazy::Lazy<BatchGetRequest> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(BatchGetRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for BatchGetRequest {
+    fn clear(&mut self) {
+        self.keys.clear();
+        self.read_ts = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BatchGetRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BatchGetRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BatchGetResponse {
+    // message fields
+    pub success: bool,
+    pub errorMessage: ::std::string::String,
+    pub results: ::protobuf::RepeatedField<GetResponse>,
+    pub read_ts: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BatchGetResponse {
+    fn default() -> &'a BatchGetResponse {
+        <BatchGetResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BatchGetResponse {
+    pub fn new() -> BatchGetResponse {
+        ::std::default::Default::default()
+    }
+
+    // bool success = 1;
+
+
+    pub fn get_success(&self) -> bool {
+        self.success
+    }
+    pub fn clear_success(&mut self) {
+        self.success = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_success(&mut self, v: bool) {
+        self.success = v;
+    }
+
+    // string errorMessage = 2;
+
+
+    pub fn get_errorMessage(&self) -> &str {
+        &self.errorMessage
+    }
+    pub fn clear_errorMessage(&mut self) {
+        self.errorMessage.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_errorMessage(&mut self, v: ::std::string::String) {
+        self.errorMessage = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_errorMessage(&mut self) -> &mut ::std::string::String {
+        &mut self.errorMessage
+    }
+
+    // Take field
+    pub fn take_errorMessage(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.errorMessage, ::std::string::String::new())
+    }
+
+    // repeated .GetResponse results = 3;
+
+
+    pub fn get_results(&self) -> &[GetResponse] {
+        &self.results
+    }
+    pub fn clear_results(&mut self) {
+        self.results.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_results(&mut self, v: ::protobuf::RepeatedField<GetResponse>) {
+        self.results = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_results(&mut self) -> &mut ::protobuf::RepeatedField<GetResponse> {
+        &mut self.results
+    }
+
+    // Take field
+    pub fn take_results(&mut self) -> ::protobuf::RepeatedField<GetResponse> {
+        ::std::mem::replace(&mut self.results, ::protobuf::RepeatedField::new())
+    }
+
+    // uint64 read_ts = 4;
+
+
+    pub fn get_read_ts(&self) -> u64 {
+        self.read_ts
+    }
+    pub fn clear_read_ts(&mut self) {
+        self.read_ts = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_read_ts(&mut self, v: u64) {
+        self.read_ts = v;
+    }
+}
+
+impl ::protobuf::Message for BatchGetResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.results {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.success = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.errorMessage)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.results)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.read_ts = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.success != false {
+            my_size += 2;
+        }
+        if !self.errorMessage.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.errorMessage);
+        }
+        for value in &self.results {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.read_ts != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.read_ts, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.success != false {
+            os.write_bool(1, self.success)?;
+        }
+        if !self.errorMessage.is_empty() {
+            os.write_string(2, &self.errorMessage)?;
+        }
+        for v in &self.results {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.read_ts != 0 {
+            os.write_uint64(4, self.read_ts)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BatchGetResponse {
+        BatchGetResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "success",
+                    |m: &BatchGetResponse| { &m.success },
+                    |m: &mut BatchGetResponse| { &mut m.success },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "errorMessage",
+                    |m: &BatchGetResponse| { &m.errorMessage },
+                    |m: &mut BatchGetResponse| { &mut m.errorMessage },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<GetResponse>>(
+                    "results",
+                    |m: &BatchGetResponse| { &m.results },
+                    |m: &mut BatchGetResponse| { &mut m.results },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "read_ts",
+                    |m: &BatchGetResponse| { &m.read_ts },
+                    |m: &mut BatchGetResponse| { &mut m.read_ts },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<BatchGetResponse>(
+                    "BatchGetResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static BatchGetResponse {
+        static mut instance: ::protobuf::lazy::Lazy<BatchGetResponse> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(BatchGetResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for BatchGetResponse {
+    fn clear(&mut self) {
+        self.success = false;
+        self.errorMessage.clear();
+        self.results.clear();
+        self.read_ts = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BatchGetResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BatchGetResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BatchPutRequest {
+    // message fields
+    pub pairs: ::protobuf::RepeatedField<KvPair>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BatchPutRequest {
+    fn default() -> &'a BatchPutRequest {
+        <BatchPutRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BatchPutRequest {
+    pub fn new() -> BatchPutRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated .KvPair pairs = 1;
+
+
+    pub fn get_pairs(&self) -> &[KvPair] {
+        &self.pairs
+    }
+    pub fn clear_pairs(&mut self) {
+        self.pairs.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pairs(&mut self, v: ::protobuf::RepeatedField<KvPair>) {
+        self.pairs = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_pairs(&mut self) -> &mut ::protobuf::RepeatedField<KvPair> {
+        &mut self.pairs
+    }
+
+    // Take field
+    pub fn take_pairs(&mut self) -> ::protobuf::RepeatedField<KvPair> {
+        ::std::mem::replace(&mut self.pairs, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for BatchPutRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.pairs {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.pairs)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.pairs {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.pairs {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BatchPutRequest {
+        BatchPutRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<KvPair>>(
+                    "pairs",
+                    |m: &BatchPutRequest| { &m.pairs },
+                    |m: &mut BatchPutRequest| { &mut m.pairs },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<BatchPutRequest>(
+                    "BatchPutRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static BatchPutRequest {
+        static mut instance: ::protobuf::lazy::Lazy<BatchPutRequest> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(BatchPutRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for BatchPutRequest {
+    fn clear(&mut self) {
+        self.pairs.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BatchPutRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BatchPutRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BatchPutResponse {
+    // message fields
+    pub success: bool,
+    pub errorMessage: ::std::string::String,
+    pub results: ::protobuf::RepeatedField<PutResponse>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BatchPutResponse {
+    fn default() -> &'a BatchPutResponse {
+        <BatchPutResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BatchPutResponse {
+    pub fn new() -> BatchPutResponse {
+        ::std::default::Default::default()
+    }
+
+    // bool success = 1;
+
+
+    pub fn get_success(&self) -> bool {
+        self.success
+    }
+    pub fn clear_success(&mut self) {
+        self.success = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_success(&mut self, v: bool) {
+        self.success = v;
+    }
+
+    // string errorMessage = 2;
+
+
+    pub fn get_errorMessage(&self) -> &str {
+        &self.errorMessage
+    }
+    pub fn clear_errorMessage(&mut self) {
+        self.errorMessage.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_errorMessage(&mut self, v: ::std::string::String) {
+        self.errorMessage = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_errorMessage(&mut self) -> &mut ::std::string::String {
+        &mut self.errorMessage
+    }
+
+    // Take field
+    pub fn take_errorMessage(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.errorMessage, ::std::string::String::new())
+    }
+
+    // repeated .PutResponse results = 3;
+
+
+    pub fn get_results(&self) -> &[PutResponse] {
+        &self.results
+    }
+    pub fn clear_results(&mut self) {
+        self.results.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_results(&mut self, v: ::protobuf::RepeatedField<PutResponse>) {
+        self.results = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_results(&mut self) -> &mut ::protobuf::RepeatedField<PutResponse> {
+        &mut self.results
+    }
+
+    // Take field
+    pub fn take_results(&mut self) -> ::protobuf::RepeatedField<PutResponse> {
+        ::std::mem::replace(&mut self.results, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for BatchPutResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.results {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.success = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.errorMessage)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.results)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.success != false {
+            my_size += 2;
+        }
+        if !self.errorMessage.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.errorMessage);
+        }
+        for value in &self.results {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.success != false {
+            os.write_bool(1, self.success)?;
+        }
+        if !self.errorMessage.is_empty() {
+            os.write_string(2, &self.errorMessage)?;
+        }
+        for v in &self.results {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BatchPutResponse {
+        BatchPutResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "success",
+                    |m: &BatchPutResponse| { &m.success },
+                    |m: &mut BatchPutResponse| { &mut m.success },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "errorMessage",
+                    |m: &BatchPutResponse| { &m.errorMessage },
+                    |m: &mut BatchPutResponse| { &mut m.errorMessage },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PutResponse>>(
+                    "results",
+                    |m: &BatchPutResponse| { &m.results },
+                    |m: &mut BatchPutResponse| { &mut m.results },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<BatchPutResponse>(
+                    "BatchPutResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static BatchPutResponse {
+        static mut instance: ::protobuf::lazy::Lazy<BatchPutResponse> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(BatchPutResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for BatchPutResponse {
+    fn clear(&mut self) {
+        self.success = false;
+        self.errorMessage.clear();
+        self.results.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BatchPutResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BatchPutResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BatchDeleteRequest {
+    // message fields
+    pub keys: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BatchDeleteRequest {
+    fn default() -> &'a BatchDeleteRequest {
+        <BatchDeleteRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BatchDeleteRequest {
+    pub fn new() -> BatchDeleteRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated bytes keys = 1;
+
+
+    pub fn get_keys(&self) -> &[::std::vec::Vec<u8>] {
+        &self.keys
+    }
+    pub fn clear_keys(&mut self) {
+        self.keys.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_keys(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.keys = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_keys(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.keys
+    }
+
+    // Take field
+    pub fn take_keys(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.keys, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for BatchDeleteRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.keys)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.keys {
+            my_size += ::protobuf::rt::bytes_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.keys {
+            os.write_bytes(1, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BatchDeleteRequest {
+        BatchDeleteRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "keys",
+                    |m: &BatchDeleteRequest| { &m.keys },
+                    |m: &mut BatchDeleteRequest| { &mut m.keys },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<BatchDeleteRequest>(
+                    "BatchDeleteRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static BatchDeleteRequest {
+        static mut instance: ::protobuf::lazy::Lazy<BatchDeleteRequest> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(BatchDeleteRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for BatchDeleteRequest {
+    fn clear(&mut self) {
+        self.keys.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BatchDeleteRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BatchDeleteRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BatchDeleteResponse {
+    // message fields
+    pub success: bool,
+    pub errorMessage: ::std::string::String,
+    pub results: ::protobuf::RepeatedField<DeleteResponse>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BatchDeleteResponse {
+    fn default() -> &'a BatchDeleteResponse {
+        <BatchDeleteResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BatchDeleteResponse {
+    pub fn new() -> BatchDeleteResponse {
+        ::std::default::Default::default()
+    }
+
+    // bool success = 1;
+
+
+    pub fn get_success(&self) -> bool {
+        self.success
+    }
+    pub fn clear_success(&mut self) {
+        self.success = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_success(&mut self, v: bool) {
+        self.success = v;
+    }
+
+    // string errorMessage = 2;
+
+
+    pub fn get_errorMessage(&self) -> &str {
+        &self.errorMessage
+    }
+    pub fn clear_errorMessage(&mut self) {
+        self.errorMessage.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_errorMessage(&mut self, v: ::std::string::String) {
+        self.errorMessage = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_errorMessage(&mut self) -> &mut ::std::string::String {
+        &mut self.errorMessage
+    }
+
+    // Take field
+    pub fn take_errorMessage(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.errorMessage, ::std::string::String::new())
+    }
+
+    // repeated .DeleteResponse results = 3;
+
+
+    pub fn get_results(&self) -> &[DeleteResponse] {
+        &self.results
+    }
+    pub fn clear_results(&mut self) {
+        self.results.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_results(&mut self, v: ::protobuf::RepeatedField<DeleteResponse>) {
+        self.results = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_results(&mut self) -> &mut ::protobuf::RepeatedField<DeleteResponse> {
+        &mut self.results
+    }
+
+    // Take field
+    pub fn take_results(&mut self) -> ::protobuf::RepeatedField<DeleteResponse> {
+        ::std::mem::replace(&mut self.results, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for BatchDeleteResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.results {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.success = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.errorMessage)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.results)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.success != false {
+            my_size += 2;
+        }
+        if !self.errorMessage.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.errorMessage);
+        }
+        for value in &self.results {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.success != false {
+            os.write_bool(1, self.success)?;
+        }
+        if !self.errorMessage.is_empty() {
+            os.write_string(2, &self.errorMessage)?;
+        }
+        for v in &self.results {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BatchDeleteResponse {
+        BatchDeleteResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "success",
+                    |m: &BatchDeleteResponse| { &m.success },
+                    |m: &mut BatchDeleteResponse| { &mut m.success },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "errorMessage",
+                    |m: &BatchDeleteResponse| { &m.errorMessage },
+                    |m: &mut BatchDeleteResponse| { &mut m.errorMessage },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DeleteResponse>>(
+                    "results",
+                    |m: &BatchDeleteResponse| { &m.results },
+                    |m: &mut BatchDeleteResponse| { &mut m.results },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<BatchDeleteResponse>(
+                    "BatchDeleteResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static BatchDeleteResponse {
+        static mut instance: ::protobuf::lazy::Lazy<BatchDeleteResponse> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(BatchDeleteResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for BatchDeleteResponse {
+    fn clear(&mut self) {
+        self.success = false;
+        self.errorMessage.clear();
+        self.results.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BatchDeleteResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BatchDeleteResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ExpireRequest {
+    // message fields
+    pub key: ::std::vec::Vec<u8>,
+    pub ttl_ms: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ExpireRequest {
+    fn default() -> &'a ExpireRequest {
+        <ExpireRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ExpireRequest {
+    pub fn new() -> ExpireRequest {
+        ::std::default::Default::default()
+    }
+
+    // bytes key = 1;
+
+
+    pub fn get_key(&self) -> &[u8] {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    }
+
+    // uint64 ttl_ms = 2;
+
+
+    pub fn get_ttl_ms(&self) -> u64 {
+        self.ttl_ms
+    }
+    pub fn clear_ttl_ms(&mut self) {
+        self.ttl_ms = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ttl_ms(&mut self, v: u64) {
+        self.ttl_ms = v;
+    }
+}
+
+impl ::protobuf::Message for ExpireRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.ttl_ms = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.key);
+        }
+        if self.ttl_ms != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.ttl_ms, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_bytes(1, &self.key)?;
+        }
+        if self.ttl_ms != 0 {
+            os.write_uint64(2, self.ttl_ms)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ExpireRequest {
+        ExpireRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "key",
+                    |m: &ExpireRequest| { &m.key },
+                    |m: &mut ExpireRequest| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "ttl_ms",
+                    |m: &ExpireRequest| { &m.ttl_ms },
+                    |m: &mut ExpireRequest| { &mut m.ttl_ms },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<ExpireRequest>(
+                    "ExpireRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ExpireRequest {
+        static mut instance: ::protobuf::lazy::Lazy<ExpireRequest> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(ExpireRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ExpireRequest {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.ttl_ms = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ExpireRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ExpireRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ExpireResponse {
+    // message fields
+    pub success: bool,
+    pub errorMessage: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ExpireResponse {
+    fn default() -> &'a ExpireResponse {
+        <ExpireResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ExpireResponse {
+    pub fn new() -> ExpireResponse {
+        ::std::default::Default::default()
+    }
+
+    // bool success = 1;
+
+
+    pub fn get_success(&self) -> bool {
+        self.success
+    }
+    pub fn clear_success(&mut self) {
+        self.success = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_success(&mut self, v: bool) {
+        self.success = v;
+    }
+
+    // string errorMessage = 2;
+
+
+    pub fn get_errorMessage(&self) -> &str {
+        &self.errorMessage
+    }
+    pub fn clear_errorMessage(&mut self) {
+        self.errorMessage.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_errorMessage(&mut self, v: ::std::string::String) {
+        self.errorMessage = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_errorMessage(&mut self) -> &mut ::std::string::String {
+        &mut self.errorMessage
+    }
+
+    // Take field
+    pub fn take_errorMessage(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.errorMessage, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ExpireResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.success = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.errorMessage)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.success != false {
+            my_size += 2;
+        }
+        if !self.errorMessage.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.errorMessage);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.success != false {
+            os.write_bool(1, self.success)?;
+        }
+        if !self.errorMessage.is_empty() {
+            os.write_string(2, &self.errorMessage)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ExpireResponse {
+        ExpireResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "success",
+                    |m: &ExpireResponse| { &m.success },
+                    |m: &mut ExpireResponse| { &mut m.success },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "errorMessage",
+                    |m: &ExpireResponse| { &m.errorMessage },
+                    |m: &mut ExpireResponse| { &mut m.errorMessage },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<ExpireResponse>(
+                    "ExpireResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ExpireResponse {
+        static mut instance: ::protobuf::lazy::Lazy<ExpireResponse> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(ExpireResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ExpireResponse {
+    fn clear(&mut self) {
+        self.success = false;
+        self.errorMessage.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ExpireResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ExpireResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TtlRequest {
+    // message fields
+    pub key: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a TtlRequest {
+    fn default() -> &'a TtlRequest {
+        <TtlRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TtlRequest {
+    pub fn new() -> TtlRequest {
+        ::std::default::Default::default()
+    }
+
+    // bytes key = 1;
+
+
+    pub fn get_key(&self) -> &[u8] {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for TtlRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.key);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_bytes(1, &self.key)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> TtlRequest {
+        TtlRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "key",
+                    |m: &TtlRequest| { &m.key },
+                    |m: &mut TtlRequest| { &mut m.key },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<TtlRequest>(
+                    "TtlRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static TtlRequest {
+        static mut instance: ::protobuf::lazy::Lazy<TtlRequest> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(TtlRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for TtlRequest {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TtlRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TtlRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TtlResponse {
+    // message fields
+    pub success: bool,
+    pub errorMessage: ::std::string::String,
+    pub expires: bool,
+    pub ttl_ms: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a TtlResponse {
+    fn default() -> &'a TtlResponse {
+        <TtlResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TtlResponse {
+    pub fn new() -> TtlResponse {
+        ::std::default::Default::default()
+    }
+
+    // bool success = 1;
+
+
+    pub fn get_success(&self) -> bool {
+        self.success
+    }
+    pub fn clear_success(&mut self) {
+        self.success = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_success(&mut self, v: bool) {
+        self.success = v;
+    }
+
+    // string errorMessage = 2;
+
+
+    pub fn get_errorMessage(&self) -> &str {
+        &self.errorMessage
+    }
+    pub fn clear_errorMessage(&mut self) {
+        self.errorMessage.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_errorMessage(&mut self, v: ::std::string::String) {
+        self.errorMessage = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_errorMessage(&mut self) -> &mut ::std::string::String {
+        &mut self.errorMessage
+    }
+
+    // Take field
+    pub fn take_errorMessage(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.errorMessage, ::std::string::String::new())
+    }
+
+    // bool expires = 3;
+
+
+    pub fn get_expires(&self) -> bool {
+        self.expires
+    }
+    pub fn clear_expires(&mut self) {
+        self.expires = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_expires(&mut self, v: bool) {
+        self.expires = v;
+    }
+
+    // uint64 ttl_ms = 4;
+
+
+    pub fn get_ttl_ms(&self) -> u64 {
+        self.ttl_ms
+    }
+    pub fn clear_ttl_ms(&mut self) {
+        self.ttl_ms = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ttl_ms(&mut self, v: u64) {
+        self.ttl_ms = v;
+    }
+}
+
+impl ::protobuf::Message for TtlResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.success = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.errorMessage)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.expires = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.ttl_ms = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.success != false {
+            my_size += 2;
+        }
+        if !self.errorMessage.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.errorMessage);
+        }
+        if self.expires != false {
+            my_size += 2;
+        }
+        if self.ttl_ms != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.ttl_ms, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.success != false {
+            os.write_bool(1, self.success)?;
+        }
+        if !self.errorMessage.is_empty() {
+            os.write_string(2, &self.errorMessage)?;
+        }
+        if self.expires != false {
+            os.write_bool(3, self.expires)?;
+        }
+        if self.ttl_ms != 0 {
+            os.write_uint64(4, self.ttl_ms)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> TtlResponse {
+        TtlResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "success",
+                    |m: &TtlResponse| { &m.success },
+                    |m: &mut TtlResponse| { &mut m.success },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "errorMessage",
+                    |m: &TtlResponse| { &m.errorMessage },
+                    |m: &mut TtlResponse| { &mut m.errorMessage },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "expires",
+                    |m: &TtlResponse| { &m.expires },
+                    |m: &mut TtlResponse| { &mut m.expires },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "ttl_ms",
+                    |m: &TtlResponse| { &m.ttl_ms },
+                    |m: &mut TtlResponse| { &mut m.ttl_ms },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<TtlResponse>(
+                    "TtlResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static TtlResponse {
+        static mut instance: ::protobuf::lazy::Lazy<TtlResponse> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(TtlResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for TtlResponse {
+    fn clear(&mut self) {
+        self.success = false;
+        self.errorMessage.clear();
+        self.expires = false;
+        self.ttl_ms = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TtlResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TtlResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TxnWrite {
+    // message fields
+    pub kind: TxnWrite_Kind,
+    pub key: ::std::vec::Vec<u8>,
+    pub value: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a TxnWrite {
+    fn default() -> &'a TxnWrite {
+        <TxnWrite as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TxnWrite {
+    pub fn new() -> TxnWrite {
+        ::std::default::Default::default()
+    }
+
+    // .TxnWrite.Kind kind = 1;
+
+
+    pub fn get_kind(&self) -> TxnWrite_Kind {
+        self.kind
+    }
+    pub fn clear_kind(&mut self) {
+        self.kind = TxnWrite_Kind::PUT;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: TxnWrite_Kind) {
+        self.kind = v;
+    }
+
+    // bytes key = 2;
+
+
+    pub fn get_key(&self) -> &[u8] {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    }
+
+    // bytes value = 3;
+
+
+    pub fn get_value(&self) -> &[u8] {
+        &self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for TxnWrite {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.kind, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.kind != TxnWrite_Kind::PUT {
+            my_size += ::protobuf::rt::enum_size(1, self.kind);
+        }
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.key);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.value);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.kind != TxnWrite_Kind::PUT {
+            os.write_enum(1, self.kind.value())?;
+        }
+        if !self.key.is_empty() {
+            os.write_bytes(2, &self.key)?;
+        }
+        if !self.value.is_empty() {
+            os.write_bytes(3, &self.value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> TxnWrite {
+        TxnWrite::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<TxnWrite_Kind>>(
+                    "kind",
+                    |m: &TxnWrite| { &m.kind },
+                    |m: &mut TxnWrite| { &mut m.kind },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "key",
+                    |m: &TxnWrite| { &m.key },
+                    |m: &mut TxnWrite| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "value",
+                    |m: &TxnWrite| { &m.value },
+                    |m: &mut TxnWrite| { &mut m.value },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<TxnWrite>(
+                    "TxnWrite",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static TxnWrite {
+        static mut instance: ::protobuf::lazy::Lazy<TxnWrite> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(TxnWrite::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for TxnWrite {
+    fn clear(&mut self) {
+        self.kind = TxnWrite_Kind::PUT;
+        self.key.clear();
+        self.value.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TxnWrite {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TxnWrite {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum TxnWrite_Kind {
+    PUT = 0,
+    DELETE = 1,
+}
+
+impl ::protobuf::ProtobufEnum for TxnWrite_Kind {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<TxnWrite_Kind> {
+        match value {
+            0 => ::std::option::Option::Some(TxnWrite_Kind::PUT),
+            1 => ::std::option::Option::Some(TxnWrite_Kind::DELETE),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [TxnWrite_Kind] = &[
+            TxnWrite_Kind::PUT,
+            TxnWrite_Kind::DELETE,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new_pb_name::<TxnWrite_Kind>("TxnWrite.Kind", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for TxnWrite_Kind {
+}
+
+impl ::std::default::Default for TxnWrite_Kind {
+    fn default() -> Self {
+        TxnWrite_Kind::PUT
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TxnWrite_Kind {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(self.descriptor())
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TxnCondition {
+    // message fields
+    pub key: ::std::vec::Vec<u8>,
+    pub condition: ::protobuf::SingularPtrField<Condition>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a TxnCondition {
+    fn default() -> &'a TxnCondition {
+        <TxnCondition as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TxnCondition {
+    pub fn new() -> TxnCondition {
+        ::std::default::Default::default()
+    }
+
+    // bytes key = 1;
+
+
+    pub fn get_key(&self) -> &[u8] {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    }
+
+    // .Condition condition = 2;
+
+
+    pub fn get_condition(&self) -> &Condition {
+        self.condition.as_ref().unwrap_or_else(|| Condition::default_instance())
+    }
+    pub fn clear_condition(&mut self) {
+        self.condition.clear();
+    }
+
+    pub fn has_condition(&self) -> bool {
+        self.condition.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_condition(&mut self, v: Condition) {
+        self.condition = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_condition(&mut self) -> &mut Condition {
+        if self.condition.is_none() {
+            self.condition.set_default();
+        }
+        self.condition.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_condition(&mut self) -> Condition {
+        self.condition.take().unwrap_or_else(|| Condition::new())
+    }
+}
+
+impl ::protobuf::Message for TxnCondition {
+    fn is_initialized(&self) -> bool {
+        for v in &self.condition {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.condition)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.key);
+        }
+        if let Some(ref v) = self.condition.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_bytes(1, &self.key)?;
+        }
+        if let Some(ref v) = self.condition.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> TxnCondition {
+        TxnCondition::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "key",
+                    |m: &TxnCondition| { &m.key },
+                    |m: &mut TxnCondition| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Condition>>(
+                    "condition",
+                    |m: &TxnCondition| { &m.condition },
+                    |m: &mut TxnCondition| { &mut m.condition },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<TxnCondition>(
+                    "TxnCondition",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static TxnCondition {
+        static mut instance: ::protobuf::lazy::Lazy<TxnCondition> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(TxnCondition::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for TxnCondition {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.condition.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TxnCondition {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TxnCondition {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TxnRequest {
+    // message fields
+    pub start_ts: u64,
+    pub read_keys: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    pub conditions: ::protobuf::RepeatedField<TxnCondition>,
+    pub writes: ::protobuf::RepeatedField<TxnWrite>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a TxnRequest {
+    fn default() -> &'a TxnRequest {
+        <TxnRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TxnRequest {
+    pub fn new() -> TxnRequest {
+        ::std::default::Default::default()
+    }
+
+    // uint64 start_ts = 1;
+
+
+    pub fn get_start_ts(&self) -> u64 {
+        self.start_ts
+    }
+    pub fn clear_start_ts(&mut self) {
+        self.start_ts = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start_ts(&mut self, v: u64) {
+        self.start_ts = v;
+    }
+
+    // repeated bytes read_keys = 2;
+
+
+    pub fn get_read_keys(&self) -> &[::std::vec::Vec<u8>] {
+        &self.read_keys
+    }
+    pub fn clear_read_keys(&mut self) {
+        self.read_keys.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_read_keys(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.read_keys = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_read_keys(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.read_keys
+    }
+
+    // Take field
+    pub fn take_read_keys(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.read_keys, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .TxnCondition conditions = 3;
+
+
+    pub fn get_conditions(&self) -> &[TxnCondition] {
+        &self.conditions
+    }
+    pub fn clear_conditions(&mut self) {
+        self.conditions.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_conditions(&mut self, v: ::protobuf::RepeatedField<TxnCondition>) {
+        self.conditions = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_conditions(&mut self) -> &mut ::protobuf::RepeatedField<TxnCondition> {
+        &mut self.conditions
+    }
+
+    // Take field
+    pub fn take_conditions(&mut self) -> ::protobuf::RepeatedField<TxnCondition> {
+        ::std::mem::replace(&mut self.conditions, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .TxnWrite writes = 4;
+
+
+    pub fn get_writes(&self) -> &[TxnWrite] {
+        &self.writes
+    }
+    pub fn clear_writes(&mut self) {
+        self.writes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_writes(&mut self, v: ::protobuf::RepeatedField<TxnWrite>) {
+        self.writes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_writes(&mut self) -> &mut ::protobuf::RepeatedField<TxnWrite> {
+        &mut self.writes
+    }
+
+    // Take field
+    pub fn take_writes(&mut self) -> ::protobuf::RepeatedField<TxnWrite> {
+        ::std::mem::replace(&mut self.writes, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for TxnRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.conditions {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.writes {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.start_ts = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.read_keys)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.conditions)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.writes)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.start_ts != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.start_ts, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.read_keys {
+            my_size += ::protobuf::rt::bytes_size(2, &value);
+        };
+        for value in &self.conditions {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.writes {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.start_ts != 0 {
+            os.write_uint64(1, self.start_ts)?;
+        }
+        for v in &self.read_keys {
+            os.write_bytes(2, &v)?;
+        };
+        for v in &self.conditions {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.writes {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> TxnRequest {
+        TxnRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "start_ts",
+                    |m: &TxnRequest| { &m.start_ts },
+                    |m: &mut TxnRequest| { &mut m.start_ts },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "read_keys",
+                    |m: &TxnRequest| { &m.read_keys },
+                    |m: &mut TxnRequest| { &mut m.read_keys },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<TxnCondition>>(
+                    "conditions",
+                    |m: &TxnRequest| { &m.conditions },
+                    |m: &mut TxnRequest| { &mut m.conditions },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<TxnWrite>>(
+                    "writes",
+                    |m: &TxnRequest| { &m.writes },
+                    |m: &mut TxnRequest| { &mut m.writes },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<TxnRequest>(
+                    "TxnRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static TxnRequest {
+        static mut instance: ::protobuf::lazy::Lazy<TxnRequest> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(TxnRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for TxnRequest {
+    fn clear(&mut self) {
+        self.start_ts = 0;
+        self.read_keys.clear();
+        self.conditions.clear();
+        self.writes.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TxnRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TxnRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TxnResponse {
+    // message fields
+    pub success: bool,
+    pub errorMessage: ::std::string::String,
+    pub conflict: bool,
+    pub conflict_key: ::std::vec::Vec<u8>,
+    pub condition_failed: bool,
+    pub failed_condition: u32,
+    pub exists: bool,
+    pub current_value: ::std::vec::Vec<u8>,
+    pub version: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a TxnResponse {
+    fn default() -> &'a TxnResponse {
+        <TxnResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TxnResponse {
+    pub fn new() -> TxnResponse {
+        ::std::default::Default::default()
+    }
+
+    // bool success = 1;
+
+
+    pub fn get_success(&self) -> bool {
+        self.success
+    }
+    pub fn clear_success(&mut self) {
+        self.success = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_success(&mut self, v: bool) {
+        self.success = v;
+    }
+
+    // string errorMessage = 2;
+
+
+    pub fn get_errorMessage(&self) -> &str {
+        &self.errorMessage
+    }
+    pub fn clear_errorMessage(&mut self) {
+        self.errorMessage.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_errorMessage(&mut self, v: ::std::string::String) {
+        self.errorMessage = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_errorMessage(&mut self) -> &mut ::std::string::String {
+        &mut self.errorMessage
+    }
+
+    // Take field
+    pub fn take_errorMessage(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.errorMessage, ::std::string::String::new())
+    }
+
+    // bool conflict = 3;
+
+
+    pub fn get_conflict(&self) -> bool {
+        self.conflict
+    }
+    pub fn clear_conflict(&mut self) {
+        self.conflict = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_conflict(&mut self, v: bool) {
+        self.conflict = v;
+    }
+
+    // bytes conflict_key = 4;
+
+
+    pub fn get_conflict_key(&self) -> &[u8] {
+        &self.conflict_key
+    }
+    pub fn clear_conflict_key(&mut self) {
+        self.conflict_key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_conflict_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.conflict_key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_conflict_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.conflict_key
+    }
+
+    // Take field
+    pub fn take_conflict_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.conflict_key, ::std::vec::Vec::new())
+    }
+
+    // bool condition_failed = 5;
+
+
+    pub fn get_condition_failed(&self) -> bool {
+        self.condition_failed
+    }
+    pub fn clear_condition_failed(&mut self) {
+        self.condition_failed = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_condition_failed(&mut self, v: bool) {
+        self.condition_failed = v;
+    }
+
+    // uint32 failed_condition = 6;
+
+
+    pub fn get_failed_condition(&self) -> u32 {
+        self.failed_condition
+    }
+    pub fn clear_failed_condition(&mut self) {
+        self.failed_condition = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_failed_condition(&mut self, v: u32) {
+        self.failed_condition = v;
+    }
+
+    // bool exists = 7;
+
+
+    pub fn get_exists(&self) -> bool {
+        self.exists
+    }
+    pub fn clear_exists(&mut self) {
+        self.exists = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exists(&mut self, v: bool) {
+        self.exists = v;
+    }
+
+    // bytes current_value = 8;
+
+
+    pub fn get_current_value(&self) -> &[u8] {
+        &self.current_value
+    }
+    pub fn clear_current_value(&mut self) {
+        self.current_value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_current_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.current_value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_current_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.current_value
+    }
+
+    // Take field
+    pub fn take_current_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.current_value, ::std::vec::Vec::new())
+    }
+
+    // uint64 version = 9;
+
+
+    pub fn get_version(&self) -> u64 {
+        self.version
+    }
+    pub fn clear_version(&mut self) {
+        self.version = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_version(&mut self, v: u64) {
+        self.version = v;
+    }
+}
+
+impl ::protobuf::Message for TxnResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.success = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.errorMessage)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.conflict = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.conflict_key)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.condition_failed = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.failed_condition = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.exists = tmp;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.current_value)?;
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.version = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.success != false {
+            my_size += 2;
+        }
+        if !self.errorMessage.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.errorMessage);
+        }
+        if self.conflict != false {
+            my_size += 2;
+        }
+        if !self.conflict_key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.conflict_key);
+        }
+        if self.condition_failed != false {
+            my_size += 2;
+        }
+        if self.failed_condition != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.failed_condition, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.exists != false {
+            my_size += 2;
+        }
+        if !self.current_value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(8, &self.current_value);
+        }
+        if self.version != 0 {
+            my_size += ::protobuf::rt::value_size(9, self.version, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.success != false {
+            os.write_bool(1, self.success)?;
+        }
+        if !self.errorMessage.is_empty() {
+            os.write_string(2, &self.errorMessage)?;
+        }
+        if self.conflict != false {
+            os.write_bool(3, self.conflict)?;
+        }
+        if !self.conflict_key.is_empty() {
+            os.write_bytes(4, &self.conflict_key)?;
+        }
+        if self.condition_failed != false {
+            os.write_bool(5, self.condition_failed)?;
+        }
+        if self.failed_condition != 0 {
+            os.write_uint32(6, self.failed_condition)?;
+        }
+        if self.exists != false {
+            os.write_bool(7, self.exists)?;
+        }
+        if !self.current_value.is_empty() {
+            os.write_bytes(8, &self.current_value)?;
+        }
+        if self.version != 0 {
+            os.write_uint64(9, self.version)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> TxnResponse {
+        TxnResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "success",
+                    |m: &TxnResponse| { &m.success },
+                    |m: &mut TxnResponse| { &mut m.success },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "errorMessage",
+                    |m: &TxnResponse| { &m.errorMessage },
+                    |m: &mut TxnResponse| { &mut m.errorMessage },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "conflict",
+                    |m: &TxnResponse| { &m.conflict },
+                    |m: &mut TxnResponse| { &mut m.conflict },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "conflict_key",
+                    |m: &TxnResponse| { &m.conflict_key },
+                    |m: &mut TxnResponse| { &mut m.conflict_key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "condition_failed",
+                    |m: &TxnResponse| { &m.condition_failed },
+                    |m: &mut TxnResponse| { &mut m.condition_failed },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "failed_condition",
+                    |m: &TxnResponse| { &m.failed_condition },
+                    |m: &mut TxnResponse| { &mut m.failed_condition },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "exists",
+                    |m: &TxnResponse| { &m.exists },
+                    |m: &mut TxnResponse| { &mut m.exists },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "current_value",
+                    |m: &TxnResponse| { &m.current_value },
+                    |m: &mut TxnResponse| { &mut m.current_value },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "version",
+                    |m: &TxnResponse| { &m.version },
+                    |m: &mut TxnResponse| { &mut m.version },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<TxnResponse>(
+                    "TxnResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static TxnResponse {
+        static mut instance: ::protobuf::lazy::Lazy<TxnResponse> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(TxnResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for TxnResponse {
+    fn clear(&mut self) {
+        self.success = false;
+        self.errorMessage.clear();
+        self.conflict = false;
+        self.conflict_key.clear();
+        self.condition_failed = false;
+        self.failed_condition = 0;
+        self.exists = false;
+        self.current_value.clear();
+        self.version = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TxnResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TxnResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GetTimestampRequest {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GetTimestampRequest {
+    fn default() -> &'a GetTimestampRequest {
+        <GetTimestampRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetTimestampRequest {
+    pub fn new() -> GetTimestampRequest {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for GetTimestampRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GetTimestampRequest {
+        GetTimestampRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<GetTimestampRequest>(
+                    "GetTimestampRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static GetTimestampRequest {
+        static mut instance: ::protobuf::lazy::Lazy<GetTimestampRequest> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(GetTimestampRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for GetTimestampRequest {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GetTimestampRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetTimestampRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GetTimestampResponse {
+    // message fields
+    pub success: bool,
+    pub errorMessage: ::std::string::String,
+    pub timestamp: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GetTimestampResponse {
+    fn default() -> &'a GetTimestampResponse {
+        <GetTimestampResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetTimestampResponse {
+    pub fn new() -> GetTimestampResponse {
+        ::std::default::Default::default()
+    }
+
+    // bool success = 1;
+
+
+    pub fn get_success(&self) -> bool {
+        self.success
+    }
+    pub fn clear_success(&mut self) {
+        self.success = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_success(&mut self, v: bool) {
+        self.success = v;
+    }
+
+    // string errorMessage = 2;
+
+
+    pub fn get_errorMessage(&self) -> &str {
+        &self.errorMessage
+    }
+    pub fn clear_errorMessage(&mut self) {
+        self.errorMessage.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_errorMessage(&mut self, v: ::std::string::String) {
+        self.errorMessage = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_errorMessage(&mut self) -> &mut ::std::string::String {
+        &mut self.errorMessage
+    }
+
+    // Take field
+    pub fn take_errorMessage(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.errorMessage, ::std::string::String::new())
+    }
+
+    // uint64 timestamp = 3;
+
+
+    pub fn get_timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    pub fn clear_timestamp(&mut self) {
+        self.timestamp = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_timestamp(&mut self, v: u64) {
+        self.timestamp = v;
+    }
+}
+
+impl ::protobuf::Message for GetTimestampResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.success = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.errorMessage)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.timestamp = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.success != false {
+            my_size += 2;
+        }
+        if !self.errorMessage.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.errorMessage);
+        }
+        if self.timestamp != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.timestamp, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.success != false {
+            os.write_bool(1, self.success)?;
+        }
+        if !self.errorMessage.is_empty() {
+            os.write_string(2, &self.errorMessage)?;
+        }
+        if self.timestamp != 0 {
+            os.write_uint64(3, self.timestamp)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GetTimestampResponse {
+        GetTimestampResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "success",
+                    |m: &GetTimestampResponse| { &m.success },
+                    |m: &mut GetTimestampResponse| { &mut m.success },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "errorMessage",
+                    |m: &GetTimestampResponse| { &m.errorMessage },
+                    |m: &mut GetTimestampResponse| { &mut m.errorMessage },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "timestamp",
+                    |m: &GetTimestampResponse| { &m.timestamp },
+                    |m: &mut GetTimestampResponse| { &mut m.timestamp },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<GetTimestampResponse>(
+                    "GetTimestampResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static GetTimestampResponse {
+        static mut instance: ::protobuf::lazy::Lazy<GetTimestampResponse> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(GetTimestampResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for GetTimestampResponse {
+    fn clear(&mut self) {
+        self.success = false;
+        self.errorMessage.clear();
+        self.timestamp = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GetTimestampResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetTimestampResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LockInfo {
+    // message fields
+    pub key: ::std::vec::Vec<u8>,
+    pub primary: ::std::vec::Vec<u8>,
+    pub start_ts: u64,
+    pub ttl_ms: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a LockInfo {
+    fn default() -> &'a LockInfo {
+        <LockInfo as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LockInfo {
+    pub fn new() -> LockInfo {
+        ::std::default::Default::default()
+    }
+
+    // bytes key = 1;
+
+
+    pub fn get_key(&self) -> &[u8] {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    }
+
+    // bytes primary = 2;
+
+
+    pub fn get_primary(&self) -> &[u8] {
+        &self.primary
+    }
+    pub fn clear_primary(&mut self) {
+        self.primary.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_primary(&mut self, v: ::std::vec::Vec<u8>) {
+        self.primary = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_primary(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.primary
+    }
+
+    // Take field
+    pub fn take_primary(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.primary, ::std::vec::Vec::new())
+    }
+
+    // uint64 start_ts = 3;
+
+
+    pub fn get_start_ts(&self) -> u64 {
+        self.start_ts
+    }
+    pub fn clear_start_ts(&mut self) {
+        self.start_ts = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start_ts(&mut self, v: u64) {
+        self.start_ts = v;
+    }
+
+    // uint64 ttl_ms = 4;
+
+
+    pub fn get_ttl_ms(&self) -> u64 {
+        self.ttl_ms
+    }
+    pub fn clear_ttl_ms(&mut self) {
+        self.ttl_ms = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ttl_ms(&mut self, v: u64) {
+        self.ttl_ms = v;
+    }
+}
+
+impl ::protobuf::Message for LockInfo {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.primary)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.start_ts = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.ttl_ms = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.key);
+        }
+        if !self.primary.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.primary);
+        }
+        if self.start_ts != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.start_ts, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.ttl_ms != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.ttl_ms, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_bytes(1, &self.key)?;
+        }
+        if !self.primary.is_empty() {
+            os.write_bytes(2, &self.primary)?;
+        }
+        if self.start_ts != 0 {
+            os.write_uint64(3, self.start_ts)?;
+        }
+        if self.ttl_ms != 0 {
+            os.write_uint64(4, self.ttl_ms)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LockInfo {
+        LockInfo::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "key",
+                    |m: &LockInfo| { &m.key },
+                    |m: &mut LockInfo| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "primary",
+                    |m: &LockInfo| { &m.primary },
+                    |m: &mut LockInfo| { &mut m.primary },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "start_ts",
+                    |m: &LockInfo| { &m.start_ts },
+                    |m: &mut LockInfo| { &mut m.start_ts },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "ttl_ms",
+                    |m: &LockInfo| { &m.ttl_ms },
+                    |m: &mut LockInfo| { &mut m.ttl_ms },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<LockInfo>(
+                    "LockInfo",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static LockInfo {
+        static mut instance: ::protobuf::lazy::Lazy<LockInfo> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(LockInfo::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for LockInfo {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.primary.clear();
+        self.start_ts = 0;
+        self.ttl_ms = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LockInfo {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LockInfo {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct KvGetRequest {
+    // message fields
+    pub key: ::std::vec::Vec<u8>,
+    pub start_ts: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a KvGetRequest {
+    fn default() -> &'a KvGetRequest {
+        <KvGetRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl KvGetRequest {
+    pub fn new() -> KvGetRequest {
+        ::std::default::Default::default()
+    }
+
+    // bytes key = 1;
+
+
+    pub fn get_key(&self) -> &[u8] {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    }
+
+    // uint64 start_ts = 2;
+
+
+    pub fn get_start_ts(&self) -> u64 {
+        self.start_ts
+    }
+    pub fn clear_start_ts(&mut self) {
+        self.start_ts = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start_ts(&mut self, v: u64) {
+        self.start_ts = v;
+    }
+}
+
+impl ::protobuf::Message for KvGetRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.start_ts = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.key);
+        }
+        if self.start_ts != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.start_ts, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_bytes(1, &self.key)?;
+        }
+        if self.start_ts != 0 {
+            os.write_uint64(2, self.start_ts)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> KvGetRequest {
+        KvGetRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "key",
+                    |m: &KvGetRequest| { &m.key },
+                    |m: &mut KvGetRequest| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "start_ts",
+                    |m: &KvGetRequest| { &m.start_ts },
+                    |m: &mut KvGetRequest| { &mut m.start_ts },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<KvGetRequest>(
+                    "KvGetRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static KvGetRequest {
+        static mut instance: ::protobuf::lazy::Lazy<KvGetRequest> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(KvGetRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for KvGetRequest {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.start_ts = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for KvGetRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for KvGetRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct KvGetResponse {
+    // message fields
+    pub success: bool,
+    pub errorMessage: ::std::string::String,
+    pub exists: bool,
+    pub value: ::std::vec::Vec<u8>,
+    pub locked: ::protobuf::SingularPtrField<LockInfo>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a KvGetResponse {
+    fn default() -> &'a KvGetResponse {
+        <KvGetResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl KvGetResponse {
+    pub fn new() -> KvGetResponse {
+        ::std::default::Default::default()
+    }
+
+    // bool success = 1;
+
+
+    pub fn get_success(&self) -> bool {
+        self.success
+    }
+    pub fn clear_success(&mut self) {
+        self.success = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_success(&mut self, v: bool) {
+        self.success = v;
+    }
+
+    // string errorMessage = 2;
+
+
+    pub fn get_errorMessage(&self) -> &str {
+        &self.errorMessage
+    }
+    pub fn clear_errorMessage(&mut self) {
+        self.errorMessage.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_errorMessage(&mut self, v: ::std::string::String) {
+        self.errorMessage = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_errorMessage(&mut self) -> &mut ::std::string::String {
+        &mut self.errorMessage
+    }
+
+    // Take field
+    pub fn take_errorMessage(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.errorMessage, ::std::string::String::new())
+    }
+
+    // bool exists = 3;
+
+
+    pub fn get_exists(&self) -> bool {
+        self.exists
+    }
+    pub fn clear_exists(&mut self) {
+        self.exists = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exists(&mut self, v: bool) {
+        self.exists = v;
+    }
+
+    // bytes value = 4;
+
+
+    pub fn get_value(&self) -> &[u8] {
+        &self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    }
+
+    // .LockInfo locked = 5;
+
+
+    pub fn get_locked(&self) -> &LockInfo {
+        self.locked.as_ref().unwrap_or_else(|| LockInfo::default_instance())
+    }
+    pub fn clear_locked(&mut self) {
+        self.locked.clear();
+    }
+
+    pub fn has_locked(&self) -> bool {
+        self.locked.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_locked(&mut self, v: LockInfo) {
+        self.locked = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_locked(&mut self) -> &mut LockInfo {
+        if self.locked.is_none() {
+            self.locked.set_default();
+        }
+        self.locked.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_locked(&mut self) -> LockInfo {
+        self.locked.take().unwrap_or_else(|| LockInfo::new())
+    }
+}
+
+impl ::protobuf::Message for KvGetResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.locked {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.success = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.errorMessage)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.exists = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.locked)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.success != false {
+            my_size += 2;
+        }
+        if !self.errorMessage.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.errorMessage);
+        }
+        if self.exists != false {
+            my_size += 2;
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.value);
+        }
+        if let Some(ref v) = self.locked.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.success != false {
+            os.write_bool(1, self.success)?;
+        }
+        if !self.errorMessage.is_empty() {
+            os.write_string(2, &self.errorMessage)?;
+        }
+        if self.exists != false {
+            os.write_bool(3, self.exists)?;
+        }
+        if !self.value.is_empty() {
+            os.write_bytes(4, &self.value)?;
+        }
+        if let Some(ref v) = self.locked.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> KvGetResponse {
+        KvGetResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "success",
+                    |m: &KvGetResponse| { &m.success },
+                    |m: &mut KvGetResponse| { &mut m.success },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "errorMessage",
+                    |m: &KvGetResponse| { &m.errorMessage },
+                    |m: &mut KvGetResponse| { &mut m.errorMessage },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "exists",
+                    |m: &KvGetResponse| { &m.exists },
+                    |m: &mut KvGetResponse| { &mut m.exists },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "value",
+                    |m: &KvGetResponse| { &m.value },
+                    |m: &mut KvGetResponse| { &mut m.value },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<LockInfo>>(
+                    "locked",
+                    |m: &KvGetResponse| { &m.locked },
+                    |m: &mut KvGetResponse| { &mut m.locked },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<KvGetResponse>(
+                    "KvGetResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static KvGetResponse {
+        static mut instance: ::protobuf::lazy::Lazy<KvGetResponse> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(KvGetResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for KvGetResponse {
+    fn clear(&mut self) {
+        self.success = false;
+        self.errorMessage.clear();
+        self.exists = false;
+        self.value.clear();
+        self.locked.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for KvGetResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for KvGetResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct KvPrewriteRequest {
+    // message fields
+    pub mutations: ::protobuf::RepeatedField<TxnWrite>,
+    pub primary: ::std::vec::Vec<u8>,
+    pub start_ts: u64,
+    pub lock_ttl_ms: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a KvPrewriteRequest {
+    fn default() -> &'a KvPrewriteRequest {
+        <KvPrewriteRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl KvPrewriteRequest {
+    pub fn new() -> KvPrewriteRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated .TxnWrite mutations = 1;
+
+
+    pub fn get_mutations(&self) -> &[TxnWrite] {
+        &self.mutations
+    }
+    pub fn clear_mutations(&mut self) {
+        self.mutations.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mutations(&mut self, v: ::protobuf::RepeatedField<TxnWrite>) {
+        self.mutations = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_mutations(&mut self) -> &mut ::protobuf::RepeatedField<TxnWrite> {
+        &mut self.mutations
+    }
+
+    // Take field
+    pub fn take_mutations(&mut self) -> ::protobuf::RepeatedField<TxnWrite> {
+        ::std::mem::replace(&mut self.mutations, ::protobuf::RepeatedField::new())
+    }
+
+    // bytes primary = 2;
+
+
+    pub fn get_primary(&self) -> &[u8] {
+        &self.primary
+    }
+    pub fn clear_primary(&mut self) {
+        self.primary.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_primary(&mut self, v: ::std::vec::Vec<u8>) {
+        self.primary = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_primary(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.primary
+    }
+
+    // Take field
+    pub fn take_primary(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.primary, ::std::vec::Vec::new())
+    }
+
+    // uint64 start_ts = 3;
+
+
+    pub fn get_start_ts(&self) -> u64 {
+        self.start_ts
+    }
+    pub fn clear_start_ts(&mut self) {
+        self.start_ts = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start_ts(&mut self, v: u64) {
+        self.start_ts = v;
+    }
+
+    // uint64 lock_ttl_ms = 4;
+
+
+    pub fn get_lock_ttl_ms(&self) -> u64 {
+        self.lock_ttl_ms
+    }
+    pub fn clear_lock_ttl_ms(&mut self) {
+        self.lock_ttl_ms = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_lock_ttl_ms(&mut self, v: u64) {
+        self.lock_ttl_ms = v;
+    }
+}
+
+impl ::protobuf::Message for KvPrewriteRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.mutations {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.mutations)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.primary)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.start_ts = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.lock_ttl_ms = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.mutations {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if !self.primary.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.primary);
+        }
+        if self.start_ts != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.start_ts, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.lock_ttl_ms != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.lock_ttl_ms, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.mutations {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if !self.primary.is_empty() {
+            os.write_bytes(2, &self.primary)?;
+        }
+        if self.start_ts != 0 {
+            os.write_uint64(3, self.start_ts)?;
+        }
+        if self.lock_ttl_ms != 0 {
+            os.write_uint64(4, self.lock_ttl_ms)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> KvPrewriteRequest {
+        KvPrewriteRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<TxnWrite>>(
+                    "mutations",
+                    |m: &KvPrewriteRequest| { &m.mutations },
+                    |m: &mut KvPrewriteRequest| { &mut m.mutations },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "primary",
+                    |m: &KvPrewriteRequest| { &m.primary },
+                    |m: &mut KvPrewriteRequest| { &mut m.primary },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "start_ts",
+                    |m: &KvPrewriteRequest| { &m.start_ts },
+                    |m: &mut KvPrewriteRequest| { &mut m.start_ts },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "lock_ttl_ms",
+                    |m: &KvPrewriteRequest| { &m.lock_ttl_ms },
+                    |m: &mut KvPrewriteRequest| { &mut m.lock_ttl_ms },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<KvPrewriteRequest>(
+                    "KvPrewriteRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static KvPrewriteRequest {
+        static mut instance: ::protobuf::lazy::Lazy<KvPrewriteRequest> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(KvPrewriteRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for KvPrewriteRequest {
+    fn clear(&mut self) {
+        self.mutations.clear();
+        self.primary.clear();
+        self.start_ts = 0;
+        self.lock_ttl_ms = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for KvPrewriteRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for KvPrewriteRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct KvPrewriteResponse {
+    // message fields
+    pub success: bool,
+    pub errorMessage: ::std::string::String,
+    pub locked: ::protobuf::SingularPtrField<LockInfo>,
+    pub conflict: bool,
+    pub conflict_key: ::std::vec::Vec<u8>,
+    pub conflict_ts: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a KvPrewriteResponse {
+    fn default() -> &'a KvPrewriteResponse {
+        <KvPrewriteResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl KvPrewriteResponse {
+    pub fn new() -> KvPrewriteResponse {
+        ::std::default::Default::default()
+    }
+
+    // bool success = 1;
+
+
+    pub fn get_success(&self) -> bool {
+        self.success
+    }
+    pub fn clear_success(&mut self) {
+        self.success = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_success(&mut self, v: bool) {
+        self.success = v;
+    }
+
+    // string errorMessage = 2;
+
+
+    pub fn get_errorMessage(&self) -> &str {
+        &self.errorMessage
+    }
+    pub fn clear_errorMessage(&mut self) {
+        self.errorMessage.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_errorMessage(&mut self, v: ::std::string::String) {
+        self.errorMessage = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_errorMessage(&mut self) -> &mut ::std::string::String {
+        &mut self.errorMessage
+    }
+
+    // Take field
+    pub fn take_errorMessage(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.errorMessage, ::std::string::String::new())
+    }
+
+    // .LockInfo locked = 3;
+
+
+    pub fn get_locked(&self) -> &LockInfo {
+        self.locked.as_ref().unwrap_or_else(|| LockInfo::default_instance())
+    }
+    pub fn clear_locked(&mut self) {
+        self.locked.clear();
+    }
+
+    pub fn has_locked(&self) -> bool {
+        self.locked.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_locked(&mut self, v: LockInfo) {
+        self.locked = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_locked(&mut self) -> &mut LockInfo {
+        if self.locked.is_none() {
+            self.locked.set_default();
+        }
+        self.locked.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_locked(&mut self) -> LockInfo {
+        self.locked.take().unwrap_or_else(|| LockInfo::new())
+    }
+
+    // bool conflict = 4;
+
+
+    pub fn get_conflict(&self) -> bool {
+        self.conflict
+    }
+    pub fn clear_conflict(&mut self) {
+        self.conflict = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_conflict(&mut self, v: bool) {
+        self.conflict = v;
+    }
+
+    // bytes conflict_key = 5;
+
+
+    pub fn get_conflict_key(&self) -> &[u8] {
+        &self.conflict_key
+    }
+    pub fn clear_conflict_key(&mut self) {
+        self.conflict_key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_conflict_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.conflict_key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_conflict_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.conflict_key
+    }
+
+    // Take field
+    pub fn take_conflict_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.conflict_key, ::std::vec::Vec::new())
+    }
+
+    // uint64 conflict_ts = 6;
+
+
+    pub fn get_conflict_ts(&self) -> u64 {
+        self.conflict_ts
+    }
+    pub fn clear_conflict_ts(&mut self) {
+        self.conflict_ts = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_conflict_ts(&mut self, v: u64) {
+        self.conflict_ts = v;
+    }
+}
+
+impl ::protobuf::Message for KvPrewriteResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.locked {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.success = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.errorMessage)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.locked)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.conflict = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.conflict_key)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.conflict_ts = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.success != false {
+            my_size += 2;
+        }
+        if !self.errorMessage.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.errorMessage);
+        }
+        if let Some(ref v) = self.locked.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.conflict != false {
+            my_size += 2;
+        }
+        if !self.conflict_key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(5, &self.conflict_key);
+        }
+        if self.conflict_ts != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.conflict_ts, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.success != false {
+            os.write_bool(1, self.success)?;
+        }
+        if !self.errorMessage.is_empty() {
+            os.write_string(2, &self.errorMessage)?;
+        }
+        if let Some(ref v) = self.locked.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.conflict != false {
+            os.write_bool(4, self.conflict)?;
+        }
+        if !self.conflict_key.is_empty() {
+            os.write_bytes(5, &self.conflict_key)?;
+        }
+        if self.conflict_ts != 0 {
+            os.write_uint64(6, self.conflict_ts)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> KvPrewriteResponse {
+        KvPrewriteResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "success",
+                    |m: &KvPrewriteResponse| { &m.success },
+                    |m: &mut KvPrewriteResponse| { &mut m.success },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "errorMessage",
+                    |m: &KvPrewriteResponse| { &m.errorMessage },
+                    |m: &mut KvPrewriteResponse| { &mut m.errorMessage },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<LockInfo>>(
+                    "locked",
+                    |m: &KvPrewriteResponse| { &m.locked },
+                    |m: &mut KvPrewriteResponse| { &mut m.locked },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "conflict",
+                    |m: &KvPrewriteResponse| { &m.conflict },
+                    |m: &mut KvPrewriteResponse| { &mut m.conflict },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "conflict_key",
+                    |m: &KvPrewriteResponse| { &m.conflict_key },
+                    |m: &mut KvPrewriteResponse| { &mut m.conflict_key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "conflict_ts",
+                    |m: &KvPrewriteResponse| { &m.conflict_ts },
+                    |m: &mut KvPrewriteResponse| { &mut m.conflict_ts },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<KvPrewriteResponse>(
+                    "KvPrewriteResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static KvPrewriteResponse {
+        static mut instance: ::protobuf::lazy::Lazy<KvPrewriteResponse> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(KvPrewriteResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for KvPrewriteResponse {
+    fn clear(&mut self) {
+        self.success = false;
+        self.errorMessage.clear();
+        self.locked.clear();
+        self.conflict = false;
+        self.conflict_key.clear();
+        self.conflict_ts = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for KvPrewriteResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for KvPrewriteResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct KvCommitRequest {
+    // message fields
+    pub keys: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    pub start_ts: u64,
+    pub commit_ts: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a KvCommitRequest {
+    fn default() -> &'a KvCommitRequest {
+        <KvCommitRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl KvCommitRequest {
+    pub fn new() -> KvCommitRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated bytes keys = 1;
+
+
+    pub fn get_keys(&self) -> &[::std::vec::Vec<u8>] {
+        &self.keys
+    }
+    pub fn clear_keys(&mut self) {
+        self.keys.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_keys(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.keys = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_keys(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.keys
+    }
+
+    // Take field
+    pub fn take_keys(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.keys, ::protobuf::RepeatedField::new())
+    }
+
+    // uint64 start_ts = 2;
+
+
+    pub fn get_start_ts(&self) -> u64 {
+        self.start_ts
+    }
+    pub fn clear_start_ts(&mut self) {
+        self.start_ts = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start_ts(&mut self, v: u64) {
+        self.start_ts = v;
+    }
+
+    // uint64 commit_ts = 3;
+
+
+    pub fn get_commit_ts(&self) -> u64 {
+        self.commit_ts
+    }
+    pub fn clear_commit_ts(&mut self) {
+        self.commit_ts = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_commit_ts(&mut self, v: u64) {
+        self.commit_ts = v;
+    }
+}
+
+impl ::protobuf::Message for KvCommitRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.keys)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.start_ts = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.commit_ts = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.keys {
+            my_size += ::protobuf::rt::bytes_size(1, &value);
+        };
+        if self.start_ts != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.start_ts, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.commit_ts != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.commit_ts, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.keys {
+            os.write_bytes(1, &v)?;
+        };
+        if self.start_ts != 0 {
+            os.write_uint64(2, self.start_ts)?;
+        }
+        if self.commit_ts != 0 {
+            os.write_uint64(3, self.commit_ts)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> KvCommitRequest {
+        KvCommitRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "keys",
+                    |m: &KvCommitRequest| { &m.keys },
+                    |m: &mut KvCommitRequest| { &mut m.keys },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "start_ts",
+                    |m: &KvCommitRequest| { &m.start_ts },
+                    |m: &mut KvCommitRequest| { &mut m.start_ts },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "commit_ts",
+                    |m: &KvCommitRequest| { &m.commit_ts },
+                    |m: &mut KvCommitRequest| { &mut m.commit_ts },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<KvCommitRequest>(
+                    "KvCommitRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static KvCommitRequest {
+        static mut instance: ::protobuf::lazy::Lazy<KvCommitRequest> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(KvCommitRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for KvCommitRequest {
+    fn clear(&mut self) {
+        self.keys.clear();
+        self.start_ts = 0;
+        self.commit_ts = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for KvCommitRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for KvCommitRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct KvCommitResponse {
+    // message fields
+    pub success: bool,
+    pub errorMessage: ::std::string::String,
+    pub rolled_back: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a KvCommitResponse {
+    fn default() -> &'a KvCommitResponse {
+        <KvCommitResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl KvCommitResponse {
+    pub fn new() -> KvCommitResponse {
+        ::std::default::Default::default()
+    }
+
+    // bool success = 1;
+
+
+    pub fn get_success(&self) -> bool {
+        self.success
+    }
+    pub fn clear_success(&mut self) {
+        self.success = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_success(&mut self, v: bool) {
+        self.success = v;
+    }
+
+    // string errorMessage = 2;
+
+
+    pub fn get_errorMessage(&self) -> &str {
+        &self.errorMessage
+    }
+    pub fn clear_errorMessage(&mut self) {
+        self.errorMessage.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_errorMessage(&mut self, v: ::std::string::String) {
+        self.errorMessage = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_errorMessage(&mut self) -> &mut ::std::string::String {
+        &mut self.errorMessage
+    }
+
+    // Take field
+    pub fn take_errorMessage(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.errorMessage, ::std::string::String::new())
+    }
+
+    // bool rolled_back = 3;
+
+
+    pub fn get_rolled_back(&self) -> bool {
+        self.rolled_back
+    }
+    pub fn clear_rolled_back(&mut self) {
+        self.rolled_back = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rolled_back(&mut self, v: bool) {
+        self.rolled_back = v;
+    }
+}
+
+impl ::protobuf::Message for KvCommitResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.success = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.errorMessage)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.rolled_back = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.success != false {
+            my_size += 2;
+        }
+        if !self.errorMessage.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.errorMessage);
+        }
+        if self.rolled_back != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.success != false {
+            os.write_bool(1, self.success)?;
+        }
+        if !self.errorMessage.is_empty() {
+            os.write_string(2, &self.errorMessage)?;
+        }
+        if self.rolled_back != false {
+            os.write_bool(3, self.rolled_back)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> KvCommitResponse {
+        KvCommitResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "success",
+                    |m: &KvCommitResponse| { &m.success },
+                    |m: &mut KvCommitResponse| { &mut m.success },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "errorMessage",
+                    |m: &KvCommitResponse| { &m.errorMessage },
+                    |m: &mut KvCommitResponse| { &mut m.errorMessage },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "rolled_back",
+                    |m: &KvCommitResponse| { &m.rolled_back },
+                    |m: &mut KvCommitResponse| { &mut m.rolled_back },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<KvCommitResponse>(
+                    "KvCommitResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static KvCommitResponse {
+        static mut instance: ::protobuf::lazy::Lazy<KvCommitResponse> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(KvCommitResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for KvCommitResponse {
+    fn clear(&mut self) {
+        self.success = false;
+        self.errorMessage.clear();
+        self.rolled_back = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for KvCommitResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for KvCommitResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct KvRollbackRequest {
+    // message fields
+    pub keys: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    pub start_ts: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a KvRollbackRequest {
+    fn default() -> &'a KvRollbackRequest {
+        <KvRollbackRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl KvRollbackRequest {
+    pub fn new() -> KvRollbackRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated bytes keys = 1;
+
+
+    pub fn get_keys(&self) -> &[::std::vec::Vec<u8>] {
+        &self.keys
+    }
+    pub fn clear_keys(&mut self) {
+        self.keys.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_keys(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.keys = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_keys(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.keys
+    }
+
+    // Take field
+    pub fn take_keys(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.keys, ::protobuf::RepeatedField::new())
+    }
+
+    // uint64 start_ts = 2;
+
+
+    pub fn get_start_ts(&self) -> u64 {
+        self.start_ts
+    }
+    pub fn clear_start_ts(&mut self) {
+        self.start_ts = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start_ts(&mut self, v: u64) {
+        self.start_ts = v;
+    }
+}
+
+impl ::protobuf::Message for KvRollbackRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.keys)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.start_ts = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.keys {
+            my_size += ::protobuf::rt::bytes_size(1, &value);
+        };
+        if self.start_ts != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.start_ts, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.keys {
+            os.write_bytes(1, &v)?;
+        };
+        if self.start_ts != 0 {
+            os.write_uint64(2, self.start_ts)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> KvRollbackRequest {
+        KvRollbackRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "keys",
+                    |m: &KvRollbackRequest| { &m.keys },
+                    |m: &mut KvRollbackRequest| { &mut m.keys },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "start_ts",
+                    |m: &KvRollbackRequest| { &m.start_ts },
+                    |m: &mut KvRollbackRequest| { &mut m.start_ts },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<KvRollbackRequest>(
+                    "KvRollbackRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static KvRollbackRequest {
+        static mut instance: ::protobuf::lazy::Lazy<KvRollbackRequest> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(KvRollbackRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for KvRollbackRequest {
+    fn clear(&mut self) {
+        self.keys.clear();
+        self.start_ts = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for KvRollbackRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for KvRollbackRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct KvRollbackResponse {
+    // message fields
+    pub success: bool,
+    pub errorMessage: ::std::string::String,
+    pub committed: bool,
+    pub commit_ts: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a KvRollbackResponse {
+    fn default() -> &'a KvRollbackResponse {
+        <KvRollbackResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl KvRollbackResponse {
+    pub fn new() -> KvRollbackResponse {
+        ::std::default::Default::default()
+    }
+
+    // bool success = 1;
+
+
+    pub fn get_success(&self) -> bool {
+        self.success
+    }
+    pub fn clear_success(&mut self) {
+        self.success = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_success(&mut self, v: bool) {
+        self.success = v;
+    }
+
+    // string errorMessage = 2;
+
+
+    pub fn get_errorMessage(&self) -> &str {
+        &self.errorMessage
+    }
+    pub fn clear_errorMessage(&mut self) {
+        self.errorMessage.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_errorMessage(&mut self, v: ::std::string::String) {
+        self.errorMessage = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_errorMessage(&mut self) -> &mut ::std::string::String {
+        &mut self.errorMessage
+    }
+
+    // Take field
+    pub fn take_errorMessage(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.errorMessage, ::std::string::String::new())
+    }
+
+    // bool committed = 3;
+
+
+    pub fn get_committed(&self) -> bool {
+        self.committed
+    }
+    pub fn clear_committed(&mut self) {
+        self.committed = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_committed(&mut self, v: bool) {
+        self.committed = v;
+    }
+
+    // uint64 commit_ts = 4;
+
+
+    pub fn get_commit_ts(&self) -> u64 {
+        self.commit_ts
+    }
+    pub fn clear_commit_ts(&mut self) {
+        self.commit_ts = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_commit_ts(&mut self, v: u64) {
+        self.commit_ts = v;
+    }
+}
+
+impl ::protobuf::Message for KvRollbackResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.success = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.errorMessage)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.committed = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.commit_ts = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.success != false {
+            my_size += 2;
+        }
+        if !self.errorMessage.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.errorMessage);
+        }
+        if self.committed != false {
+            my_size += 2;
+        }
+        if self.commit_ts != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.commit_ts, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.success != false {
+            os.write_bool(1, self.success)?;
+        }
+        if !self.errorMessage.is_empty() {
+            os.write_string(2, &self.errorMessage)?;
+        }
+        if self.committed != false {
+            os.write_bool(3, self.committed)?;
+        }
+        if self.commit_ts != 0 {
+            os.write_uint64(4, self.commit_ts)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> KvRollbackResponse {
+        KvRollbackResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "success",
+                    |m: &KvRollbackResponse| { &m.success },
+                    |m: &mut KvRollbackResponse| { &mut m.success },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "errorMessage",
+                    |m: &KvRollbackResponse| { &m.errorMessage },
+                    |m: &mut KvRollbackResponse| { &mut m.errorMessage },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "committed",
+                    |m: &KvRollbackResponse| { &m.committed },
+                    |m: &mut KvRollbackResponse| { &mut m.committed },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "commit_ts",
+                    |m: &KvRollbackResponse| { &m.commit_ts },
+                    |m: &mut KvRollbackResponse| { &mut m.commit_ts },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<KvRollbackResponse>(
+                    "KvRollbackResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static KvRollbackResponse {
+        static mut instance: ::protobuf::lazy::Lazy<KvRollbackResponse> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(KvRollbackResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for KvRollbackResponse {
+    fn clear(&mut self) {
+        self.success = false;
+        self.errorMessage.clear();
+        self.committed = false;
+        self.commit_ts = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for KvRollbackResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for KvRollbackResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct KvCheckTxnStatusRequest {
+    // message fields
+    pub primary_key: ::std::vec::Vec<u8>,
+    pub start_ts: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a KvCheckTxnStatusRequest {
+    fn default() -> &'a KvCheckTxnStatusRequest {
+        <KvCheckTxnStatusRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl KvCheckTxnStatusRequest {
+    pub fn new() -> KvCheckTxnStatusRequest {
+        ::std::default::Default::default()
+    }
+
+    // bytes primary_key = 1;
+
+
+    pub fn get_primary_key(&self) -> &[u8] {
+        &self.primary_key
+    }
+    pub fn clear_primary_key(&mut self) {
+        self.primary_key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_primary_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.primary_key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_primary_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.primary_key
+    }
+
+    // Take field
+    pub fn take_primary_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.primary_key, ::std::vec::Vec::new())
+    }
+
+    // uint64 start_ts = 2;
+
+
+    pub fn get_start_ts(&self) -> u64 {
+        self.start_ts
+    }
+    pub fn clear_start_ts(&mut self) {
+        self.start_ts = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start_ts(&mut self, v: u64) {
+        self.start_ts = v;
+    }
+}
+
+impl ::protobuf::Message for KvCheckTxnStatusRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.primary_key)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.start_ts = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.primary_key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.primary_key);
+        }
+        if self.start_ts != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.start_ts, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.primary_key.is_empty() {
+            os.write_bytes(1, &self.primary_key)?;
+        }
+        if self.start_ts != 0 {
+            os.write_uint64(2, self.start_ts)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> KvCheckTxnStatusRequest {
+        KvCheckTxnStatusRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "primary_key",
+                    |m: &KvCheckTxnStatusRequest| { &m.primary_key },
+                    |m: &mut KvCheckTxnStatusRequest| { &mut m.primary_key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "start_ts",
+                    |m: &KvCheckTxnStatusRequest| { &m.start_ts },
+                    |m: &mut KvCheckTxnStatusRequest| { &mut m.start_ts },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<KvCheckTxnStatusRequest>(
+                    "KvCheckTxnStatusRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static KvCheckTxnStatusRequest {
+        static mut instance: ::protobuf::lazy::Lazy<KvCheckTxnStatusRequest> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(KvCheckTxnStatusRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for KvCheckTxnStatusRequest {
+    fn clear(&mut self) {
+        self.primary_key.clear();
+        self.start_ts = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for KvCheckTxnStatusRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for KvCheckTxnStatusRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct KvCheckTxnStatusResponse {
+    // message fields
+    pub success: bool,
+    pub errorMessage: ::std::string::String,
+    pub status: KvCheckTxnStatusResponse_Status,
+    pub commit_ts: u64,
+    pub ttl_ms: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a KvCheckTxnStatusResponse {
+    fn default() -> &'a KvCheckTxnStatusResponse {
+        <KvCheckTxnStatusResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl KvCheckTxnStatusResponse {
+    pub fn new() -> KvCheckTxnStatusResponse {
+        ::std::default::Default::default()
+    }
+
+    // bool success = 1;
+
+
+    pub fn get_success(&self) -> bool {
+        self.success
+    }
+    pub fn clear_success(&mut self) {
+        self.success = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_success(&mut self, v: bool) {
+        self.success = v;
+    }
+
+    // string errorMessage = 2;
+
+
+    pub fn get_errorMessage(&self) -> &str {
+        &self.errorMessage
+    }
+    pub fn clear_errorMessage(&mut self) {
+        self.errorMessage.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_errorMessage(&mut self, v: ::std::string::String) {
+        self.errorMessage = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_errorMessage(&mut self) -> &mut ::std::string::String {
+        &mut self.errorMessage
+    }
+
+    // Take field
+    pub fn take_errorMessage(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.errorMessage, ::std::string::String::new())
+    }
+
+    // .KvCheckTxnStatusResponse.Status status = 3;
+
+
+    pub fn get_status(&self) -> KvCheckTxnStatusResponse_Status {
+        self.status
+    }
+    pub fn clear_status(&mut self) {
+        self.status = KvCheckTxnStatusResponse_Status::LOCKED;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: KvCheckTxnStatusResponse_Status) {
+        self.status = v;
+    }
+
+    // uint64 commit_ts = 4;
+
+
+    pub fn get_commit_ts(&self) -> u64 {
+        self.commit_ts
+    }
+    pub fn clear_commit_ts(&mut self) {
+        self.commit_ts = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_commit_ts(&mut self, v: u64) {
+        self.commit_ts = v;
+    }
+
+    // uint64 ttl_ms = 5;
+
+
+    pub fn get_ttl_ms(&self) -> u64 {
+        self.ttl_ms
+    }
+    pub fn clear_ttl_ms(&mut self) {
+        self.ttl_ms = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ttl_ms(&mut self, v: u64) {
+        self.ttl_ms = v;
+    }
+}
+
+impl ::protobuf::Message for KvCheckTxnStatusResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.success = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.errorMessage)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 3, &mut self.unknown_fields)?
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.commit_ts = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.ttl_ms = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.success != false {
+            my_size += 2;
+        }
+        if !self.errorMessage.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.errorMessage);
+        }
+        if self.status != KvCheckTxnStatusResponse_Status::LOCKED {
+            my_size += ::protobuf::rt::enum_size(3, self.status);
+        }
+        if self.commit_ts != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.commit_ts, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.ttl_ms != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.ttl_ms, ::protobuf::wire_format::WireTypeVarint);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -746,6 +11232,15 @@ impl ::protobuf::Message for PutResponse {
         if !self.errorMessage.is_empty() {
             os.write_string(2, &self.errorMessage)?;
         }
+        if self.status != KvCheckTxnStatusResponse_Status::LOCKED {
+            os.write_enum(3, self.status.value())?;
+        }
+        if self.commit_ts != 0 {
+            os.write_uint64(4, self.commit_ts)?;
+        }
+        if self.ttl_ms != 0 {
+            os.write_uint64(5, self.ttl_ms)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -776,8 +11271,8 @@ impl ::protobuf::Message for PutResponse {
         Self::descriptor_static()
     }
 
-    fn new() -> PutResponse {
-        PutResponse::new()
+    fn new() -> KvCheckTxnStatusResponse {
+        KvCheckTxnStatusResponse::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -787,16 +11282,211 @@ impl ::protobuf::Message for PutResponse {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
                     "success",
-                    |m: &PutResponse| { &m.success },
-                    |m: &mut PutResponse| { &mut m.success },
+                    |m: &KvCheckTxnStatusResponse| { &m.success },
+                    |m: &mut KvCheckTxnStatusResponse| { &mut m.success },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
                     "errorMessage",
-                    |m: &PutResponse| { &m.errorMessage },
-                    |m: &mut PutResponse| { &mut m.errorMessage },
+                    |m: &KvCheckTxnStatusResponse| { &m.errorMessage },
+                    |m: &mut KvCheckTxnStatusResponse| { &mut m.errorMessage },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new_pb_name::<PutResponse>(
-                    "PutResponse",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<KvCheckTxnStatusResponse_Status>>(
+                    "status",
+                    |m: &KvCheckTxnStatusResponse| { &m.status },
+                    |m: &mut KvCheckTxnStatusResponse| { &mut m.status },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "commit_ts",
+                    |m: &KvCheckTxnStatusResponse| { &m.commit_ts },
+                    |m: &mut KvCheckTxnStatusResponse| { &mut m.commit_ts },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "ttl_ms",
+                    |m: &KvCheckTxnStatusResponse| { &m.ttl_ms },
+                    |m: &mut KvCheckTxnStatusResponse| { &mut m.ttl_ms },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<KvCheckTxnStatusResponse>(
+                    "KvCheckTxnStatusResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static KvCheckTxnStatusResponse {
+        static mut instance: ::protobuf::lazy::Lazy<KvCheckTxnStatusResponse> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(KvCheckTxnStatusResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for KvCheckTxnStatusResponse {
+    fn clear(&mut self) {
+        self.success = false;
+        self.errorMessage.clear();
+        self.status = KvCheckTxnStatusResponse_Status::LOCKED;
+        self.commit_ts = 0;
+        self.ttl_ms = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for KvCheckTxnStatusResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for KvCheckTxnStatusResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum KvCheckTxnStatusResponse_Status {
+    LOCKED = 0,
+    COMMITTED = 1,
+    ROLLED_BACK = 2,
+}
+
+impl ::protobuf::ProtobufEnum for KvCheckTxnStatusResponse_Status {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<KvCheckTxnStatusResponse_Status> {
+        match value {
+            0 => ::std::option::Option::Some(KvCheckTxnStatusResponse_Status::LOCKED),
+            1 => ::std::option::Option::Some(KvCheckTxnStatusResponse_Status::COMMITTED),
+            2 => ::std::option::Option::Some(KvCheckTxnStatusResponse_Status::ROLLED_BACK),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [KvCheckTxnStatusResponse_Status] = &[
+            KvCheckTxnStatusResponse_Status::LOCKED,
+            KvCheckTxnStatusResponse_Status::COMMITTED,
+            KvCheckTxnStatusResponse_Status::ROLLED_BACK,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new_pb_name::<KvCheckTxnStatusResponse_Status>("KvCheckTxnStatusResponse.Status", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for KvCheckTxnStatusResponse_Status {
+}
+
+impl ::std::default::Default for KvCheckTxnStatusResponse_Status {
+    fn default() -> Self {
+        KvCheckTxnStatusResponse_Status::LOCKED
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for KvCheckTxnStatusResponse_Status {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(self.descriptor())
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BackupRequest {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BackupRequest {
+    fn default() -> &'a BackupRequest {
+        <BackupRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BackupRequest {
+    pub fn new() -> BackupRequest {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for BackupRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BackupRequest {
+        BackupRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<BackupRequest>(
+                    "BackupRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -804,82 +11494,80 @@ impl ::protobuf::Message for PutResponse {
         }
     }
 
-    fn default_instance() -> &'static PutResponse {
-        static mut instance: ::protobuf::lazy::Lazy<PutResponse> = ::protobuf::lazy::Lazy::INIT;
+    fn default_instance() -> &'static BackupRequest {
+        static mut instance: ::protobuf::lazy::Lazy<BackupRequest> = ::protobuf::lazy::Lazy::INIT;
         unsafe {
-            instance.get(PutResponse::new)
+            instance.get(BackupRequest::new)
         }
     }
 }
 
-impl ::protobuf::Clear for PutResponse {
+impl ::protobuf::Clear for BackupRequest {
     fn clear(&mut self) {
-        self.success = false;
-        self.errorMessage.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for PutResponse {
+impl ::std::fmt::Debug for BackupRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for PutResponse {
+impl ::protobuf::reflect::ProtobufValue for BackupRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct DeleteRequest {
+pub struct SnapshotChunk {
     // message fields
-    pub key: ::std::vec::Vec<u8>,
+    pub data: ::std::vec::Vec<u8>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a DeleteRequest {
-    fn default() -> &'a DeleteRequest {
-        <DeleteRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a SnapshotChunk {
+    fn default() -> &'a SnapshotChunk {
+        <SnapshotChunk as ::protobuf::Message>::default_instance()
     }
 }
 
-impl DeleteRequest {
-    pub fn new() -> DeleteRequest {
+impl SnapshotChunk {
+    pub fn new() -> SnapshotChunk {
         ::std::default::Default::default()
     }
 
-    // bytes key = 1;
+    // bytes data = 1;
 
 
-    pub fn get_key(&self) -> &[u8] {
-        &self.key
+    pub fn get_data(&self) -> &[u8] {
+        &self.data
     }
-    pub fn clear_key(&mut self) {
-        self.key.clear();
+    pub fn clear_data(&mut self) {
+        self.data.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
-        self.key = v;
+    pub fn set_data(&mut self, v: ::std::vec::Vec<u8>) {
+        self.data = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.key
+    pub fn mut_data(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.data
     }
 
     // Take field
-    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.data, ::std::vec::Vec::new())
     }
 }
 
-impl ::protobuf::Message for DeleteRequest {
+impl ::protobuf::Message for SnapshotChunk {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -889,7 +11577,7 @@ impl ::protobuf::Message for DeleteRequest {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.data)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -903,8 +11591,8 @@ impl ::protobuf::Message for DeleteRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.key.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.key);
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.data);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -912,8 +11600,8 @@ impl ::protobuf::Message for DeleteRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if !self.key.is_empty() {
-            os.write_bytes(1, &self.key)?;
+        if !self.data.is_empty() {
+            os.write_bytes(1, &self.data)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -945,8 +11633,8 @@ impl ::protobuf::Message for DeleteRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> DeleteRequest {
-        DeleteRequest::new()
+    fn new() -> SnapshotChunk {
+        SnapshotChunk::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -955,12 +11643,12 @@ impl ::protobuf::Message for DeleteRequest {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "key",
-                    |m: &DeleteRequest| { &m.key },
-                    |m: &mut DeleteRequest| { &mut m.key },
+                    "data",
+                    |m: &SnapshotChunk| { &m.data },
+                    |m: &mut SnapshotChunk| { &mut m.data },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new_pb_name::<DeleteRequest>(
-                    "DeleteRequest",
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<SnapshotChunk>(
+                    "SnapshotChunk",
                     fields,
                     file_descriptor_proto()
                 )
@@ -968,51 +11656,52 @@ impl ::protobuf::Message for DeleteRequest {
         }
     }
 
-    fn default_instance() -> &'static DeleteRequest {
-        static mut instance: ::protobuf::lazy::Lazy<DeleteRequest> = ::protobuf::lazy::Lazy::INIT;
+    fn default_instance() -> &'static SnapshotChunk {
+        static mut instance: ::protobuf::lazy::Lazy<SnapshotChunk> = ::protobuf::lazy::Lazy::INIT;
         unsafe {
-            instance.get(DeleteRequest::new)
+            instance.get(SnapshotChunk::new)
         }
     }
 }
 
-impl ::protobuf::Clear for DeleteRequest {
+impl ::protobuf::Clear for SnapshotChunk {
     fn clear(&mut self) {
-        self.key.clear();
+        self.data.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for DeleteRequest {
+impl ::std::fmt::Debug for SnapshotChunk {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for DeleteRequest {
+impl ::protobuf::reflect::ProtobufValue for SnapshotChunk {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct DeleteResponse {
+pub struct RestoreResponse {
     // message fields
     pub success: bool,
     pub errorMessage: ::std::string::String,
+    pub count: u64,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a DeleteResponse {
-    fn default() -> &'a DeleteResponse {
-        <DeleteResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a RestoreResponse {
+    fn default() -> &'a RestoreResponse {
+        <RestoreResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl DeleteResponse {
-    pub fn new() -> DeleteResponse {
+impl RestoreResponse {
+    pub fn new() -> RestoreResponse {
         ::std::default::Default::default()
     }
 
@@ -1056,9 +11745,24 @@ impl DeleteResponse {
     pub fn take_errorMessage(&mut self) -> ::std::string::String {
         ::std::mem::replace(&mut self.errorMessage, ::std::string::String::new())
     }
+
+    // uint64 count = 3;
+
+
+    pub fn get_count(&self) -> u64 {
+        self.count
+    }
+    pub fn clear_count(&mut self) {
+        self.count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: u64) {
+        self.count = v;
+    }
 }
 
-impl ::protobuf::Message for DeleteResponse {
+impl ::protobuf::Message for RestoreResponse {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -1077,6 +11781,13 @@ impl ::protobuf::Message for DeleteResponse {
                 2 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.errorMessage)?;
                 },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.count = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1095,6 +11806,9 @@ impl ::protobuf::Message for DeleteResponse {
         if !self.errorMessage.is_empty() {
             my_size += ::protobuf::rt::string_size(2, &self.errorMessage);
         }
+        if self.count != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.count, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1107,6 +11821,9 @@ impl ::protobuf::Message for DeleteResponse {
         if !self.errorMessage.is_empty() {
             os.write_string(2, &self.errorMessage)?;
         }
+        if self.count != 0 {
+            os.write_uint64(3, self.count)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1137,8 +11854,8 @@ impl ::protobuf::Message for DeleteResponse {
         Self::descriptor_static()
     }
 
-    fn new() -> DeleteResponse {
-        DeleteResponse::new()
+    fn new() -> RestoreResponse {
+        RestoreResponse::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1148,16 +11865,21 @@ impl ::protobuf::Message for DeleteResponse {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
                     "success",
-                    |m: &DeleteResponse| { &m.success },
-                    |m: &mut DeleteResponse| { &mut m.success },
+                    |m: &RestoreResponse| { &m.success },
+                    |m: &mut RestoreResponse| { &mut m.success },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
                     "errorMessage",
-                    |m: &DeleteResponse| { &m.errorMessage },
-                    |m: &mut DeleteResponse| { &mut m.errorMessage },
+                    |m: &RestoreResponse| { &m.errorMessage },
+                    |m: &mut RestoreResponse| { &mut m.errorMessage },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new_pb_name::<DeleteResponse>(
-                    "DeleteResponse",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "count",
+                    |m: &RestoreResponse| { &m.count },
+                    |m: &mut RestoreResponse| { &mut m.count },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<RestoreResponse>(
+                    "RestoreResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1165,71 +11887,314 @@ impl ::protobuf::Message for DeleteResponse {
         }
     }
 
-    fn default_instance() -> &'static DeleteResponse {
-        static mut instance: ::protobuf::lazy::Lazy<DeleteResponse> = ::protobuf::lazy::Lazy::INIT;
+    fn default_instance() -> &'static RestoreResponse {
+        static mut instance: ::protobuf::lazy::Lazy<RestoreResponse> = ::protobuf::lazy::Lazy::INIT;
         unsafe {
-            instance.get(DeleteResponse::new)
+            instance.get(RestoreResponse::new)
         }
     }
 }
 
-impl ::protobuf::Clear for DeleteResponse {
+impl ::protobuf::Clear for RestoreResponse {
     fn clear(&mut self) {
         self.success = false;
         self.errorMessage.clear();
+        self.count = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RestoreResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RestoreResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct StatsRequest {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StatsRequest {
+    fn default() -> &'a StatsRequest {
+        <StatsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StatsRequest {
+    pub fn new() -> StatsRequest {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for StatsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StatsRequest {
+        StatsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<StatsRequest>(
+                    "StatsRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static StatsRequest {
+        static mut instance: ::protobuf::lazy::Lazy<StatsRequest> = ::protobuf::lazy::Lazy::INIT;
+        unsafe {
+            instance.get(StatsRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for StatsRequest {
+    fn clear(&mut self) {
         self.unknown_fields.clear();
     }
-}
+}
+
+impl ::std::fmt::Debug for StatsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StatsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LsmTreeStats {
+    // message fields
+    pub flushed_bytes: u64,
+    pub compaction_read_bytes: u64,
+    pub compaction_written_bytes: u64,
+    pub compactions: u64,
+    pub pending_compaction_bytes: u64,
+    pub level_bytes: ::std::vec::Vec<u64>,
+    pub bloom_false_positives: u64,
+    pub write_amplification: f64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a LsmTreeStats {
+    fn default() -> &'a LsmTreeStats {
+        <LsmTreeStats as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LsmTreeStats {
+    pub fn new() -> LsmTreeStats {
+        ::std::default::Default::default()
+    }
+
+    // uint64 flushed_bytes = 1;
+
+
+    pub fn get_flushed_bytes(&self) -> u64 {
+        self.flushed_bytes
+    }
+    pub fn clear_flushed_bytes(&mut self) {
+        self.flushed_bytes = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_flushed_bytes(&mut self, v: u64) {
+        self.flushed_bytes = v;
+    }
+
+    // uint64 compaction_read_bytes = 2;
+
+
+    pub fn get_compaction_read_bytes(&self) -> u64 {
+        self.compaction_read_bytes
+    }
+    pub fn clear_compaction_read_bytes(&mut self) {
+        self.compaction_read_bytes = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_compaction_read_bytes(&mut self, v: u64) {
+        self.compaction_read_bytes = v;
+    }
+
+    // uint64 compaction_written_bytes = 3;
+
+
+    pub fn get_compaction_written_bytes(&self) -> u64 {
+        self.compaction_written_bytes
+    }
+    pub fn clear_compaction_written_bytes(&mut self) {
+        self.compaction_written_bytes = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_compaction_written_bytes(&mut self, v: u64) {
+        self.compaction_written_bytes = v;
+    }
+
+    // uint64 compactions = 4;
+
+
+    pub fn get_compactions(&self) -> u64 {
+        self.compactions
+    }
+    pub fn clear_compactions(&mut self) {
+        self.compactions = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_compactions(&mut self, v: u64) {
+        self.compactions = v;
+    }
+
+    // uint64 pending_compaction_bytes = 5;
+
+
+    pub fn get_pending_compaction_bytes(&self) -> u64 {
+        self.pending_compaction_bytes
+    }
+    pub fn clear_pending_compaction_bytes(&mut self) {
+        self.pending_compaction_bytes = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pending_compaction_bytes(&mut self, v: u64) {
+        self.pending_compaction_bytes = v;
+    }
+
+    // repeated uint64 level_bytes = 6;
+
+
+    pub fn get_level_bytes(&self) -> &[u64] {
+        &self.level_bytes
+    }
+    pub fn clear_level_bytes(&mut self) {
+        self.level_bytes.clear();
+    }
 
-impl ::std::fmt::Debug for DeleteResponse {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-        ::protobuf::text_format::fmt(self, f)
+    // Param is passed by value, moved
+    pub fn set_level_bytes(&mut self, v: ::std::vec::Vec<u64>) {
+        self.level_bytes = v;
     }
-}
 
-impl ::protobuf::reflect::ProtobufValue for DeleteResponse {
-    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
-        ::protobuf::reflect::ReflectValueRef::Message(self)
+    // Mutable pointer to the field.
+    pub fn mut_level_bytes(&mut self) -> &mut ::std::vec::Vec<u64> {
+        &mut self.level_bytes
     }
-}
 
-#[derive(PartialEq,Clone,Default)]
-pub struct ScanRequest {
-    // message fields
-    pub cursor: u64,
-    // special fields
-    pub unknown_fields: ::protobuf::UnknownFields,
-    pub cached_size: ::protobuf::CachedSize,
-}
+    // Take field
+    pub fn take_level_bytes(&mut self) -> ::std::vec::Vec<u64> {
+        ::std::mem::replace(&mut self.level_bytes, ::std::vec::Vec::new())
+    }
 
-impl<'a> ::std::default::Default for &'a ScanRequest {
-    fn default() -> &'a ScanRequest {
-        <ScanRequest as ::protobuf::Message>::default_instance()
+    // uint64 bloom_false_positives = 7;
+
+
+    pub fn get_bloom_false_positives(&self) -> u64 {
+        self.bloom_false_positives
+    }
+    pub fn clear_bloom_false_positives(&mut self) {
+        self.bloom_false_positives = 0;
     }
-}
 
-impl ScanRequest {
-    pub fn new() -> ScanRequest {
-        ::std::default::Default::default()
+    // Param is passed by value, moved
+    pub fn set_bloom_false_positives(&mut self, v: u64) {
+        self.bloom_false_positives = v;
     }
 
-    // uint64 cursor = 1;
+    // double write_amplification = 8;
 
 
-    pub fn get_cursor(&self) -> u64 {
-        self.cursor
+    pub fn get_write_amplification(&self) -> f64 {
+        self.write_amplification
     }
-    pub fn clear_cursor(&mut self) {
-        self.cursor = 0;
+    pub fn clear_write_amplification(&mut self) {
+        self.write_amplification = 0.;
     }
 
     // Param is passed by value, moved
-    pub fn set_cursor(&mut self, v: u64) {
-        self.cursor = v;
+    pub fn set_write_amplification(&mut self, v: f64) {
+        self.write_amplification = v;
     }
 }
 
-impl ::protobuf::Message for ScanRequest {
+impl ::protobuf::Message for LsmTreeStats {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -1243,7 +12208,52 @@ impl ::protobuf::Message for ScanRequest {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint64()?;
-                    self.cursor = tmp;
+                    self.flushed_bytes = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.compaction_read_bytes = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.compaction_written_bytes = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.compactions = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.pending_compaction_bytes = tmp;
+                },
+                6 => {
+                    ::protobuf::rt::read_repeated_uint64_into(wire_type, is, &mut self.level_bytes)?;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.bloom_false_positives = tmp;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.write_amplification = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1257,8 +12267,29 @@ impl ::protobuf::Message for ScanRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.cursor != 0 {
-            my_size += ::protobuf::rt::value_size(1, self.cursor, ::protobuf::wire_format::WireTypeVarint);
+        if self.flushed_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.flushed_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.compaction_read_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.compaction_read_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.compaction_written_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.compaction_written_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.compactions != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.compactions, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.pending_compaction_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.pending_compaction_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.level_bytes {
+            my_size += ::protobuf::rt::value_size(6, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if self.bloom_false_positives != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.bloom_false_positives, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.write_amplification != 0. {
+            my_size += 9;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1266,8 +12297,29 @@ impl ::protobuf::Message for ScanRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if self.cursor != 0 {
-            os.write_uint64(1, self.cursor)?;
+        if self.flushed_bytes != 0 {
+            os.write_uint64(1, self.flushed_bytes)?;
+        }
+        if self.compaction_read_bytes != 0 {
+            os.write_uint64(2, self.compaction_read_bytes)?;
+        }
+        if self.compaction_written_bytes != 0 {
+            os.write_uint64(3, self.compaction_written_bytes)?;
+        }
+        if self.compactions != 0 {
+            os.write_uint64(4, self.compactions)?;
+        }
+        if self.pending_compaction_bytes != 0 {
+            os.write_uint64(5, self.pending_compaction_bytes)?;
+        }
+        for v in &self.level_bytes {
+            os.write_uint64(6, *v)?;
+        };
+        if self.bloom_false_positives != 0 {
+            os.write_uint64(7, self.bloom_false_positives)?;
+        }
+        if self.write_amplification != 0. {
+            os.write_double(8, self.write_amplification)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1299,8 +12351,8 @@ impl ::protobuf::Message for ScanRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> ScanRequest {
-        ScanRequest::new()
+    fn new() -> LsmTreeStats {
+        LsmTreeStats::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1309,12 +12361,47 @@ impl ::protobuf::Message for ScanRequest {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "cursor",
-                    |m: &ScanRequest| { &m.cursor },
-                    |m: &mut ScanRequest| { &mut m.cursor },
+                    "flushed_bytes",
+                    |m: &LsmTreeStats| { &m.flushed_bytes },
+                    |m: &mut LsmTreeStats| { &mut m.flushed_bytes },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new_pb_name::<ScanRequest>(
-                    "ScanRequest",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "compaction_read_bytes",
+                    |m: &LsmTreeStats| { &m.compaction_read_bytes },
+                    |m: &mut LsmTreeStats| { &mut m.compaction_read_bytes },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "compaction_written_bytes",
+                    |m: &LsmTreeStats| { &m.compaction_written_bytes },
+                    |m: &mut LsmTreeStats| { &mut m.compaction_written_bytes },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "compactions",
+                    |m: &LsmTreeStats| { &m.compactions },
+                    |m: &mut LsmTreeStats| { &mut m.compactions },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "pending_compaction_bytes",
+                    |m: &LsmTreeStats| { &m.pending_compaction_bytes },
+                    |m: &mut LsmTreeStats| { &mut m.pending_compaction_bytes },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "level_bytes",
+                    |m: &LsmTreeStats| { &m.level_bytes },
+                    |m: &mut LsmTreeStats| { &mut m.level_bytes },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "bloom_false_positives",
+                    |m: &LsmTreeStats| { &m.bloom_false_positives },
+                    |m: &mut LsmTreeStats| { &mut m.bloom_false_positives },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                    "write_amplification",
+                    |m: &LsmTreeStats| { &m.write_amplification },
+                    |m: &mut LsmTreeStats| { &mut m.write_amplification },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<LsmTreeStats>(
+                    "LsmTreeStats",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1322,97 +12409,175 @@ impl ::protobuf::Message for ScanRequest {
         }
     }
 
-    fn default_instance() -> &'static ScanRequest {
-        static mut instance: ::protobuf::lazy::Lazy<ScanRequest> = ::protobuf::lazy::Lazy::INIT;
+    fn default_instance() -> &'static LsmTreeStats {
+        static mut instance: ::protobuf::lazy::Lazy<LsmTreeStats> = ::protobuf::lazy::Lazy::INIT;
         unsafe {
-            instance.get(ScanRequest::new)
+            instance.get(LsmTreeStats::new)
         }
     }
 }
 
-impl ::protobuf::Clear for ScanRequest {
+impl ::protobuf::Clear for LsmTreeStats {
     fn clear(&mut self) {
-        self.cursor = 0;
+        self.flushed_bytes = 0;
+        self.compaction_read_bytes = 0;
+        self.compaction_written_bytes = 0;
+        self.compactions = 0;
+        self.pending_compaction_bytes = 0;
+        self.level_bytes.clear();
+        self.bloom_false_positives = 0;
+        self.write_amplification = 0.;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for ScanRequest {
+impl ::std::fmt::Debug for LsmTreeStats {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for ScanRequest {
+impl ::protobuf::reflect::ProtobufValue for LsmTreeStats {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct ScanResponse {
+pub struct StatsResponse {
     // message fields
-    pub cursor: u64,
-    pub result: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    pub success: bool,
+    pub errorMessage: ::std::string::String,
+    pub partitions: ::protobuf::RepeatedField<LsmTreeStats>,
+    pub percolator: ::protobuf::SingularPtrField<LsmTreeStats>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a ScanResponse {
-    fn default() -> &'a ScanResponse {
-        <ScanResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a StatsResponse {
+    fn default() -> &'a StatsResponse {
+        <StatsResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl ScanResponse {
-    pub fn new() -> ScanResponse {
+impl StatsResponse {
+    pub fn new() -> StatsResponse {
         ::std::default::Default::default()
     }
 
-    // uint64 cursor = 1;
+    // bool success = 1;
 
 
-    pub fn get_cursor(&self) -> u64 {
-        self.cursor
+    pub fn get_success(&self) -> bool {
+        self.success
     }
-    pub fn clear_cursor(&mut self) {
-        self.cursor = 0;
+    pub fn clear_success(&mut self) {
+        self.success = false;
     }
 
     // Param is passed by value, moved
-    pub fn set_cursor(&mut self, v: u64) {
-        self.cursor = v;
+    pub fn set_success(&mut self, v: bool) {
+        self.success = v;
     }
 
-    // repeated bytes result = 2;
+    // string errorMessage = 2;
 
 
-    pub fn get_result(&self) -> &[::std::vec::Vec<u8>] {
-        &self.result
+    pub fn get_errorMessage(&self) -> &str {
+        &self.errorMessage
     }
-    pub fn clear_result(&mut self) {
-        self.result.clear();
+    pub fn clear_errorMessage(&mut self) {
+        self.errorMessage.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_result(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
-        self.result = v;
+    pub fn set_errorMessage(&mut self, v: ::std::string::String) {
+        self.errorMessage = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_result(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
-        &mut self.result
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_errorMessage(&mut self) -> &mut ::std::string::String {
+        &mut self.errorMessage
     }
 
     // Take field
-    pub fn take_result(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
-        ::std::mem::replace(&mut self.result, ::protobuf::RepeatedField::new())
+    pub fn take_errorMessage(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.errorMessage, ::std::string::String::new())
+    }
+
+    // repeated .LsmTreeStats partitions = 3;
+
+
+    pub fn get_partitions(&self) -> &[LsmTreeStats] {
+        &self.partitions
+    }
+    pub fn clear_partitions(&mut self) {
+        self.partitions.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_partitions(&mut self, v: ::protobuf::RepeatedField<LsmTreeStats>) {
+        self.partitions = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_partitions(&mut self) -> &mut ::protobuf::RepeatedField<LsmTreeStats> {
+        &mut self.partitions
+    }
+
+    // Take field
+    pub fn take_partitions(&mut self) -> ::protobuf::RepeatedField<LsmTreeStats> {
+        ::std::mem::replace(&mut self.partitions, ::protobuf::RepeatedField::new())
+    }
+
+    // .LsmTreeStats percolator = 4;
+
+
+    pub fn get_percolator(&self) -> &LsmTreeStats {
+        self.percolator.as_ref().unwrap_or_else(|| LsmTreeStats::default_instance())
+    }
+    pub fn clear_percolator(&mut self) {
+        self.percolator.clear();
+    }
+
+    pub fn has_percolator(&self) -> bool {
+        self.percolator.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_percolator(&mut self, v: LsmTreeStats) {
+        self.percolator = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_percolator(&mut self) -> &mut LsmTreeStats {
+        if self.percolator.is_none() {
+            self.percolator.set_default();
+        }
+        self.percolator.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_percolator(&mut self) -> LsmTreeStats {
+        self.percolator.take().unwrap_or_else(|| LsmTreeStats::new())
     }
 }
 
-impl ::protobuf::Message for ScanResponse {
+impl ::protobuf::Message for StatsResponse {
     fn is_initialized(&self) -> bool {
+        for v in &self.partitions {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.percolator {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -1424,11 +12589,17 @@ impl ::protobuf::Message for ScanResponse {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_uint64()?;
-                    self.cursor = tmp;
+                    let tmp = is.read_bool()?;
+                    self.success = tmp;
                 },
                 2 => {
-                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.result)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.errorMessage)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.partitions)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.percolator)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1442,24 +12613,42 @@ impl ::protobuf::Message for ScanResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.cursor != 0 {
-            my_size += ::protobuf::rt::value_size(1, self.cursor, ::protobuf::wire_format::WireTypeVarint);
+        if self.success != false {
+            my_size += 2;
         }
-        for value in &self.result {
-            my_size += ::protobuf::rt::bytes_size(2, &value);
+        if !self.errorMessage.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.errorMessage);
+        }
+        for value in &self.partitions {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
+        if let Some(ref v) = self.percolator.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if self.cursor != 0 {
-            os.write_uint64(1, self.cursor)?;
+        if self.success != false {
+            os.write_bool(1, self.success)?;
         }
-        for v in &self.result {
-            os.write_bytes(2, &v)?;
+        if !self.errorMessage.is_empty() {
+            os.write_string(2, &self.errorMessage)?;
+        }
+        for v in &self.partitions {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
         };
+        if let Some(ref v) = self.percolator.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1490,8 +12679,8 @@ impl ::protobuf::Message for ScanResponse {
         Self::descriptor_static()
     }
 
-    fn new() -> ScanResponse {
-        ScanResponse::new()
+    fn new() -> StatsResponse {
+        StatsResponse::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1499,18 +12688,28 @@ impl ::protobuf::Message for ScanResponse {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "cursor",
-                    |m: &ScanResponse| { &m.cursor },
-                    |m: &mut ScanResponse| { &mut m.cursor },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "success",
+                    |m: &StatsResponse| { &m.success },
+                    |m: &mut StatsResponse| { &mut m.success },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "result",
-                    |m: &ScanResponse| { &m.result },
-                    |m: &mut ScanResponse| { &mut m.result },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "errorMessage",
+                    |m: &StatsResponse| { &m.errorMessage },
+                    |m: &mut StatsResponse| { &mut m.errorMessage },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new_pb_name::<ScanResponse>(
-                    "ScanResponse",
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<LsmTreeStats>>(
+                    "partitions",
+                    |m: &StatsResponse| { &m.partitions },
+                    |m: &mut StatsResponse| { &mut m.partitions },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<LsmTreeStats>>(
+                    "percolator",
+                    |m: &StatsResponse| { &m.percolator },
+                    |m: &mut StatsResponse| { &mut m.percolator },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new_pb_name::<StatsResponse>(
+                    "StatsResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1518,124 +12717,174 @@ impl ::protobuf::Message for ScanResponse {
         }
     }
 
-    fn default_instance() -> &'static ScanResponse {
-        static mut instance: ::protobuf::lazy::Lazy<ScanResponse> = ::protobuf::lazy::Lazy::INIT;
+    fn default_instance() -> &'static StatsResponse {
+        static mut instance: ::protobuf::lazy::Lazy<StatsResponse> = ::protobuf::lazy::Lazy::INIT;
         unsafe {
-            instance.get(ScanResponse::new)
+            instance.get(StatsResponse::new)
         }
     }
 }
 
-impl ::protobuf::Clear for ScanResponse {
+impl ::protobuf::Clear for StatsResponse {
     fn clear(&mut self) {
-        self.cursor = 0;
-        self.result.clear();
+        self.success = false;
+        self.errorMessage.clear();
+        self.partitions.clear();
+        self.percolator.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for ScanResponse {
+impl ::std::fmt::Debug for StatsResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for ScanResponse {
+impl ::protobuf::reflect::ProtobufValue for StatsResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0cminikv.proto\"\x1e\n\nGetRequest\x12\x10\n\x03key\x18\x01\x20\x01(\
-    \x0cR\x03key\"a\n\x0bGetResponse\x12\x18\n\x07success\x18\x01\x20\x01(\
-    \x08R\x07success\x12\"\n\x0cerrorMessage\x18\x02\x20\x01(\tR\x0cerrorMes\
-    sage\x12\x14\n\x05value\x18\x03\x20\x01(\x0cR\x05value\"4\n\nPutRequest\
-    \x12\x10\n\x03key\x18\x01\x20\x01(\x0cR\x03key\x12\x14\n\x05value\x18\
-    \x02\x20\x01(\x0cR\x05value\"K\n\x0bPutResponse\x12\x18\n\x07success\x18\
-    \x01\x20\x01(\x08R\x07success\x12\"\n\x0cerrorMessage\x18\x02\x20\x01(\t\
-    R\x0cerrorMessage\"!\n\rDeleteRequest\x12\x10\n\x03key\x18\x01\x20\x01(\
-    \x0cR\x03key\"N\n\x0eDeleteResponse\x12\x18\n\x07success\x18\x01\x20\x01\
-    (\x08R\x07success\x12\"\n\x0cerrorMessage\x18\x02\x20\x01(\tR\x0cerrorMe\
-    ssage\"%\n\x0bScanRequest\x12\x16\n\x06cursor\x18\x01\x20\x01(\x04R\x06c\
-    ursor\">\n\x0cScanResponse\x12\x16\n\x06cursor\x18\x01\x20\x01(\x04R\x06\
-    cursor\x12\x16\n\x06result\x18\x02\x20\x03(\x0cR\x06result2\xaa\x01\n\
-    \x0cMiniKVServer\x12\"\n\x03Get\x12\x0b.GetRequest\x1a\x0c.GetResponse\"\
-    \0\x12\"\n\x03Put\x12\x0b.PutRequest\x1a\x0c.PutResponse\"\0\x12+\n\x06D\
-    elete\x12\x0e.DeleteRequest\x1a\x0f.DeleteResponse\"\0\x12%\n\x04Scan\
-    \x12\x0c.ScanRequest\x1a\r.ScanResponse\"\0J\x95\x0c\n\x06\x12\x04\0\03\
-    \x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\x81\x01\n\x02\x04\0\x12\x04\x04\0\
-    \x06\x01\x1au\x20todo:\x20since\x20the\x20key\x20and\x20value\x20size\
-    \x20are\x20fixed,\x20true\x20using\x20fixed-length\x20elements\n\x20eg.\
-    \x20u64\n\x20todo:\x20return\x20error\x20message\n\n\n\n\x03\x04\0\x01\
-    \x12\x03\x04\x08\x12\n\x0b\n\x04\x04\0\x02\0\x12\x03\x05\x04\x12\n\r\n\
-    \x05\x04\0\x02\0\x04\x12\x04\x05\x04\x04\x14\n\x0c\n\x05\x04\0\x02\0\x05\
-    \x12\x03\x05\x04\t\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x05\n\r\n\x0c\n\
-    \x05\x04\0\x02\0\x03\x12\x03\x05\x10\x11\n\n\n\x02\x04\x01\x12\x04\x08\0\
-    \x0c\x01\n\n\n\x03\x04\x01\x01\x12\x03\x08\x08\x13\n\x0b\n\x04\x04\x01\
-    \x02\0\x12\x03\t\x04\x15\n\r\n\x05\x04\x01\x02\0\x04\x12\x04\t\x04\x08\
-    \x15\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\t\x04\x08\n\x0c\n\x05\x04\x01\
-    \x02\0\x01\x12\x03\t\t\x10\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\t\x13\
-    \x14\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\n\x04\x1c\n\r\n\x05\x04\x01\x02\
-    \x01\x04\x12\x04\n\x04\t\x15\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03\n\
-    \x04\n\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\n\x0b\x17\n\x0c\n\x05\x04\
-    \x01\x02\x01\x03\x12\x03\n\x1a\x1b\n\x0b\n\x04\x04\x01\x02\x02\x12\x03\
-    \x0b\x04\x14\n\r\n\x05\x04\x01\x02\x02\x04\x12\x04\x0b\x04\n\x1c\n\x0c\n\
-    \x05\x04\x01\x02\x02\x05\x12\x03\x0b\x04\t\n\x0c\n\x05\x04\x01\x02\x02\
-    \x01\x12\x03\x0b\n\x0f\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\x0b\x12\
-    \x13\n\n\n\x02\x04\x02\x12\x04\x0e\0\x11\x01\n\n\n\x03\x04\x02\x01\x12\
-    \x03\x0e\x08\x12\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x0f\x04\x12\n\r\n\x05\
-    \x04\x02\x02\0\x04\x12\x04\x0f\x04\x0e\x14\n\x0c\n\x05\x04\x02\x02\0\x05\
-    \x12\x03\x0f\x04\t\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x0f\n\r\n\x0c\n\
-    \x05\x04\x02\x02\0\x03\x12\x03\x0f\x10\x11\n\x0b\n\x04\x04\x02\x02\x01\
-    \x12\x03\x10\x04\x14\n\r\n\x05\x04\x02\x02\x01\x04\x12\x04\x10\x04\x0f\
-    \x12\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\x10\x04\t\n\x0c\n\x05\x04\
-    \x02\x02\x01\x01\x12\x03\x10\n\x0f\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\
-    \x03\x10\x12\x13\n\n\n\x02\x04\x03\x12\x04\x13\0\x16\x01\n\n\n\x03\x04\
-    \x03\x01\x12\x03\x13\x08\x13\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x14\x04\
-    \x15\n\r\n\x05\x04\x03\x02\0\x04\x12\x04\x14\x04\x13\x15\n\x0c\n\x05\x04\
-    \x03\x02\0\x05\x12\x03\x14\x04\x08\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\
-    \x14\t\x10\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x14\x13\x14\n\x0b\n\x04\
-    \x04\x03\x02\x01\x12\x03\x15\x04\x1c\n\r\n\x05\x04\x03\x02\x01\x04\x12\
-    \x04\x15\x04\x14\x15\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03\x15\x04\n\n\
-    \x0c\n\x05\x04\x03\x02\x01\x01\x12\x03\x15\x0b\x17\n\x0c\n\x05\x04\x03\
-    \x02\x01\x03\x12\x03\x15\x1a\x1b\n\n\n\x02\x04\x04\x12\x04\x18\0\x1a\x01\
-    \n\n\n\x03\x04\x04\x01\x12\x03\x18\x08\x15\n\x0b\n\x04\x04\x04\x02\0\x12\
-    \x03\x19\x04\x12\n\r\n\x05\x04\x04\x02\0\x04\x12\x04\x19\x04\x18\x17\n\
-    \x0c\n\x05\x04\x04\x02\0\x05\x12\x03\x19\x04\t\n\x0c\n\x05\x04\x04\x02\0\
-    \x01\x12\x03\x19\n\r\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03\x19\x10\x11\n\
-    \n\n\x02\x04\x05\x12\x04\x1c\0\x1f\x01\n\n\n\x03\x04\x05\x01\x12\x03\x1c\
-    \x08\x16\n\x0b\n\x04\x04\x05\x02\0\x12\x03\x1d\x04\x15\n\r\n\x05\x04\x05\
-    \x02\0\x04\x12\x04\x1d\x04\x1c\x18\n\x0c\n\x05\x04\x05\x02\0\x05\x12\x03\
-    \x1d\x04\x08\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03\x1d\t\x10\n\x0c\n\x05\
-    \x04\x05\x02\0\x03\x12\x03\x1d\x13\x14\n\x0b\n\x04\x04\x05\x02\x01\x12\
-    \x03\x1e\x04\x1c\n\r\n\x05\x04\x05\x02\x01\x04\x12\x04\x1e\x04\x1d\x15\n\
-    \x0c\n\x05\x04\x05\x02\x01\x05\x12\x03\x1e\x04\n\n\x0c\n\x05\x04\x05\x02\
-    \x01\x01\x12\x03\x1e\x0b\x17\n\x0c\n\x05\x04\x05\x02\x01\x03\x12\x03\x1e\
-    \x1a\x1b\n\n\n\x02\x04\x06\x12\x04!\0#\x01\n\n\n\x03\x04\x06\x01\x12\x03\
-    !\x08\x13\n\x0b\n\x04\x04\x06\x02\0\x12\x03\"\x04\x16\n\r\n\x05\x04\x06\
-    \x02\0\x04\x12\x04\"\x04!\x15\n\x0c\n\x05\x04\x06\x02\0\x05\x12\x03\"\
-    \x04\n\n\x0c\n\x05\x04\x06\x02\0\x01\x12\x03\"\x0b\x11\n\x0c\n\x05\x04\
-    \x06\x02\0\x03\x12\x03\"\x14\x15\n\n\n\x02\x04\x07\x12\x04%\0(\x01\n\n\n\
-    \x03\x04\x07\x01\x12\x03%\x08\x14\n\x0b\n\x04\x04\x07\x02\0\x12\x03&\x04\
-    \x16\n\r\n\x05\x04\x07\x02\0\x04\x12\x04&\x04%\x16\n\x0c\n\x05\x04\x07\
-    \x02\0\x05\x12\x03&\x04\n\n\x0c\n\x05\x04\x07\x02\0\x01\x12\x03&\x0b\x11\
-    \n\x0c\n\x05\x04\x07\x02\0\x03\x12\x03&\x14\x15\n\x0b\n\x04\x04\x07\x02\
-    \x01\x12\x03'\x04\x1e\n\x0c\n\x05\x04\x07\x02\x01\x04\x12\x03'\x04\x0c\n\
-    \x0c\n\x05\x04\x07\x02\x01\x05\x12\x03'\r\x12\n\x0c\n\x05\x04\x07\x02\
-    \x01\x01\x12\x03'\x13\x19\n\x0c\n\x05\x04\x07\x02\x01\x03\x12\x03'\x1c\
-    \x1d\n\n\n\x02\x06\0\x12\x04*\03\x01\n\n\n\x03\x06\0\x01\x12\x03*\x08\
-    \x14\n\x0c\n\x04\x06\0\x02\0\x12\x04+\x04,\x05\n\x0c\n\x05\x06\0\x02\0\
-    \x01\x12\x03+\x08\x0b\n\x0c\n\x05\x06\0\x02\0\x02\x12\x03+\r\x17\n\x0c\n\
-    \x05\x06\0\x02\0\x03\x12\x03+\"-\n\x0c\n\x04\x06\0\x02\x01\x12\x04-\x04.\
-    \x05\n\x0c\n\x05\x06\0\x02\x01\x01\x12\x03-\x08\x0b\n\x0c\n\x05\x06\0\
-    \x02\x01\x02\x12\x03-\r\x17\n\x0c\n\x05\x06\0\x02\x01\x03\x12\x03-\"-\n\
-    \x0c\n\x04\x06\0\x02\x02\x12\x04/\x040\x05\n\x0c\n\x05\x06\0\x02\x02\x01\
-    \x12\x03/\x08\x0e\n\x0c\n\x05\x06\0\x02\x02\x02\x12\x03/\x10\x1d\n\x0c\n\
-    \x05\x06\0\x02\x02\x03\x12\x03/(6\n\x0c\n\x04\x06\0\x02\x03\x12\x041\x04\
-    2\x05\n\x0c\n\x05\x06\0\x02\x03\x01\x12\x031\x08\x0c\n\x0c\n\x05\x06\0\
-    \x02\x03\x02\x12\x031\x0e\x19\n\x0c\n\x05\x06\0\x02\x03\x03\x12\x031$0b\
-    \x06proto3\
+    \n\x0cminikv.proto\x12\0\"0\n\nGetRequest\x12\r\n\x03key\x18\x01\x20\x01\
+    (\x0cB\0\x12\x11\n\x07read_ts\x18\x02\x20\x01(\x04B\0:\0\"q\n\x0bGetResp\
+    onse\x12\x11\n\x07success\x18\x01\x20\x01(\x08B\0\x12\x16\n\x0cerrorMess\
+    age\x18\x02\x20\x01(\tB\0\x12\x0f\n\x05value\x18\x03\x20\x01(\x0cB\0\x12\
+    \x11\n\x07version\x18\x04\x20\x01(\x04B\0\x12\x11\n\x07read_ts\x18\x05\
+    \x20\x01(\x04B\0:\0\"\x8a\x01\n\tCondition\x12\x1f\n\x04kind\x18\x01\x20\
+    \x01(\x0e2\x0f.Condition.KindB\0\x12\x0f\n\x05value\x18\x02\x20\x01(\x0c\
+    B\0\x12\x11\n\x07version\x18\x03\x20\x01(\x04B\0\"6\n\x04Kind\x12\x08\n\
+    \x04NONE\x10\0\x12\n\n\x06ABSENT\x10\x01\x12\t\n\x05EQUAL\x10\x02\x12\
+    \x0b\n\x07VERSION\x10\x03\x1a\0:\0\"a\n\nPutRequest\x12\r\n\x03key\x18\
+    \x01\x20\x01(\x0cB\0\x12\x0f\n\x05value\x18\x02\x20\x01(\x0cB\0\x12\x1f\
+    \n\tcondition\x18\x03\x20\x01(\x0b2\n.ConditionB\0\x12\x10\n\x06ttl_ms\
+    \x18\x04\x20\x01(\x04B\0:\0\"\x94\x01\n\x0bPutResponse\x12\x11\n\x07succ\
+    ess\x18\x01\x20\x01(\x08B\0\x12\x16\n\x0cerrorMessage\x18\x02\x20\x01(\t\
+    B\0\x12\x1a\n\x10condition_failed\x18\x03\x20\x01(\x08B\0\x12\x10\n\x06e\
+    xists\x18\x04\x20\x01(\x08B\0\x12\x17\n\rcurrent_value\x18\x05\x20\x01(\
+    \x0cB\0\x12\x11\n\x07version\x18\x06\x20\x01(\x04B\0:\0\"A\n\rDeleteRequ\
+    est\x12\r\n\x03key\x18\x01\x20\x01(\x0cB\0\x12\x1f\n\tcondition\x18\x02\
+    \x20\x01(\x0b2\n.ConditionB\0:\0\"\x97\x01\n\x0eDeleteResponse\x12\x11\n\
+    \x07success\x18\x01\x20\x01(\x08B\0\x12\x16\n\x0cerrorMessage\x18\x02\
+    \x20\x01(\tB\0\x12\x1a\n\x10condition_failed\x18\x03\x20\x01(\x08B\0\x12\
+    \x10\n\x06exists\x18\x04\x20\x01(\x08B\0\x12\x17\n\rcurrent_value\x18\
+    \x05\x20\x01(\x0cB\0\x12\x11\n\x07version\x18\x06\x20\x01(\x04B\0:\0\"]\
+    \n\x15CompareAndSwapRequest\x12\r\n\x03key\x18\x01\x20\x01(\x0cB\0\x12\
+    \x1e\n\x08expected\x18\x02\x20\x01(\x0b2\n.ConditionB\0\x12\x13\n\tnew_v\
+    alue\x18\x03\x20\x01(\x0cB\0:\0\"\x96\x01\n\x16CompareAndSwapResponse\
+    \x12\x11\n\x07success\x18\x01\x20\x01(\x08B\0\x12\x16\n\x0cerrorMessage\
+    \x18\x02\x20\x01(\tB\0\x12\x11\n\x07swapped\x18\x03\x20\x01(\x08B\0\x12\
+    \x10\n\x06exists\x18\x04\x20\x01(\x08B\0\x12\x17\n\rcurrent_value\x18\
+    \x05\x20\x01(\x0cB\0\x12\x11\n\x07version\x18\x06\x20\x01(\x04B\0:\0\"\\\
+    \n\x0bScanRequest\x12\x10\n\x06cursor\x18\x01\x20\x01(\x04B\0\x12\x0f\n\
+    \x05count\x18\x02\x20\x01(\rB\0\x12\x15\n\x0bwith_values\x18\x03\x20\x01\
+    (\x08B\0\x12\x11\n\x07read_ts\x18\x04\x20\x01(\x04B\0:\0\"\x84\x01\n\x0c\
+    ScanResponse\x12\x10\n\x06cursor\x18\x01\x20\x01(\x04B\0\x12\x10\n\x06re\
+    sult\x18\x02\x20\x03(\x0cB\0\x12\x11\n\x07success\x18\x03\x20\x01(\x08B\
+    \0\x12\x16\n\x0cerrorMessage\x18\x04\x20\x01(\tB\0\x12\x10\n\x06values\
+    \x18\x05\x20\x03(\x0cB\0\x12\x11\n\x07read_ts\x18\x06\x20\x01(\x04B\0:\0\
+    \"s\n\x10RangeScanRequest\x12\x13\n\tstart_key\x18\x01\x20\x01(\x0cB\0\
+    \x12\x11\n\x07end_key\x18\x02\x20\x01(\x0cB\0\x12\x0f\n\x05limit\x18\x03\
+    \x20\x01(\rB\0\x12\x11\n\x07reverse\x18\x04\x20\x01(\x08B\0\x12\x11\n\
+    \x07read_ts\x18\x05\x20\x01(\x04B\0:\0\"*\n\x06KvPair\x12\r\n\x03key\x18\
+    \x01\x20\x01(\x0cB\0\x12\x0f\n\x05value\x18\x02\x20\x01(\x0cB\0:\0\"\x81\
+    \x01\n\x11RangeScanResponse\x12\x11\n\x07success\x18\x01\x20\x01(\x08B\0\
+    \x12\x16\n\x0cerrorMessage\x18\x02\x20\x01(\tB\0\x12\x18\n\x05pairs\x18\
+    \x03\x20\x03(\x0b2\x07.KvPairB\0\x12\x12\n\x08next_key\x18\x04\x20\x01(\
+    \x0cB\0\x12\x11\n\x07read_ts\x18\x05\x20\x01(\x04B\0:\0\"w\n\x11PrefixSc\
+    anRequest\x12\x10\n\x06prefix\x18\x01\x20\x01(\x0cB\0\x12\x13\n\tstart_k\
+    ey\x18\x02\x20\x01(\x0cB\0\x12\x0f\n\x05limit\x18\x03\x20\x01(\rB\0\x12\
+    \x15\n\x0bwith_values\x18\x04\x20\x01(\x08B\0\x12\x11\n\x07read_ts\x18\
+    \x05\x20\x01(\x04B\0:\0\"\x82\x01\n\x12PrefixScanResponse\x12\x11\n\x07s\
+    uccess\x18\x01\x20\x01(\x08B\0\x12\x16\n\x0cerrorMessage\x18\x02\x20\x01\
+    (\tB\0\x12\x18\n\x05pairs\x18\x03\x20\x03(\x0b2\x07.KvPairB\0\x12\x12\n\
+    \x08next_key\x18\x04\x20\x01(\x0cB\0\x12\x11\n\x07read_ts\x18\x05\x20\
+    \x01(\x04B\0:\0\"6\n\x0fBatchGetRequest\x12\x0e\n\x04keys\x18\x01\x20\
+    \x03(\x0cB\0\x12\x11\n\x07read_ts\x18\x02\x20\x01(\x04B\0:\0\"s\n\x10Bat\
+    chGetResponse\x12\x11\n\x07success\x18\x01\x20\x01(\x08B\0\x12\x16\n\x0c\
+    errorMessage\x18\x02\x20\x01(\tB\0\x12\x1f\n\x07results\x18\x03\x20\x03(\
+    \x0b2\x0c.GetResponseB\0\x12\x11\n\x07read_ts\x18\x04\x20\x01(\x04B\0:\0\
+    \"-\n\x0fBatchPutRequest\x12\x18\n\x05pairs\x18\x01\x20\x03(\x0b2\x07.Kv\
+    PairB\0:\0\"`\n\x10BatchPutResponse\x12\x11\n\x07success\x18\x01\x20\x01\
+    (\x08B\0\x12\x16\n\x0cerrorMessage\x18\x02\x20\x01(\tB\0\x12\x1f\n\x07re\
+    sults\x18\x03\x20\x03(\x0b2\x0c.PutResponseB\0:\0\"&\n\x12BatchDeleteReq\
+    uest\x12\x0e\n\x04keys\x18\x01\x20\x03(\x0cB\0:\0\"f\n\x13BatchDeleteRes\
+    ponse\x12\x11\n\x07success\x18\x01\x20\x01(\x08B\0\x12\x16\n\x0cerrorMes\
+    sage\x18\x02\x20\x01(\tB\0\x12\"\n\x07results\x18\x03\x20\x03(\x0b2\x0f.\
+    DeleteResponseB\0:\0\"2\n\rExpireRequest\x12\r\n\x03key\x18\x01\x20\x01(\
+    \x0cB\0\x12\x10\n\x06ttl_ms\x18\x02\x20\x01(\x04B\0:\0\"=\n\x0eExpireRes\
+    ponse\x12\x11\n\x07success\x18\x01\x20\x01(\x08B\0\x12\x16\n\x0cerrorMes\
+    sage\x18\x02\x20\x01(\tB\0:\0\"\x1d\n\nTtlRequest\x12\r\n\x03key\x18\x01\
+    \x20\x01(\x0cB\0:\0\"_\n\x0bTtlResponse\x12\x11\n\x07success\x18\x01\x20\
+    \x01(\x08B\0\x12\x16\n\x0cerrorMessage\x18\x02\x20\x01(\tB\0\x12\x11\n\
+    \x07expires\x18\x03\x20\x01(\x08B\0\x12\x10\n\x06ttl_ms\x18\x04\x20\x01(\
+    \x04B\0:\0\"k\n\x08TxnWrite\x12\x1e\n\x04kind\x18\x01\x20\x01(\x0e2\x0e.\
+    TxnWrite.KindB\0\x12\r\n\x03key\x18\x02\x20\x01(\x0cB\0\x12\x0f\n\x05val\
+    ue\x18\x03\x20\x01(\x0cB\0\"\x1d\n\x04Kind\x12\x07\n\x03PUT\x10\0\x12\n\
+    \n\x06DELETE\x10\x01\x1a\0:\0\"@\n\x0cTxnCondition\x12\r\n\x03key\x18\
+    \x01\x20\x01(\x0cB\0\x12\x1f\n\tcondition\x18\x02\x20\x01(\x0b2\n.Condit\
+    ionB\0:\0\"y\n\nTxnRequest\x12\x12\n\x08start_ts\x18\x01\x20\x01(\x04B\0\
+    \x12\x13\n\tread_keys\x18\x02\x20\x03(\x0cB\0\x12#\n\nconditions\x18\x03\
+    \x20\x03(\x0b2\r.TxnConditionB\0\x12\x1b\n\x06writes\x18\x04\x20\x03(\
+    \x0b2\t.TxnWriteB\0:\0\"\xdc\x01\n\x0bTxnResponse\x12\x11\n\x07success\
+    \x18\x01\x20\x01(\x08B\0\x12\x16\n\x0cerrorMessage\x18\x02\x20\x01(\tB\0\
+    \x12\x12\n\x08conflict\x18\x03\x20\x01(\x08B\0\x12\x16\n\x0cconflict_key\
+    \x18\x04\x20\x01(\x0cB\0\x12\x1a\n\x10condition_failed\x18\x05\x20\x01(\
+    \x08B\0\x12\x1a\n\x10failed_condition\x18\x06\x20\x01(\rB\0\x12\x10\n\
+    \x06exists\x18\x07\x20\x01(\x08B\0\x12\x17\n\rcurrent_value\x18\x08\x20\
+    \x01(\x0cB\0\x12\x11\n\x07version\x18\t\x20\x01(\x04B\0:\0\"\x17\n\x13Ge\
+    tTimestampRequest:\0\"X\n\x14GetTimestampResponse\x12\x11\n\x07success\
+    \x18\x01\x20\x01(\x08B\0\x12\x16\n\x0cerrorMessage\x18\x02\x20\x01(\tB\0\
+    \x12\x13\n\ttimestamp\x18\x03\x20\x01(\x04B\0:\0\"T\n\x08LockInfo\x12\r\
+    \n\x03key\x18\x01\x20\x01(\x0cB\0\x12\x11\n\x07primary\x18\x02\x20\x01(\
+    \x0cB\0\x12\x12\n\x08start_ts\x18\x03\x20\x01(\x04B\0\x12\x10\n\x06ttl_m\
+    s\x18\x04\x20\x01(\x04B\0:\0\"3\n\x0cKvGetRequest\x12\r\n\x03key\x18\x01\
+    \x20\x01(\x0cB\0\x12\x12\n\x08start_ts\x18\x02\x20\x01(\x04B\0:\0\"|\n\r\
+    KvGetResponse\x12\x11\n\x07success\x18\x01\x20\x01(\x08B\0\x12\x16\n\x0c\
+    errorMessage\x18\x02\x20\x01(\tB\0\x12\x10\n\x06exists\x18\x03\x20\x01(\
+    \x08B\0\x12\x0f\n\x05value\x18\x04\x20\x01(\x0cB\0\x12\x1b\n\x06locked\
+    \x18\x05\x20\x01(\x0b2\t.LockInfoB\0:\0\"s\n\x11KvPrewriteRequest\x12\
+    \x1e\n\tmutations\x18\x01\x20\x03(\x0b2\t.TxnWriteB\0\x12\x11\n\x07prima\
+    ry\x18\x02\x20\x01(\x0cB\0\x12\x12\n\x08start_ts\x18\x03\x20\x01(\x04B\0\
+    \x12\x15\n\x0block_ttl_ms\x18\x04\x20\x01(\x04B\0:\0\"\xa1\x01\n\x12KvPr\
+    ewriteResponse\x12\x11\n\x07success\x18\x01\x20\x01(\x08B\0\x12\x16\n\
+    \x0cerrorMessage\x18\x02\x20\x01(\tB\0\x12\x1b\n\x06locked\x18\x03\x20\
+    \x01(\x0b2\t.LockInfoB\0\x12\x12\n\x08conflict\x18\x04\x20\x01(\x08B\0\
+    \x12\x16\n\x0cconflict_key\x18\x05\x20\x01(\x0cB\0\x12\x15\n\x0bconflict\
+    _ts\x18\x06\x20\x01(\x04B\0:\0\"L\n\x0fKvCommitRequest\x12\x0e\n\x04keys\
+    \x18\x01\x20\x03(\x0cB\0\x12\x12\n\x08start_ts\x18\x02\x20\x01(\x04B\0\
+    \x12\x13\n\tcommit_ts\x18\x03\x20\x01(\x04B\0:\0\"V\n\x10KvCommitRespons\
+    e\x12\x11\n\x07success\x18\x01\x20\x01(\x08B\0\x12\x16\n\x0cerrorMessage\
+    \x18\x02\x20\x01(\tB\0\x12\x15\n\x0brolled_back\x18\x03\x20\x01(\x08B\0:\
+    \0\"9\n\x11KvRollbackRequest\x12\x0e\n\x04keys\x18\x01\x20\x03(\x0cB\0\
+    \x12\x12\n\x08start_ts\x18\x02\x20\x01(\x04B\0:\0\"k\n\x12KvRollbackResp\
+    onse\x12\x11\n\x07success\x18\x01\x20\x01(\x08B\0\x12\x16\n\x0cerrorMess\
+    age\x18\x02\x20\x01(\tB\0\x12\x13\n\tcommitted\x18\x03\x20\x01(\x08B\0\
+    \x12\x13\n\tcommit_ts\x18\x04\x20\x01(\x04B\0:\0\"F\n\x17KvCheckTxnStatu\
+    sRequest\x12\x15\n\x0bprimary_key\x18\x01\x20\x01(\x0cB\0\x12\x12\n\x08s\
+    tart_ts\x18\x02\x20\x01(\x04B\0:\0\"\xda\x01\n\x18KvCheckTxnStatusRespon\
+    se\x12\x11\n\x07success\x18\x01\x20\x01(\x08B\0\x12\x16\n\x0cerrorMessag\
+    e\x18\x02\x20\x01(\tB\0\x122\n\x06status\x18\x03\x20\x01(\x0e2\x20.KvChe\
+    ckTxnStatusResponse.StatusB\0\x12\x13\n\tcommit_ts\x18\x04\x20\x01(\x04B\
+    \0\x12\x10\n\x06ttl_ms\x18\x05\x20\x01(\x04B\0\"6\n\x06Status\x12\n\n\
+    \x06LOCKED\x10\0\x12\r\n\tCOMMITTED\x10\x01\x12\x0f\n\x0bROLLED_BACK\x10\
+    \x02\x1a\0:\0\"\x11\n\rBackupRequest:\0\"!\n\rSnapshotChunk\x12\x0e\n\
+    \x04data\x18\x01\x20\x01(\x0cB\0:\0\"O\n\x0fRestoreResponse\x12\x11\n\
+    \x07success\x18\x01\x20\x01(\x08B\0\x12\x16\n\x0cerrorMessage\x18\x02\
+    \x20\x01(\tB\0\x12\x0f\n\x05count\x18\x03\x20\x01(\x04B\0:\0\"\x10\n\x0c\
+    StatsRequest:\0\"\x80\x02\n\x0cLsmTreeStats\x12\x17\n\rflushed_bytes\x18\
+    \x01\x20\x01(\x04B\0\x12\x1f\n\x15compaction_read_bytes\x18\x02\x20\x01(\
+    \x04B\0\x12\"\n\x18compaction_written_bytes\x18\x03\x20\x01(\x04B\0\x12\
+    \x15\n\x0bcompactions\x18\x04\x20\x01(\x04B\0\x12\"\n\x18pending_compact\
+    ion_bytes\x18\x05\x20\x01(\x04B\0\x12\x15\n\x0blevel_bytes\x18\x06\x20\
+    \x03(\x04B\0\x12\x1f\n\x15bloom_false_positives\x18\x07\x20\x01(\x04B\0\
+    \x12\x1d\n\x13write_amplification\x18\x08\x20\x01(\x01B\0:\0\"\x86\x01\n\
+    \rStatsResponse\x12\x11\n\x07success\x18\x01\x20\x01(\x08B\0\x12\x16\n\
+    \x0cerrorMessage\x18\x02\x20\x01(\tB\0\x12#\n\npartitions\x18\x03\x20\
+    \x03(\x0b2\r.LsmTreeStatsB\0\x12#\n\npercolator\x18\x04\x20\x01(\x0b2\r.\
+    LsmTreeStatsB\0:\0B\0b\x06proto3\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy::INIT;
@@ -39,6 +39,27 @@ const METHOD_MINI_KV_SERVER_DELETE: ::grpcio::Method<super::minikv::DeleteReques
     resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
 };
 
+const METHOD_MINI_KV_SERVER_COMPARE_AND_SWAP: ::grpcio::Method<super::minikv::CompareAndSwapRequest, super::minikv::CompareAndSwapResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/MiniKVServer/CompareAndSwap",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_MINI_KV_SERVER_EXPIRE: ::grpcio::Method<super::minikv::ExpireRequest, super::minikv::ExpireResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/MiniKVServer/Expire",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_MINI_KV_SERVER_TTL: ::grpcio::Method<super::minikv::TtlRequest, super::minikv::TtlResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/MiniKVServer/Ttl",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
 const METHOD_MINI_KV_SERVER_SCAN: ::grpcio::Method<super::minikv::ScanRequest, super::minikv::ScanResponse> = ::grpcio::Method {
     ty: ::grpcio::MethodType::Unary,
     name: "/MiniKVServer/Scan",
@@ -46,6 +67,111 @@ const METHOD_MINI_KV_SERVER_SCAN: ::grpcio::Method<super::minikv::ScanRequest, s
     resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
 };
 
+const METHOD_MINI_KV_SERVER_RANGE_SCAN: ::grpcio::Method<super::minikv::RangeScanRequest, super::minikv::RangeScanResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/MiniKVServer/RangeScan",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_MINI_KV_SERVER_PREFIX_SCAN: ::grpcio::Method<super::minikv::PrefixScanRequest, super::minikv::PrefixScanResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/MiniKVServer/PrefixScan",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_MINI_KV_SERVER_BATCH_GET: ::grpcio::Method<super::minikv::BatchGetRequest, super::minikv::BatchGetResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/MiniKVServer/BatchGet",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_MINI_KV_SERVER_BATCH_PUT: ::grpcio::Method<super::minikv::BatchPutRequest, super::minikv::BatchPutResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/MiniKVServer/BatchPut",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_MINI_KV_SERVER_BATCH_DELETE: ::grpcio::Method<super::minikv::BatchDeleteRequest, super::minikv::BatchDeleteResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/MiniKVServer/BatchDelete",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_MINI_KV_SERVER_TXN: ::grpcio::Method<super::minikv::TxnRequest, super::minikv::TxnResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/MiniKVServer/Txn",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_MINI_KV_SERVER_GET_TIMESTAMP: ::grpcio::Method<super::minikv::GetTimestampRequest, super::minikv::GetTimestampResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/MiniKVServer/GetTimestamp",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_MINI_KV_SERVER_KV_GET: ::grpcio::Method<super::minikv::KvGetRequest, super::minikv::KvGetResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/MiniKVServer/KvGet",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_MINI_KV_SERVER_KV_PREWRITE: ::grpcio::Method<super::minikv::KvPrewriteRequest, super::minikv::KvPrewriteResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/MiniKVServer/KvPrewrite",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_MINI_KV_SERVER_KV_COMMIT: ::grpcio::Method<super::minikv::KvCommitRequest, super::minikv::KvCommitResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/MiniKVServer/KvCommit",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_MINI_KV_SERVER_KV_ROLLBACK: ::grpcio::Method<super::minikv::KvRollbackRequest, super::minikv::KvRollbackResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/MiniKVServer/KvRollback",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_MINI_KV_SERVER_KV_CHECK_TXN_STATUS: ::grpcio::Method<super::minikv::KvCheckTxnStatusRequest, super::minikv::KvCheckTxnStatusResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/MiniKVServer/KvCheckTxnStatus",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_MINI_KV_SERVER_BACKUP: ::grpcio::Method<super::minikv::BackupRequest, super::minikv::SnapshotChunk> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::ServerStreaming,
+    name: "/MiniKVServer/Backup",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_MINI_KV_SERVER_RESTORE: ::grpcio::Method<super::minikv::SnapshotChunk, super::minikv::RestoreResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::ClientStreaming,
+    name: "/MiniKVServer/Restore",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_MINI_KV_SERVER_STATS: ::grpcio::Method<super::minikv::StatsRequest, super::minikv::StatsResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/MiniKVServer/Stats",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
 #[derive(Clone)]
 pub struct MiniKvServerClient {
     client: ::grpcio::Client,
@@ -106,6 +232,54 @@ impl MiniKvServerClient {
         self.delete_async_opt(req, ::grpcio::CallOption::default())
     }
 
+    pub fn compare_and_swap_opt(&self, req: &super::minikv::CompareAndSwapRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::minikv::CompareAndSwapResponse> {
+        self.client.unary_call(&METHOD_MINI_KV_SERVER_COMPARE_AND_SWAP, req, opt)
+    }
+
+    pub fn compare_and_swap(&self, req: &super::minikv::CompareAndSwapRequest) -> ::grpcio::Result<super::minikv::CompareAndSwapResponse> {
+        self.compare_and_swap_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn compare_and_swap_async_opt(&self, req: &super::minikv::CompareAndSwapRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::CompareAndSwapResponse>> {
+        self.client.unary_call_async(&METHOD_MINI_KV_SERVER_COMPARE_AND_SWAP, req, opt)
+    }
+
+    pub fn compare_and_swap_async(&self, req: &super::minikv::CompareAndSwapRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::CompareAndSwapResponse>> {
+        self.compare_and_swap_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn expire_opt(&self, req: &super::minikv::ExpireRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::minikv::ExpireResponse> {
+        self.client.unary_call(&METHOD_MINI_KV_SERVER_EXPIRE, req, opt)
+    }
+
+    pub fn expire(&self, req: &super::minikv::ExpireRequest) -> ::grpcio::Result<super::minikv::ExpireResponse> {
+        self.expire_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn expire_async_opt(&self, req: &super::minikv::ExpireRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::ExpireResponse>> {
+        self.client.unary_call_async(&METHOD_MINI_KV_SERVER_EXPIRE, req, opt)
+    }
+
+    pub fn expire_async(&self, req: &super::minikv::ExpireRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::ExpireResponse>> {
+        self.expire_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn ttl_opt(&self, req: &super::minikv::TtlRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::minikv::TtlResponse> {
+        self.client.unary_call(&METHOD_MINI_KV_SERVER_TTL, req, opt)
+    }
+
+    pub fn ttl(&self, req: &super::minikv::TtlRequest) -> ::grpcio::Result<super::minikv::TtlResponse> {
+        self.ttl_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn ttl_async_opt(&self, req: &super::minikv::TtlRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::TtlResponse>> {
+        self.client.unary_call_async(&METHOD_MINI_KV_SERVER_TTL, req, opt)
+    }
+
+    pub fn ttl_async(&self, req: &super::minikv::TtlRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::TtlResponse>> {
+        self.ttl_async_opt(req, ::grpcio::CallOption::default())
+    }
+
     pub fn scan_opt(&self, req: &super::minikv::ScanRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::minikv::ScanResponse> {
         self.client.unary_call(&METHOD_MINI_KV_SERVER_SCAN, req, opt)
     }
@@ -121,6 +295,230 @@ impl MiniKvServerClient {
     pub fn scan_async(&self, req: &super::minikv::ScanRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::ScanResponse>> {
         self.scan_async_opt(req, ::grpcio::CallOption::default())
     }
+
+    pub fn range_scan_opt(&self, req: &super::minikv::RangeScanRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::minikv::RangeScanResponse> {
+        self.client.unary_call(&METHOD_MINI_KV_SERVER_RANGE_SCAN, req, opt)
+    }
+
+    pub fn range_scan(&self, req: &super::minikv::RangeScanRequest) -> ::grpcio::Result<super::minikv::RangeScanResponse> {
+        self.range_scan_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn range_scan_async_opt(&self, req: &super::minikv::RangeScanRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::RangeScanResponse>> {
+        self.client.unary_call_async(&METHOD_MINI_KV_SERVER_RANGE_SCAN, req, opt)
+    }
+
+    pub fn range_scan_async(&self, req: &super::minikv::RangeScanRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::RangeScanResponse>> {
+        self.range_scan_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn prefix_scan_opt(&self, req: &super::minikv::PrefixScanRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::minikv::PrefixScanResponse> {
+        self.client.unary_call(&METHOD_MINI_KV_SERVER_PREFIX_SCAN, req, opt)
+    }
+
+    pub fn prefix_scan(&self, req: &super::minikv::PrefixScanRequest) -> ::grpcio::Result<super::minikv::PrefixScanResponse> {
+        self.prefix_scan_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn prefix_scan_async_opt(&self, req: &super::minikv::PrefixScanRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::PrefixScanResponse>> {
+        self.client.unary_call_async(&METHOD_MINI_KV_SERVER_PREFIX_SCAN, req, opt)
+    }
+
+    pub fn prefix_scan_async(&self, req: &super::minikv::PrefixScanRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::PrefixScanResponse>> {
+        self.prefix_scan_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn batch_get_opt(&self, req: &super::minikv::BatchGetRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::minikv::BatchGetResponse> {
+        self.client.unary_call(&METHOD_MINI_KV_SERVER_BATCH_GET, req, opt)
+    }
+
+    pub fn batch_get(&self, req: &super::minikv::BatchGetRequest) -> ::grpcio::Result<super::minikv::BatchGetResponse> {
+        self.batch_get_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn batch_get_async_opt(&self, req: &super::minikv::BatchGetRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::BatchGetResponse>> {
+        self.client.unary_call_async(&METHOD_MINI_KV_SERVER_BATCH_GET, req, opt)
+    }
+
+    pub fn batch_get_async(&self, req: &super::minikv::BatchGetRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::BatchGetResponse>> {
+        self.batch_get_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn batch_put_opt(&self, req: &super::minikv::BatchPutRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::minikv::BatchPutResponse> {
+        self.client.unary_call(&METHOD_MINI_KV_SERVER_BATCH_PUT, req, opt)
+    }
+
+    pub fn batch_put(&self, req: &super::minikv::BatchPutRequest) -> ::grpcio::Result<super::minikv::BatchPutResponse> {
+        self.batch_put_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn batch_put_async_opt(&self, req: &super::minikv::BatchPutRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::BatchPutResponse>> {
+        self.client.unary_call_async(&METHOD_MINI_KV_SERVER_BATCH_PUT, req, opt)
+    }
+
+    pub fn batch_put_async(&self, req: &super::minikv::BatchPutRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::BatchPutResponse>> {
+        self.batch_put_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn batch_delete_opt(&self, req: &super::minikv::BatchDeleteRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::minikv::BatchDeleteResponse> {
+        self.client.unary_call(&METHOD_MINI_KV_SERVER_BATCH_DELETE, req, opt)
+    }
+
+    pub fn batch_delete(&self, req: &super::minikv::BatchDeleteRequest) -> ::grpcio::Result<super::minikv::BatchDeleteResponse> {
+        self.batch_delete_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn batch_delete_async_opt(&self, req: &super::minikv::BatchDeleteRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::BatchDeleteResponse>> {
+        self.client.unary_call_async(&METHOD_MINI_KV_SERVER_BATCH_DELETE, req, opt)
+    }
+
+    pub fn batch_delete_async(&self, req: &super::minikv::BatchDeleteRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::BatchDeleteResponse>> {
+        self.batch_delete_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn txn_opt(&self, req: &super::minikv::TxnRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::minikv::TxnResponse> {
+        self.client.unary_call(&METHOD_MINI_KV_SERVER_TXN, req, opt)
+    }
+
+    pub fn txn(&self, req: &super::minikv::TxnRequest) -> ::grpcio::Result<super::minikv::TxnResponse> {
+        self.txn_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn txn_async_opt(&self, req: &super::minikv::TxnRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::TxnResponse>> {
+        self.client.unary_call_async(&METHOD_MINI_KV_SERVER_TXN, req, opt)
+    }
+
+    pub fn txn_async(&self, req: &super::minikv::TxnRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::TxnResponse>> {
+        self.txn_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_timestamp_opt(&self, req: &super::minikv::GetTimestampRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::minikv::GetTimestampResponse> {
+        self.client.unary_call(&METHOD_MINI_KV_SERVER_GET_TIMESTAMP, req, opt)
+    }
+
+    pub fn get_timestamp(&self, req: &super::minikv::GetTimestampRequest) -> ::grpcio::Result<super::minikv::GetTimestampResponse> {
+        self.get_timestamp_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_timestamp_async_opt(&self, req: &super::minikv::GetTimestampRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::GetTimestampResponse>> {
+        self.client.unary_call_async(&METHOD_MINI_KV_SERVER_GET_TIMESTAMP, req, opt)
+    }
+
+    pub fn get_timestamp_async(&self, req: &super::minikv::GetTimestampRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::GetTimestampResponse>> {
+        self.get_timestamp_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn kv_get_opt(&self, req: &super::minikv::KvGetRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::minikv::KvGetResponse> {
+        self.client.unary_call(&METHOD_MINI_KV_SERVER_KV_GET, req, opt)
+    }
+
+    pub fn kv_get(&self, req: &super::minikv::KvGetRequest) -> ::grpcio::Result<super::minikv::KvGetResponse> {
+        self.kv_get_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn kv_get_async_opt(&self, req: &super::minikv::KvGetRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::KvGetResponse>> {
+        self.client.unary_call_async(&METHOD_MINI_KV_SERVER_KV_GET, req, opt)
+    }
+
+    pub fn kv_get_async(&self, req: &super::minikv::KvGetRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::KvGetResponse>> {
+        self.kv_get_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn kv_prewrite_opt(&self, req: &super::minikv::KvPrewriteRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::minikv::KvPrewriteResponse> {
+        self.client.unary_call(&METHOD_MINI_KV_SERVER_KV_PREWRITE, req, opt)
+    }
+
+    pub fn kv_prewrite(&self, req: &super::minikv::KvPrewriteRequest) -> ::grpcio::Result<super::minikv::KvPrewriteResponse> {
+        self.kv_prewrite_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn kv_prewrite_async_opt(&self, req: &super::minikv::KvPrewriteRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::KvPrewriteResponse>> {
+        self.client.unary_call_async(&METHOD_MINI_KV_SERVER_KV_PREWRITE, req, opt)
+    }
+
+    pub fn kv_prewrite_async(&self, req: &super::minikv::KvPrewriteRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::KvPrewriteResponse>> {
+        self.kv_prewrite_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn kv_commit_opt(&self, req: &super::minikv::KvCommitRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::minikv::KvCommitResponse> {
+        self.client.unary_call(&METHOD_MINI_KV_SERVER_KV_COMMIT, req, opt)
+    }
+
+    pub fn kv_commit(&self, req: &super::minikv::KvCommitRequest) -> ::grpcio::Result<super::minikv::KvCommitResponse> {
+        self.kv_commit_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn kv_commit_async_opt(&self, req: &super::minikv::KvCommitRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::KvCommitResponse>> {
+        self.client.unary_call_async(&METHOD_MINI_KV_SERVER_KV_COMMIT, req, opt)
+    }
+
+    pub fn kv_commit_async(&self, req: &super::minikv::KvCommitRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::KvCommitResponse>> {
+        self.kv_commit_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn kv_rollback_opt(&self, req: &super::minikv::KvRollbackRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::minikv::KvRollbackResponse> {
+        self.client.unary_call(&METHOD_MINI_KV_SERVER_KV_ROLLBACK, req, opt)
+    }
+
+    pub fn kv_rollback(&self, req: &super::minikv::KvRollbackRequest) -> ::grpcio::Result<super::minikv::KvRollbackResponse> {
+        self.kv_rollback_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn kv_rollback_async_opt(&self, req: &super::minikv::KvRollbackRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::KvRollbackResponse>> {
+        self.client.unary_call_async(&METHOD_MINI_KV_SERVER_KV_ROLLBACK, req, opt)
+    }
+
+    pub fn kv_rollback_async(&self, req: &super::minikv::KvRollbackRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::KvRollbackResponse>> {
+        self.kv_rollback_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn kv_check_txn_status_opt(&self, req: &super::minikv::KvCheckTxnStatusRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::minikv::KvCheckTxnStatusResponse> {
+        self.client.unary_call(&METHOD_MINI_KV_SERVER_KV_CHECK_TXN_STATUS, req, opt)
+    }
+
+    pub fn kv_check_txn_status(&self, req: &super::minikv::KvCheckTxnStatusRequest) -> ::grpcio::Result<super::minikv::KvCheckTxnStatusResponse> {
+        self.kv_check_txn_status_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn kv_check_txn_status_async_opt(&self, req: &super::minikv::KvCheckTxnStatusRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::KvCheckTxnStatusResponse>> {
+        self.client.unary_call_async(&METHOD_MINI_KV_SERVER_KV_CHECK_TXN_STATUS, req, opt)
+    }
+
+    pub fn kv_check_txn_status_async(&self, req: &super::minikv::KvCheckTxnStatusRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::KvCheckTxnStatusResponse>> {
+        self.kv_check_txn_status_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn backup_opt(&self, req: &super::minikv::BackupRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientSStreamReceiver<super::minikv::SnapshotChunk>> {
+        self.client.server_streaming(&METHOD_MINI_KV_SERVER_BACKUP, req, opt)
+    }
+
+    pub fn backup(&self, req: &super::minikv::BackupRequest) -> ::grpcio::Result<::grpcio::ClientSStreamReceiver<super::minikv::SnapshotChunk>> {
+        self.backup_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn restore_opt(&self, opt: ::grpcio::CallOption) -> ::grpcio::Result<(::grpcio::ClientCStreamSender<super::minikv::SnapshotChunk>, ::grpcio::ClientCStreamReceiver<super::minikv::RestoreResponse>)> {
+        self.client.client_streaming(&METHOD_MINI_KV_SERVER_RESTORE, opt)
+    }
+
+    pub fn restore(&self) -> ::grpcio::Result<(::grpcio::ClientCStreamSender<super::minikv::SnapshotChunk>, ::grpcio::ClientCStreamReceiver<super::minikv::RestoreResponse>)> {
+        self.restore_opt(::grpcio::CallOption::default())
+    }
+
+    pub fn stats_opt(&self, req: &super::minikv::StatsRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::minikv::StatsResponse> {
+        self.client.unary_call(&METHOD_MINI_KV_SERVER_STATS, req, opt)
+    }
+
+    pub fn stats(&self, req: &super::minikv::StatsRequest) -> ::grpcio::Result<super::minikv::StatsResponse> {
+        self.stats_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn stats_async_opt(&self, req: &super::minikv::StatsRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::StatsResponse>> {
+        self.client.unary_call_async(&METHOD_MINI_KV_SERVER_STATS, req, opt)
+    }
+
+    pub fn stats_async(&self, req: &super::minikv::StatsRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::minikv::StatsResponse>> {
+        self.stats_async_opt(req, ::grpcio::CallOption::default())
+    }
     // pub fn spawn<F>(&self, f: F) where F: ::futures::Future<Item = (), Error = ()> + Send + 'static {
     //     self.client.spawn(f)
     // }
@@ -130,7 +528,25 @@ pub trait MiniKvServer {
     fn get(&mut self, ctx: ::grpcio::RpcContext, req: super::minikv::GetRequest, sink: ::grpcio::UnarySink<super::minikv::GetResponse>);
     fn put(&mut self, ctx: ::grpcio::RpcContext, req: super::minikv::PutRequest, sink: ::grpcio::UnarySink<super::minikv::PutResponse>);
     fn delete(&mut self, ctx: ::grpcio::RpcContext, req: super::minikv::DeleteRequest, sink: ::grpcio::UnarySink<super::minikv::DeleteResponse>);
+    fn compare_and_swap(&mut self, ctx: ::grpcio::RpcContext, req: super::minikv::CompareAndSwapRequest, sink: ::grpcio::UnarySink<super::minikv::CompareAndSwapResponse>);
+    fn expire(&mut self, ctx: ::grpcio::RpcContext, req: super::minikv::ExpireRequest, sink: ::grpcio::UnarySink<super::minikv::ExpireResponse>);
+    fn ttl(&mut self, ctx: ::grpcio::RpcContext, req: super::minikv::TtlRequest, sink: ::grpcio::UnarySink<super::minikv::TtlResponse>);
     fn scan(&mut self, ctx: ::grpcio::RpcContext, req: super::minikv::ScanRequest, sink: ::grpcio::UnarySink<super::minikv::ScanResponse>);
+    fn range_scan(&mut self, ctx: ::grpcio::RpcContext, req: super::minikv::RangeScanRequest, sink: ::grpcio::UnarySink<super::minikv::RangeScanResponse>);
+    fn prefix_scan(&mut self, ctx: ::grpcio::RpcContext, req: super::minikv::PrefixScanRequest, sink: ::grpcio::UnarySink<super::minikv::PrefixScanResponse>);
+    fn batch_get(&mut self, ctx: ::grpcio::RpcContext, req: super::minikv::BatchGetRequest, sink: ::grpcio::UnarySink<super::minikv::BatchGetResponse>);
+    fn batch_put(&mut self, ctx: ::grpcio::RpcContext, req: super::minikv::BatchPutRequest, sink: ::grpcio::UnarySink<super::minikv::BatchPutResponse>);
+    fn batch_delete(&mut self, ctx: ::grpcio::RpcContext, req: super::minikv::BatchDeleteRequest, sink: ::grpcio::UnarySink<super::minikv::BatchDeleteResponse>);
+    fn txn(&mut self, ctx: ::grpcio::RpcContext, req: super::minikv::TxnRequest, sink: ::grpcio::UnarySink<super::minikv::TxnResponse>);
+    fn get_timestamp(&mut self, ctx: ::grpcio::RpcContext, req: super::minikv::GetTimestampRequest, sink: ::grpcio::UnarySink<super::minikv::GetTimestampResponse>);
+    fn kv_get(&mut self, ctx: ::grpcio::RpcContext, req: super::minikv::KvGetRequest, sink: ::grpcio::UnarySink<super::minikv::KvGetResponse>);
+    fn kv_prewrite(&mut self, ctx: ::grpcio::RpcContext, req: super::minikv::KvPrewriteRequest, sink: ::grpcio::UnarySink<super::minikv::KvPrewriteResponse>);
+    fn kv_commit(&mut self, ctx: ::grpcio::RpcContext, req: super::minikv::KvCommitRequest, sink: ::grpcio::UnarySink<super::minikv::KvCommitResponse>);
+    fn kv_rollback(&mut self, ctx: ::grpcio::RpcContext, req: super::minikv::KvRollbackRequest, sink: ::grpcio::UnarySink<super::minikv::KvRollbackResponse>);
+    fn kv_check_txn_status(&mut self, ctx: ::grpcio::RpcContext, req: super::minikv::KvCheckTxnStatusRequest, sink: ::grpcio::UnarySink<super::minikv::KvCheckTxnStatusResponse>);
+    fn backup(&mut self, ctx: ::grpcio::RpcContext, req: super::minikv::BackupRequest, sink: ::grpcio::ServerStreamingSink<super::minikv::SnapshotChunk>);
+    fn restore(&mut self, ctx: ::grpcio::RpcContext, stream: ::grpcio::RequestStream<super::minikv::SnapshotChunk>, sink: ::grpcio::ClientStreamingSink<super::minikv::RestoreResponse>);
+    fn stats(&mut self, ctx: ::grpcio::RpcContext, req: super::minikv::StatsRequest, sink: ::grpcio::UnarySink<super::minikv::StatsResponse>);
 }
 
 pub fn create_mini_kv_server<S: MiniKvServer + Send + Clone + 'static>(s: S) -> ::grpcio::Service {
@@ -147,9 +563,81 @@ pub fn create_mini_kv_server<S: MiniKvServer + Send + Clone + 'static>(s: S) ->
     builder = builder.add_unary_handler(&METHOD_MINI_KV_SERVER_DELETE, move |ctx, req, resp| {
         instance.delete(ctx, req, resp)
     });
-    let mut instance = s;
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_MINI_KV_SERVER_COMPARE_AND_SWAP, move |ctx, req, resp| {
+        instance.compare_and_swap(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_MINI_KV_SERVER_EXPIRE, move |ctx, req, resp| {
+        instance.expire(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_MINI_KV_SERVER_TTL, move |ctx, req, resp| {
+        instance.ttl(ctx, req, resp)
+    });
+    let mut instance = s.clone();
     builder = builder.add_unary_handler(&METHOD_MINI_KV_SERVER_SCAN, move |ctx, req, resp| {
         instance.scan(ctx, req, resp)
     });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_MINI_KV_SERVER_RANGE_SCAN, move |ctx, req, resp| {
+        instance.range_scan(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_MINI_KV_SERVER_PREFIX_SCAN, move |ctx, req, resp| {
+        instance.prefix_scan(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_MINI_KV_SERVER_BATCH_GET, move |ctx, req, resp| {
+        instance.batch_get(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_MINI_KV_SERVER_BATCH_PUT, move |ctx, req, resp| {
+        instance.batch_put(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_MINI_KV_SERVER_BATCH_DELETE, move |ctx, req, resp| {
+        instance.batch_delete(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_MINI_KV_SERVER_TXN, move |ctx, req, resp| {
+        instance.txn(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_MINI_KV_SERVER_GET_TIMESTAMP, move |ctx, req, resp| {
+        instance.get_timestamp(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_MINI_KV_SERVER_KV_GET, move |ctx, req, resp| {
+        instance.kv_get(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_MINI_KV_SERVER_KV_PREWRITE, move |ctx, req, resp| {
+        instance.kv_prewrite(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_MINI_KV_SERVER_KV_COMMIT, move |ctx, req, resp| {
+        instance.kv_commit(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_MINI_KV_SERVER_KV_ROLLBACK, move |ctx, req, resp| {
+        instance.kv_rollback(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_MINI_KV_SERVER_KV_CHECK_TXN_STATUS, move |ctx, req, resp| {
+        instance.kv_check_txn_status(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_server_streaming_handler(&METHOD_MINI_KV_SERVER_BACKUP, move |ctx, req, resp| {
+        instance.backup(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_client_streaming_handler(&METHOD_MINI_KV_SERVER_RESTORE, move |ctx, req, resp| {
+        instance.restore(ctx, req, resp)
+    });
+    let mut instance = s;
+    builder = builder.add_unary_handler(&METHOD_MINI_KV_SERVER_STATS, move |ctx, req, resp| {
+        instance.stats(ctx, req, resp)
+    });
     builder.build()
 }
@@ -1,12 +1,96 @@
 use grpcio::{ChannelBuilder, EnvBuilder};
 use std::sync::Arc;
 use rpc::minikv_grpc::MiniKvServerClient;
-use rpc::minikv::{GetRequest, PutRequest, DeleteRequest, ScanRequest};
+use rpc::minikv::{GetRequest, PutRequest, DeleteRequest, Condition_Kind, CompareAndSwapRequest, TxnRequest, TxnCondition, TxnWrite, TxnWrite_Kind, GetTimestampRequest, LockInfo, KvGetRequest, KvPrewriteRequest, KvCommitRequest, KvRollbackRequest, KvCheckTxnStatusRequest, KvCheckTxnStatusResponse_Status, ExpireRequest, TtlRequest, ScanRequest, RangeScanRequest, PrefixScanRequest, KvPair, BatchGetRequest, BatchPutRequest, BatchDeleteRequest, BackupRequest, SnapshotChunk, StatsRequest, LsmTreeStats};
 use std::io::{stdin, BufRead, stdout};
 use std::str::{from_utf8, FromStr};
-use std::io::Write;
-use mini_kv::shared::bytes::get_bytes_with_fill;
+use std::io::{Read, Write};
+use std::fs::{self, File};
 use std::env::args;
+use grpcio::WriteFlags;
+use futures01::{stream, Future, Sink, Stream};
+
+/// size of the chunks a snapshot is sent in
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// print the statistics of a lsm-tree in one line
+fn print_stats(name: &str, stats: &LsmTreeStats) {
+    println!(
+        "{}: flushed {} bytes, {} compactions, write amplification {:.2}, pending compaction {} bytes, level sizes {:?}, bloom filter false positives {}",
+        name,
+        stats.get_flushed_bytes(),
+        stats.get_compactions(),
+        stats.get_write_amplification(),
+        stats.get_pending_compaction_bytes(),
+        stats.get_level_bytes(),
+        stats.get_bloom_false_positives()
+    );
+}
+
+/// save a snapshot of the server into `path`, return the size of the snapshot
+fn backup(client: &MiniKvServerClient, path: &str) -> Result<u64, String> {
+    let receiver = client.backup(&BackupRequest::default()).map_err(|e| e.to_string())?;
+    let mut file = File::create(path).map_err(|e| e.to_string())?;
+    let mut size = 0;
+    for chunk in receiver.wait() {
+        let chunk = chunk.map_err(|e| e.to_string())?;
+        file.write_all(chunk.get_data()).map_err(|e| e.to_string())?;
+        size += chunk.get_data().len() as u64;
+    }
+    file.sync_all().map_err(|e| e.to_string())?;
+    Ok(size)
+}
+
+/// replace all the data on the server with the snapshot in `path`, return the count of entries restored
+fn restore(client: &MiniKvServerClient, path: &str) -> Result<u64, String> {
+    let mut file = File::open(path).map_err(|e| e.to_string())?;
+    let mut chunks = vec![];
+    loop {
+        let mut data = Vec::with_capacity(CHUNK_SIZE);
+        let size = (&mut file).take(CHUNK_SIZE as u64).read_to_end(&mut data).map_err(|e| e.to_string())?;
+        if size == 0 {
+            break;
+        }
+        let mut chunk = SnapshotChunk::default();
+        chunk.set_data(data);
+        chunks.push((chunk, WriteFlags::default()));
+    }
+    let (sender, receiver) = client.restore().map_err(|e| e.to_string())?;
+    // keep the sender until the response arrives, or the call is cancelled
+    let (_sender, _) = sender.send_all(stream::iter_ok::<_, grpcio::Error>(chunks))
+        .wait()
+        .map_err(|e| e.to_string())?;
+    let response = receiver.wait().map_err(|e| e.to_string())?;
+    if response.get_success() {
+        Ok(response.get_count())
+    } else {
+        Err(response.get_errorMessage().to_string())
+    }
+}
+
+/// show the current value of a key after a condition failed
+fn print_condition_failed(exists: bool, current_value: &[u8], version: u64) {
+    if exists {
+        println!(
+            "condition failed, current value: {} (version {})",
+            from_utf8(current_value).unwrap_or("<non-printable>"),
+            version
+        );
+    } else {
+        println!("condition failed, the key does not exist");
+    }
+}
+
+/// show the lock which blocked a Percolator request
+fn print_locked(lock: &LockInfo) {
+    println!(
+        "key {} is locked by the transaction started at {} with primary key {}, expires in {} ms",
+        from_utf8(lock.get_key()).unwrap_or("<non-printable>"),
+        lock.get_start_ts(),
+        from_utf8(lock.get_primary()).unwrap_or("<non-printable>"),
+        lock.get_ttl_ms()
+    );
+}
 
 fn main() {
     let env = Arc::new(EnvBuilder::new().build());
@@ -27,45 +111,61 @@ fn main() {
             "" => {}
             "put" => {
                 let mut request = PutRequest::default();
-                if let Some(arg1) = command_and_arg_iter.next() {
-                    let key_str = arg1.as_bytes();
-                    if key_str.len() != 8 {
-                        println!("waring: key must be 8 bytes long, will padding/truncate to 8 bytes")
-                    }
-                    let key = get_bytes_with_fill(key_str, 8, 0x00);
-                    request.set_key(key);
-                    if let Some(arg2) = command_and_arg_iter.next() {
-                        let value_str = arg2.as_bytes();
-                        if value_str.len() != 256 {
-                            println!("waring: value must be 256 bytes long, will padding/truncate to 256 bytes");
-                        }
-                        let value = get_bytes_with_fill(value_str, 256, 0x00);
-                        request.set_value(value);
-                        let response = client.put(&request);
-                        if let Ok(resp) = response {
-                            if !resp.get_success() {
-                                eprintln!("error: {}", resp.get_errorMessage());
+                let mut args = vec![];
+                let mut valid = true;
+                while let Some(arg) = command_and_arg_iter.next() {
+                    match arg {
+                        "" => {}
+                        "--if-absent" => request.mut_condition().set_kind(Condition_Kind::ABSENT),
+                        "--if-equal" => match command_and_arg_iter.next() {
+                            Some(expected) => {
+                                request.mut_condition().set_kind(Condition_Kind::EQUAL);
+                                request.mut_condition().set_value(expected.as_bytes().to_vec());
                             }
-                        } else {
-                            eprintln!("{}", response.unwrap_err());
-                        }
-                    } else {
-                        eprintln!("error: Must provide a key and a value");
+                            None => valid = false,
+                        },
+                        "--if-version" => match command_and_arg_iter.next().map(u64::from_str) {
+                            Some(Ok(version)) => {
+                                request.mut_condition().set_kind(Condition_Kind::VERSION);
+                                request.mut_condition().set_version(version);
+                            }
+                            _ => valid = false,
+                        },
+                        "--ttl" => match command_and_arg_iter.next().map(u64::from_str) {
+                            Some(Ok(ttl_ms)) => request.set_ttl_ms(ttl_ms),
+                            _ => valid = false,
+                        },
+                        arg => args.push(arg),
+                    }
+                }
+                if !valid || args.len() != 2 {
+                    eprintln!("error: Usage: put <key> <value> [--if-absent | --if-equal <value> | --if-version <version>] [--ttl <milliseconds>]");
+                    continue;
+                }
+                request.set_key(args[0].as_bytes().to_vec());
+                request.set_value(args[1].as_bytes().to_vec());
+                let response = client.put(&request);
+                if let Ok(resp) = response {
+                    if resp.get_condition_failed() {
+                        print_condition_failed(resp.get_exists(), resp.get_current_value(), resp.get_version());
+                    } else if !resp.get_success() {
+                        eprintln!("error: {}", resp.get_errorMessage());
                     }
                 } else {
-                    eprintln!("error: Must provide a key and a value");
+                    eprintln!("{}", response.unwrap_err());
                 }
             }
             "get" => {
                 let mut request = GetRequest::default();
                 if let Some(arg) = command_and_arg_iter.next() {
-                    let key_str = arg.as_bytes();
-                    let key = get_bytes_with_fill(key_str, 8, 0x00);
-                    request.set_key(key);
+                    request.set_key(arg.as_bytes().to_vec());
+                    let with_version = command_and_arg_iter.next() == Some("--version");
                     let response = client.get(&request);
                     if let Ok(resp) = response {
                         if !resp.get_success() {
                             eprintln!("error: {}", resp.get_errorMessage());
+                        } else if with_version {
+                            println!("{} (version {})", from_utf8(resp.get_value()).unwrap_or("<non-printable>"), resp.get_version())
                         } else {
                             println!("{}", from_utf8(resp.get_value()).unwrap_or("<non-printable>"))
                         }
@@ -78,13 +178,343 @@ fn main() {
             }
             "delete" => {
                 let mut request = DeleteRequest::default();
-                let key_str = command_and_arg_iter.next().unwrap().as_bytes();
-                let key = get_bytes_with_fill(key_str, 8, 0x00);
-                request.set_key(key);
+                let mut args = vec![];
+                let mut valid = true;
+                while let Some(arg) = command_and_arg_iter.next() {
+                    match arg {
+                        "" => {}
+                        "--if-equal" => match command_and_arg_iter.next() {
+                            Some(expected) => {
+                                request.mut_condition().set_kind(Condition_Kind::EQUAL);
+                                request.mut_condition().set_value(expected.as_bytes().to_vec());
+                            }
+                            None => valid = false,
+                        },
+                        "--if-version" => match command_and_arg_iter.next().map(u64::from_str) {
+                            Some(Ok(version)) => {
+                                request.mut_condition().set_kind(Condition_Kind::VERSION);
+                                request.mut_condition().set_version(version);
+                            }
+                            _ => valid = false,
+                        },
+                        arg => args.push(arg),
+                    }
+                }
+                if !valid || args.len() != 1 {
+                    eprintln!("error: Usage: delete <key> [--if-equal <value> | --if-version <version>]");
+                    continue;
+                }
+                request.set_key(args[0].as_bytes().to_vec());
                 let response = client.delete(&request);
+                if let Ok(resp) = response {
+                    if resp.get_condition_failed() {
+                        print_condition_failed(resp.get_exists(), resp.get_current_value(), resp.get_version());
+                    } else if !resp.get_success() {
+                        eprintln!("error: {}", resp.get_errorMessage());
+                    }
+                } else {
+                    eprintln!("{}", response.unwrap_err());
+                }
+            }
+            "cas" => {
+                let args: Vec<_> = command_and_arg_iter.filter(|it| !it.is_empty()).collect();
+                if args.len() != 3 {
+                    eprintln!("error: Usage: cas <key> <expected value | --absent> <new value>");
+                    continue;
+                }
+                let mut request = CompareAndSwapRequest::default();
+                request.set_key(args[0].as_bytes().to_vec());
+                if args[1] == "--absent" {
+                    request.mut_expected().set_kind(Condition_Kind::ABSENT);
+                } else {
+                    request.mut_expected().set_kind(Condition_Kind::EQUAL);
+                    request.mut_expected().set_value(args[1].as_bytes().to_vec());
+                }
+                request.set_new_value(args[2].as_bytes().to_vec());
+                let response = client.compare_and_swap(&request);
+                if let Ok(resp) = response {
+                    if !resp.get_success() {
+                        eprintln!("error: {}", resp.get_errorMessage());
+                    } else if resp.get_swapped() {
+                        println!("swapped, version: {}", resp.get_version());
+                    } else {
+                        print_condition_failed(resp.get_exists(), resp.get_current_value(), resp.get_version());
+                    }
+                } else {
+                    eprintln!("{}", response.unwrap_err());
+                }
+            }
+            "txn" => {
+                let mut request = TxnRequest::default();
+                let mut args = command_and_arg_iter.filter(|it| !it.is_empty());
+                let mut valid = true;
+                while let Some(arg) = args.next() {
+                    match (arg, args.next()) {
+                        ("at", Some(start_ts)) => match u64::from_str(start_ts) {
+                            Ok(start_ts) => request.set_start_ts(start_ts),
+                            Err(_) => valid = false,
+                        },
+                        ("read", Some(key)) => request.mut_read_keys().push(key.as_bytes().to_vec()),
+                        ("put", Some(key)) | ("delete", Some(key)) => {
+                            let mut write = TxnWrite::default();
+                            write.set_key(key.as_bytes().to_vec());
+                            if arg == "put" {
+                                match args.next() {
+                                    Some(value) => write.set_value(value.as_bytes().to_vec()),
+                                    None => valid = false,
+                                }
+                            } else {
+                                write.set_kind(TxnWrite_Kind::DELETE);
+                            }
+                            request.mut_writes().push(write);
+                        }
+                        ("if-absent", Some(key)) | ("if-equal", Some(key)) | ("if-version", Some(key)) => {
+                            let mut condition = TxnCondition::default();
+                            condition.set_key(key.as_bytes().to_vec());
+                            match arg {
+                                "if-absent" => condition.mut_condition().set_kind(Condition_Kind::ABSENT),
+                                "if-equal" => match args.next() {
+                                    Some(expected) => {
+                                        condition.mut_condition().set_kind(Condition_Kind::EQUAL);
+                                        condition.mut_condition().set_value(expected.as_bytes().to_vec());
+                                    }
+                                    None => valid = false,
+                                },
+                                _ => match args.next().map(u64::from_str) {
+                                    Some(Ok(version)) => {
+                                        condition.mut_condition().set_kind(Condition_Kind::VERSION);
+                                        condition.mut_condition().set_version(version);
+                                    }
+                                    _ => valid = false,
+                                },
+                            }
+                            request.mut_conditions().push(condition);
+                        }
+                        _ => valid = false,
+                    }
+                }
+                if !valid || request.get_writes().is_empty() {
+                    eprintln!("error: Usage: txn [at <read timestamp>] [read <key>]... \
+                        [if-absent <key> | if-equal <key> <value> | if-version <key> <version>]... \
+                        (put <key> <value> | delete <key>)...");
+                    continue;
+                }
+                let response = client.txn(&request);
+                if let Ok(resp) = response {
+                    if resp.get_success() {
+                        println!("committed, version: {}", resp.get_version());
+                    } else if resp.get_conflict() && !resp.get_conflict_key().is_empty() {
+                        println!("conflict on key: {}", from_utf8(resp.get_conflict_key()).unwrap_or("<non-printable>"));
+                    } else if resp.get_condition_failed() {
+                        print!("condition {}: ", resp.get_failed_condition());
+                        print_condition_failed(resp.get_exists(), resp.get_current_value(), resp.get_version());
+                    } else {
+                        eprintln!("error: {}", resp.get_errorMessage());
+                    }
+                } else {
+                    eprintln!("{}", response.unwrap_err());
+                }
+            }
+            "tso" => {
+                let response = client.get_timestamp(&GetTimestampRequest::default());
+                if let Ok(resp) = response {
+                    if resp.get_success() {
+                        println!("{}", resp.get_timestamp());
+                    } else {
+                        eprintln!("error: {}", resp.get_errorMessage());
+                    }
+                } else {
+                    eprintln!("{}", response.unwrap_err());
+                }
+            }
+            "kget" => {
+                let args: Vec<_> = command_and_arg_iter.filter(|it| !it.is_empty()).collect();
+                let start_ts = args.get(1).map(|it| u64::from_str(it));
+                if args.len() != 2 || start_ts.as_ref().unwrap().is_err() {
+                    eprintln!("error: Usage: kget <key> <start timestamp>");
+                    continue;
+                }
+                let mut request = KvGetRequest::default();
+                request.set_key(args[0].as_bytes().to_vec());
+                request.set_start_ts(start_ts.unwrap().unwrap());
+                let response = client.kv_get(&request);
+                if let Ok(resp) = response {
+                    if resp.get_success() && resp.get_exists() {
+                        println!("{}", from_utf8(resp.get_value()).unwrap_or("<non-printable>"));
+                    } else if resp.get_success() {
+                        println!("key not found");
+                    } else if resp.has_locked() {
+                        print_locked(resp.get_locked());
+                    } else {
+                        eprintln!("error: {}", resp.get_errorMessage());
+                    }
+                } else {
+                    eprintln!("{}", response.unwrap_err());
+                }
+            }
+            "prewrite" => {
+                let mut request = KvPrewriteRequest::default();
+                let mut args = command_and_arg_iter.filter(|it| !it.is_empty());
+                let mut valid = match (args.next().map(u64::from_str), args.next()) {
+                    (Some(Ok(start_ts)), Some(primary)) => {
+                        request.set_start_ts(start_ts);
+                        request.set_primary(primary.as_bytes().to_vec());
+                        true
+                    }
+                    _ => false,
+                };
+                while let Some(arg) = args.next() {
+                    match (arg, args.next()) {
+                        ("ttl", Some(ttl_ms)) => match u64::from_str(ttl_ms) {
+                            Ok(ttl_ms) => request.set_lock_ttl_ms(ttl_ms),
+                            Err(_) => valid = false,
+                        },
+                        ("put", Some(key)) | ("delete", Some(key)) => {
+                            let mut mutation = TxnWrite::default();
+                            mutation.set_key(key.as_bytes().to_vec());
+                            if arg == "put" {
+                                match args.next() {
+                                    Some(value) => mutation.set_value(value.as_bytes().to_vec()),
+                                    None => valid = false,
+                                }
+                            } else {
+                                mutation.set_kind(TxnWrite_Kind::DELETE);
+                            }
+                            request.mut_mutations().push(mutation);
+                        }
+                        _ => valid = false,
+                    }
+                }
+                if !valid || request.get_mutations().is_empty() {
+                    eprintln!("error: Usage: prewrite <start timestamp> <primary key> [ttl <milliseconds>] (put <key> <value> | delete <key>)...");
+                    continue;
+                }
+                let response = client.kv_prewrite(&request);
+                if let Ok(resp) = response {
+                    if resp.get_success() {
+                        println!("prewritten");
+                    } else if resp.has_locked() {
+                        print_locked(resp.get_locked());
+                    } else if resp.get_conflict() {
+                        println!(
+                            "conflict on key: {}, committed at {}",
+                            from_utf8(resp.get_conflict_key()).unwrap_or("<non-printable>"),
+                            resp.get_conflict_ts()
+                        );
+                    } else {
+                        eprintln!("error: {}", resp.get_errorMessage());
+                    }
+                } else {
+                    eprintln!("{}", response.unwrap_err());
+                }
+            }
+            "commit" => {
+                let args: Vec<_> = command_and_arg_iter.filter(|it| !it.is_empty()).collect();
+                let timestamps: Vec<_> = args.iter().take(2).map(|it| u64::from_str(it)).collect();
+                if args.len() < 3 || timestamps.iter().any(|it| it.is_err()) {
+                    eprintln!("error: Usage: commit <start timestamp> <commit timestamp> <key>...");
+                    continue;
+                }
+                let mut request = KvCommitRequest::default();
+                request.set_start_ts(*timestamps[0].as_ref().unwrap());
+                request.set_commit_ts(*timestamps[1].as_ref().unwrap());
+                request.set_keys(args[2..].iter().map(|it| it.as_bytes().to_vec()).collect());
+                let response = client.kv_commit(&request);
+                if let Ok(resp) = response {
+                    if resp.get_success() {
+                        println!("committed");
+                    } else if resp.get_rolled_back() {
+                        println!("rolled back");
+                    } else {
+                        eprintln!("error: {}", resp.get_errorMessage());
+                    }
+                } else {
+                    eprintln!("{}", response.unwrap_err());
+                }
+            }
+            "rollback" => {
+                let args: Vec<_> = command_and_arg_iter.filter(|it| !it.is_empty()).collect();
+                let start_ts = args.first().map(|it| u64::from_str(it));
+                if args.len() < 2 || start_ts.as_ref().unwrap().is_err() {
+                    eprintln!("error: Usage: rollback <start timestamp> <key>...");
+                    continue;
+                }
+                let mut request = KvRollbackRequest::default();
+                request.set_start_ts(start_ts.unwrap().unwrap());
+                request.set_keys(args[1..].iter().map(|it| it.as_bytes().to_vec()).collect());
+                let response = client.kv_rollback(&request);
+                if let Ok(resp) = response {
+                    if resp.get_success() {
+                        println!("rolled back");
+                    } else if resp.get_committed() {
+                        println!("committed at {}", resp.get_commit_ts());
+                    } else {
+                        eprintln!("error: {}", resp.get_errorMessage());
+                    }
+                } else {
+                    eprintln!("{}", response.unwrap_err());
+                }
+            }
+            "status" => {
+                let args: Vec<_> = command_and_arg_iter.filter(|it| !it.is_empty()).collect();
+                let start_ts = args.get(1).map(|it| u64::from_str(it));
+                if args.len() != 2 || start_ts.as_ref().unwrap().is_err() {
+                    eprintln!("error: Usage: status <primary key> <start timestamp>");
+                    continue;
+                }
+                let mut request = KvCheckTxnStatusRequest::default();
+                request.set_primary_key(args[0].as_bytes().to_vec());
+                request.set_start_ts(start_ts.unwrap().unwrap());
+                let response = client.kv_check_txn_status(&request);
                 if let Ok(resp) = response {
                     if !resp.get_success() {
                         eprintln!("error: {}", resp.get_errorMessage());
+                    } else {
+                        match resp.get_status() {
+                            KvCheckTxnStatusResponse_Status::LOCKED => println!("locked, expires in {} ms", resp.get_ttl_ms()),
+                            KvCheckTxnStatusResponse_Status::COMMITTED => println!("committed at {}", resp.get_commit_ts()),
+                            KvCheckTxnStatusResponse_Status::ROLLED_BACK => println!("rolled back"),
+                        }
+                    }
+                } else {
+                    eprintln!("{}", response.unwrap_err());
+                }
+            }
+            "expire" => {
+                let args: Vec<_> = command_and_arg_iter.filter(|it| !it.is_empty()).collect();
+                let ttl_ms = args.get(1).map(|it| u64::from_str(it));
+                if args.len() != 2 || ttl_ms.as_ref().unwrap().is_err() {
+                    eprintln!("error: Usage: expire <key> <milliseconds, 0 for never>");
+                    continue;
+                }
+                let mut request = ExpireRequest::default();
+                request.set_key(args[0].as_bytes().to_vec());
+                request.set_ttl_ms(ttl_ms.unwrap().unwrap());
+                let response = client.expire(&request);
+                if let Ok(resp) = response {
+                    if !resp.get_success() {
+                        eprintln!("error: {}", resp.get_errorMessage());
+                    }
+                } else {
+                    eprintln!("{}", response.unwrap_err());
+                }
+            }
+            "ttl" => {
+                let args: Vec<_> = command_and_arg_iter.filter(|it| !it.is_empty()).collect();
+                if args.len() != 1 {
+                    eprintln!("error: Usage: ttl <key>");
+                    continue;
+                }
+                let mut request = TtlRequest::default();
+                request.set_key(args[0].as_bytes().to_vec());
+                let response = client.ttl(&request);
+                if let Ok(resp) = response {
+                    if !resp.get_success() {
+                        eprintln!("error: {}", resp.get_errorMessage());
+                    } else if resp.get_expires() {
+                        println!("{} ms", resp.get_ttl_ms());
+                    } else {
+                        println!("never expires");
                     }
                 } else {
                     eprintln!("{}", response.unwrap_err());
@@ -92,14 +522,39 @@ fn main() {
             }
             "scan" => {
                 let mut request = ScanRequest::default();
-                if let Some(key_str) = command_and_arg_iter.next() {
+                if let Some(key_str) = command_and_arg_iter.next().filter(|it| !it.is_empty()) {
                     if let Ok(key) = u64::from_str(key_str) {
                         request.set_cursor(key);
+                        let mut valid = true;
+                        while let Some(arg) = command_and_arg_iter.next() {
+                            match arg {
+                                "" => {}
+                                "--values" => request.set_with_values(true),
+                                "--count" => match command_and_arg_iter.next().map(u32::from_str) {
+                                    Some(Ok(count)) => request.set_count(count),
+                                    _ => valid = false,
+                                },
+                                _ => valid = false,
+                            }
+                        }
+                        if !valid {
+                            eprintln!("error: Usage: scan <cursor> [--count <count>] [--values]");
+                            continue;
+                        }
                         let response = client.scan(&request);
                         if let Ok(resp) = response {
+                            if !resp.get_success() {
+                                eprintln!("error: {}", resp.get_errorMessage());
+                                continue;
+                            }
                             println!("cursor: {}", resp.cursor);
                             for (i, result_key) in resp.result.iter().enumerate() {
-                                println!("({}): {}", key as usize + i, from_utf8(result_key).unwrap_or("<non-printable>"));
+                                let key = from_utf8(result_key).unwrap_or("<non-printable>");
+                                if let Some(value) = resp.values.get(i) {
+                                    println!("({}): {}: {}", i, key, from_utf8(value).unwrap_or("<non-printable>"));
+                                } else {
+                                    println!("({}): {}", i, key);
+                                }
                             }
                         } else {
                             eprintln!("{}", response.unwrap_err());
@@ -111,6 +566,201 @@ fn main() {
                     eprintln!("Scan needs a cursor! Use 0 if you want to scan from the start.");
                 }
             }
+            "range" => {
+                let mut request = RangeScanRequest::default();
+                // scan from the smallest key without an upper bound by default
+                let mut keys = vec![];
+                let mut valid = true;
+                while let Some(arg) = command_and_arg_iter.next() {
+                    match arg {
+                        "" => {}
+                        "--reverse" => request.set_reverse(true),
+                        "--limit" => match command_and_arg_iter.next().map(u32::from_str) {
+                            Some(Ok(limit)) => request.set_limit(limit),
+                            _ => valid = false,
+                        },
+                        "--at" => match command_and_arg_iter.next().map(u64::from_str) {
+                            Some(Ok(read_ts)) => request.set_read_ts(read_ts),
+                            _ => valid = false,
+                        },
+                        key => keys.push(key.as_bytes().to_vec()),
+                    }
+                }
+                if !valid || keys.len() > 2 {
+                    eprintln!("error: Usage: range [start] [end] [--limit <count>] [--reverse] [--at <read timestamp>]");
+                    continue;
+                }
+                let mut keys = keys.into_iter();
+                request.set_start_key(keys.next().unwrap_or_default());
+                request.set_end_key(keys.next().unwrap_or_default());
+                let response = client.range_scan(&request);
+                if let Ok(resp) = response {
+                    if !resp.get_success() {
+                        eprintln!("error: {}", resp.get_errorMessage());
+                    } else {
+                        for pair in resp.get_pairs() {
+                            println!(
+                                "{}: {}",
+                                from_utf8(pair.get_key()).unwrap_or("<non-printable>"),
+                                from_utf8(pair.get_value()).unwrap_or("<non-printable>")
+                            );
+                        }
+                        if !resp.get_next_key().is_empty() {
+                            println!("next: {}", from_utf8(resp.get_next_key()).unwrap_or("<non-printable>"));
+                        }
+                        println!("read at: {}", resp.get_read_ts());
+                    }
+                } else {
+                    eprintln!("{}", response.unwrap_err());
+                }
+            }
+            "prefix" => {
+                let mut request = PrefixScanRequest::default();
+                if let Some(prefix) = command_and_arg_iter.next().filter(|it| !it.is_empty()) {
+                    request.set_prefix(prefix.as_bytes().to_vec());
+                    request.set_start_key(command_and_arg_iter.next().unwrap_or("").as_bytes().to_vec());
+                    request.set_with_values(true);
+                    let response = client.prefix_scan(&request);
+                    if let Ok(resp) = response {
+                        if !resp.get_success() {
+                            eprintln!("error: {}", resp.get_errorMessage());
+                        } else {
+                            for pair in resp.get_pairs() {
+                                println!(
+                                    "{}: {}",
+                                    from_utf8(pair.get_key()).unwrap_or("<non-printable>"),
+                                    from_utf8(pair.get_value()).unwrap_or("<non-printable>")
+                                );
+                            }
+                            if !resp.get_next_key().is_empty() {
+                                println!("next: {}", from_utf8(resp.get_next_key()).unwrap_or("<non-printable>"));
+                            }
+                        }
+                    } else {
+                        eprintln!("{}", response.unwrap_err());
+                    }
+                } else {
+                    eprintln!("error: Prefix needs a prefix! Use range to scan all the keys.");
+                }
+            }
+            "mget" => {
+                let keys: Vec<_> = command_and_arg_iter.filter(|it| !it.is_empty()).map(|it| it.as_bytes().to_vec()).collect();
+                if keys.is_empty() {
+                    eprintln!("error: Must provide at least one key");
+                    continue;
+                }
+                let mut request = BatchGetRequest::default();
+                request.set_keys(keys.into());
+                let response = client.batch_get(&request);
+                if let Ok(resp) = response {
+                    if !resp.get_success() {
+                        eprintln!("error: {}", resp.get_errorMessage());
+                    } else {
+                        for (key, result) in request.get_keys().iter().zip(resp.get_results()) {
+                            let key = from_utf8(key).unwrap_or("<non-printable>");
+                            if result.get_success() {
+                                println!("{}: {}", key, from_utf8(result.get_value()).unwrap_or("<non-printable>"));
+                            } else {
+                                println!("{}: error: {}", key, result.get_errorMessage());
+                            }
+                        }
+                        println!("read at: {}", resp.get_read_ts());
+                    }
+                } else {
+                    eprintln!("{}", response.unwrap_err());
+                }
+            }
+            "mput" => {
+                let args: Vec<_> = command_and_arg_iter.filter(|it| !it.is_empty()).collect();
+                if args.is_empty() || args.len() % 2 != 0 {
+                    eprintln!("error: Must provide keys and values in pairs");
+                    continue;
+                }
+                let pairs: Vec<_> = args.chunks(2)
+                    .map(|it| {
+                        let mut pair = KvPair::default();
+                        pair.set_key(it[0].as_bytes().to_vec());
+                        pair.set_value(it[1].as_bytes().to_vec());
+                        pair
+                    })
+                    .collect();
+                let mut request = BatchPutRequest::default();
+                request.set_pairs(pairs.into());
+                let response = client.batch_put(&request);
+                if let Ok(resp) = response {
+                    if !resp.get_success() {
+                        eprintln!("error: {}", resp.get_errorMessage());
+                    } else if let Some(result) = resp.get_results().iter().find(|it| !it.get_success()) {
+                        eprintln!("error: {}", result.get_errorMessage());
+                    }
+                } else {
+                    eprintln!("{}", response.unwrap_err());
+                }
+            }
+            "mdelete" => {
+                let keys: Vec<_> = command_and_arg_iter.filter(|it| !it.is_empty()).map(|it| it.as_bytes().to_vec()).collect();
+                if keys.is_empty() {
+                    eprintln!("error: Must provide at least one key");
+                    continue;
+                }
+                let mut request = BatchDeleteRequest::default();
+                request.set_keys(keys.into());
+                let response = client.batch_delete(&request);
+                if let Ok(resp) = response {
+                    if !resp.get_success() {
+                        eprintln!("error: {}", resp.get_errorMessage());
+                    } else {
+                        for (key, result) in request.get_keys().iter().zip(resp.get_results()) {
+                            if !result.get_success() {
+                                eprintln!("{}: error: {}", from_utf8(key).unwrap_or("<non-printable>"), result.get_errorMessage());
+                            }
+                        }
+                    }
+                } else {
+                    eprintln!("{}", response.unwrap_err());
+                }
+            }
+            "backup" => {
+                if let Some(path) = command_and_arg_iter.next().filter(|it| !it.is_empty()) {
+                    match backup(&client, path) {
+                        Ok(size) => println!("saved a snapshot of {} bytes into {}", size, path),
+                        Err(err) => {
+                            // don't leave a broken snapshot there
+                            fs::remove_file(path).unwrap_or(());
+                            eprintln!("error: {}", err);
+                        }
+                    }
+                } else {
+                    eprintln!("error: Must provide a file to save the backup into");
+                }
+            }
+            "restore" => {
+                if let Some(path) = command_and_arg_iter.next().filter(|it| !it.is_empty()) {
+                    match restore(&client, path) {
+                        Ok(count) => println!("restored {} pairs from {}", count, path),
+                        Err(err) => eprintln!("error: {}", err),
+                    }
+                } else {
+                    eprintln!("error: Must provide a backup file to restore from");
+                }
+            }
+            "stats" => {
+                let response = client.stats(&StatsRequest::default());
+                if let Ok(resp) = response {
+                    if resp.get_success() {
+                        for (i, stats) in resp.get_partitions().iter().enumerate() {
+                            print_stats(&format!("partition {}", i), stats);
+                        }
+                        if resp.has_percolator() {
+                            print_stats("percolator", resp.get_percolator());
+                        }
+                    } else {
+                        eprintln!("error: {}", resp.get_errorMessage());
+                    }
+                } else {
+                    eprintln!("{}", response.unwrap_err());
+                }
+            }
             "exit" => break,
             &_ => println!("Invalid command")
         }
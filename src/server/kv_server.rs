@@ -1,66 +1,413 @@
-use crate::store::Store;
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use crate::store::{Store, Page, MAX_ENTRY_SIZE};
+use crate::store::condition::Condition;
+use crate::store::lsm::LsmStats;
+use crate::store::txn::{Txn, TxnError};
+use crate::store::percolator::{Percolator, PercolatorError, TxnStatus, Lock};
+use crate::store::versioned::{Versioned, VERSION_SIZE};
+use crate::store::oracle::TimestampOracle;
+use crate::store::write_batch::WriteOp;
+use crate::store::snapshot::{Snapshot, SnapshotDecoder};
 use futures_locks::{RwLock};
-use grpcio::{RpcContext, UnarySink};
-use rpc::minikv::{ScanRequest, DeleteResponse, GetRequest, ScanResponse, PutRequest, PutResponse, GetResponse, DeleteRequest};
-use mini_kv::shared::bytes::copy_bytes;
-use futures01::future::Future;
+use grpcio::{RpcContext, RpcStatus, RpcStatusCode, UnarySink, UnarySinkResult, ServerStreamingSink, RequestStream, ClientStreamingSink, WriteFlags};
+use rpc::minikv::{ScanRequest, RangeScanRequest, RangeScanResponse, PrefixScanRequest, PrefixScanResponse, KvPair, Condition_Kind, CompareAndSwapRequest, CompareAndSwapResponse, ExpireRequest, ExpireResponse, TtlRequest, TtlResponse, BatchGetRequest, BatchGetResponse, BatchPutRequest, BatchPutResponse, BatchDeleteRequest, BatchDeleteResponse, TxnRequest, TxnResponse, TxnWrite, TxnWrite_Kind, GetTimestampRequest, GetTimestampResponse, LockInfo, KvGetRequest, KvGetResponse, KvPrewriteRequest, KvPrewriteResponse, KvCommitRequest, KvCommitResponse, KvRollbackRequest, KvRollbackResponse, KvCheckTxnStatusRequest, KvCheckTxnStatusResponse, KvCheckTxnStatusResponse_Status, DeleteResponse, GetRequest, ScanResponse, PutRequest, PutResponse, GetResponse, DeleteRequest, BackupRequest, SnapshotChunk, RestoreResponse, StatsRequest, StatsResponse, LsmTreeStats};
+use futures01::future::{self, Future};
+use futures01::{Sink, Stream};
+use futures01::sink::Wait;
+use futures01::sync::{mpsc, oneshot};
+
+/// size of the chunks a snapshot is sent in
+const CHUNK_SIZE: usize = 64 * 1024;
+/// how many chunks can be waiting for sending
+const CHUNKS_IN_FLIGHT: usize = 4;
+/// how many keys a scan returns if the count is not given
+const DEFAULT_SCAN_COUNT: usize = 16;
+/// how many pairs a scan reads each time it locks the partitions of the store
+const SCAN_CHUNK_SIZE: usize = 64;
+/// how long the locks of a Percolator transaction are held if the client doesn't tell
+const DEFAULT_LOCK_TTL: Duration = Duration::from_secs(3);
+
+/// Cut the bytes written into chunks and send them
+struct ChunkWriter {
+    sender: Wait<mpsc::Sender<SnapshotChunk>>,
+    buffer: Vec<u8>,
+}
+
+impl ChunkWriter {
+    fn send_buffer(&mut self) -> io::Result<()> {
+        let mut chunk = SnapshotChunk::default();
+        chunk.set_data(std::mem::replace(&mut self.buffer, Vec::with_capacity(CHUNK_SIZE)));
+        self.sender.send(chunk)
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "the backup is cancelled"))
+    }
+}
+
+impl Write for ChunkWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let size = buf.len().min(CHUNK_SIZE - self.buffer.len());
+        self.buffer.extend_from_slice(&buf[..size]);
+        if self.buffer.len() == CHUNK_SIZE {
+            self.send_buffer()?;
+        }
+        Ok(size)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if !self.buffer.is_empty() {
+            self.send_buffer()?;
+        }
+        self.sender.flush()
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "the backup is cancelled"))
+    }
+}
+
+/// write `snapshot` into `sender` chunk by chunk, blocks until all the chunks are sent
+fn send_snapshot(snapshot: Snapshot, sender: mpsc::Sender<SnapshotChunk>) {
+    let writer = ChunkWriter {
+        sender: sender.wait(),
+        buffer: Vec::with_capacity(CHUNK_SIZE),
+    };
+    match snapshot.write_to(writer) {
+        Ok(count) => info!("backup finished with {} entries", count),
+        Err(err) => error!("backup failed: {:?}", err),
+    }
+}
+
+/// read a page of at most `limit` pairs from the snapshot at `read_ts` a chunk at a time,
+/// the partitions of the store are only locked while reading each chunk, so the writes don't wait for the whole page,
+/// and the snapshot keeps the chunks consistent
+/// `read` reads at most the given count of pairs at the given timestamp from a position,
+/// and returns them with the position to continue from, like `Store::range_scan`
+/// return the timestamp of the snapshot and the page, or why the snapshot can't be read
+fn read_page<F>(store: &Store, read_ts: u64, from: Option<Vec<u8>>, limit: usize, read: F) -> Result<(u64, Page), String>
+    where F: Fn(&Store, Option<&[u8]>, usize, u64) -> Page {
+    store.read_and_lease(read_ts, |store, ts| {
+        let mut pairs = vec![];
+        let mut from = from;
+        loop {
+            let count = SCAN_CHUNK_SIZE.min(limit - pairs.len());
+            let (chunk, next) = read(store, from.as_deref(), count, ts);
+            pairs.extend(chunk);
+            if next.is_none() || pairs.len() >= limit {
+                // the next page is read at the same snapshot
+                let lease = read_ts != 0 || next.is_some();
+                return ((pairs, next), lease);
+            }
+            from = next;
+        }
+    })
+}
+
+/// take the error of the store reading the snapshot out of the result of `Store::read_at`
+fn read_result<T>(read: Result<(u64, io::Result<T>), String>) -> io::Result<Result<(u64, T), String>> {
+    match read {
+        Ok((ts, result)) => result.map(|it| Ok((ts, it))),
+        Err(message) => Ok(Err(message)),
+    }
+}
+
+/// reply `response`, or fail the call if the store can't read or write the data,
+/// so the client doesn't take it for a missing key or a failed condition
+fn reply<T>(sink: UnarySink<T>, response: io::Result<T>) -> UnarySinkResult {
+    match response {
+        Ok(response) => sink.success(response),
+        Err(e) => {
+            error!("store failed: {:?}", e);
+            sink.fail(RpcStatus::new(RpcStatusCode::INTERNAL, Some(format!("store failed: {}", e))))
+        }
+    }
+}
+
+/// the condition of a request, `None` if it always holds
+fn to_condition(condition: &rpc::minikv::Condition) -> Option<Condition> {
+    match condition.kind {
+        Condition_Kind::NONE => None,
+        Condition_Kind::ABSENT => Some(Condition::Absent),
+        Condition_Kind::EQUAL => Some(Condition::Equal(condition.value.clone())),
+        Condition_Kind::VERSION => Some(Condition::Version(condition.version)),
+    }
+}
+
+/// the ttl in a request, `None` if the key never expires
+fn to_ttl(ttl_ms: u64) -> Option<Duration> {
+    Some(Duration::from_millis(ttl_ms)).filter(|_| ttl_ms != 0)
+}
+
+fn to_lock_info(key: Vec<u8>, lock: Lock) -> LockInfo {
+    let mut info = LockInfo::default();
+    info.set_ttl_ms(lock.ttl().as_millis() as u64);
+    info.set_key(key);
+    info.set_primary(lock.primary);
+    info.set_start_ts(lock.start_ts);
+    info
+}
+
+fn to_lsm_tree_stats(stats: LsmStats) -> LsmTreeStats {
+    let mut result = LsmTreeStats::default();
+    result.set_write_amplification(stats.write_amplification());
+    result.set_flushed_bytes(stats.flushed_bytes);
+    result.set_compaction_read_bytes(stats.compaction_read_bytes);
+    result.set_compaction_written_bytes(stats.compaction_written_bytes);
+    result.set_compactions(stats.compactions);
+    result.set_pending_compaction_bytes(stats.pending_compaction_bytes);
+    result.set_level_bytes(stats.level_bytes);
+    result.set_bloom_false_positives(stats.bloom_false_positives);
+    result
+}
+
+fn to_mutation(mut write: TxnWrite) -> WriteOp {
+    match write.kind {
+        TxnWrite_Kind::PUT => WriteOp::Put(write.take_key(), write.take_value()),
+        TxnWrite_Kind::DELETE => WriteOp::Delete(write.take_key()),
+    }
+}
+
+/// check the `encoded_size` of a write batch
+fn check_batch_size(size: usize) -> Result<(), String> {
+    if size > MAX_ENTRY_SIZE {
+        Err(format!("batch is too large ({} bytes, at most {})", size, MAX_ENTRY_SIZE))
+    } else {
+        Ok(())
+    }
+}
+
+/// Size limits of the requests
+#[derive(Clone, Copy)]
+pub struct Limits {
+    /// max length of keys in bytes
+    pub max_key_size: usize,
+    /// max length of values in bytes
+    pub max_value_size: usize,
+    /// max count of keys or pairs returned by a scan
+    pub max_page_size: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            max_key_size: 1024,
+            max_value_size: 1024 * 1024,
+            max_page_size: 1024,
+        }
+    }
+}
+
+impl Limits {
+    fn check_key(&self, key: &[u8]) -> Result<(), String> {
+        if key.len() > self.max_key_size {
+            Err(format!("key is too large ({} bytes, at most {})", key.len(), self.max_key_size))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn check_value(&self, value: &[u8]) -> Result<(), String> {
+        if value.len() > self.max_value_size {
+            Err(format!("value is too large ({} bytes, at most {})", value.len(), self.max_value_size))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// check each key of a batch and the size of the whole batch, the error tells which key is invalid
+    fn check_keys(&self, keys: &[Vec<u8>]) -> Result<(), String> {
+        let mut size = 0;
+        for (i, key) in keys.iter().enumerate() {
+            self.check_key(key).map_err(|message| format!("key {}: {}", i, message))?;
+            // the same as `WriteBatch::encoded_size` if all the keys are deleted
+            size += 9 + key.len();
+        }
+        check_batch_size(size)
+    }
+
+    /// check each pair of a batch and the size of the whole batch
+    fn check_pairs(&self, pairs: &[KvPair]) -> Result<(), String> {
+        let mut size = 0;
+        for (i, pair) in pairs.iter().enumerate() {
+            self.check_key(pair.get_key())
+                .and_then(|_| self.check_value(pair.get_value()))
+                .map_err(|message| format!("pair {}: {}", i, message))?;
+            // the same as `WriteBatch::encoded_size` after the versions are added
+            size += 9 + pair.get_key().len() + VERSION_SIZE + pair.get_value().len();
+        }
+        check_batch_size(size)
+    }
+
+    /// check the keys and the values of a transaction, and the size of its writes
+    fn check_txn(&self, req: &TxnRequest) -> Result<(), String> {
+        for (i, key) in req.get_read_keys().iter().enumerate() {
+            self.check_key(key).map_err(|message| format!("read key {}: {}", i, message))?;
+        }
+        for (i, condition) in req.get_conditions().iter().enumerate() {
+            self.check_key(condition.get_key())
+                .and_then(|_| self.check_value(&condition.get_condition().value))
+                .map_err(|message| format!("condition {}: {}", i, message))?;
+        }
+        let mut size = 0;
+        for (i, write) in req.get_writes().iter().enumerate() {
+            self.check_key(write.get_key())
+                .and_then(|_| self.check_value(write.get_value()))
+                .map_err(|message| format!("write {}: {}", i, message))?;
+            // the same as `WriteBatch::encoded_size` after the versions are added
+            size += 9 + write.get_key().len();
+            if write.kind == TxnWrite_Kind::PUT {
+                size += VERSION_SIZE + write.get_value().len();
+            }
+        }
+        check_batch_size(size)
+    }
+
+    /// check the keys and the values of a prewrite, and the size of the locks and the values it writes
+    fn check_prewrite(&self, req: &KvPrewriteRequest) -> Result<(), String> {
+        if req.start_ts == 0 {
+            return Err("start_ts is not given".to_string());
+        }
+        self.check_key(req.get_primary()).map_err(|message| format!("primary: {}", message))?;
+        let mut size = 0;
+        for (i, mutation) in req.get_mutations().iter().enumerate() {
+            self.check_key(mutation.get_key())
+                .and_then(|_| self.check_value(mutation.get_value()))
+                .map_err(|message| format!("mutation {}: {}", i, message))?;
+            // the lock, with the primary key in it
+            size += 9 + 1 + mutation.get_key().len() + VERSION_SIZE + 17 + req.get_primary().len();
+            if mutation.kind == TxnWrite_Kind::PUT {
+                size += 9 + 1 + mutation.get_key().len() + 8 + VERSION_SIZE + mutation.get_value().len();
+            }
+        }
+        check_batch_size(size)
+    }
+
+    /// check the keys to commit or roll back, and the size of the records written for them
+    fn check_txn_keys(&self, keys: &[Vec<u8>]) -> Result<(), String> {
+        let mut size = 0;
+        for (i, key) in keys.iter().enumerate() {
+            self.check_key(key).map_err(|message| format!("key {}: {}", i, message))?;
+            // more than the write record with the encoded key, the lock and the value deleted
+            size += 64 + 4 * key.len();
+        }
+        check_batch_size(size)
+    }
+
+    /// the page size to use for a requested `limit`, 0 for the max page size
+    fn page_size(&self, limit: usize) -> usize {
+        if limit == 0 {
+            self.max_page_size
+        } else {
+            limit.min(self.max_page_size)
+        }
+    }
+}
 
 #[derive(Clone)]
 pub struct KVServer {
-    // todo: lock lines instead of whole table
-    // note: the Mutex in futures_locks has a built-in Arc
-    store: RwLock<Store>,
+    /// the store locks its partitions by itself
+    store: Arc<Store>,
+    /// the locks and the writes of the Percolator transactions
+    percolator: RwLock<Percolator>,
+    /// the timestamp oracle of the Percolator transactions
+    oracle: Arc<Mutex<TimestampOracle>>,
+    limits: Limits,
 }
 
 impl rpc::minikv_grpc::MiniKvServer for KVServer {
     fn get(&mut self, ctx: RpcContext<'_>, req: GetRequest, sink: UnarySink<GetResponse>) {
         debug!("GET {:?}", req.key);
         let mut response = GetResponse::default();
-        let mut key = [0u8; 8];
-        if req.key.len() != 8 {
-            warn!("Get is called with a key {:?} which length is not 8, will padding/truncate it to 8 bytes", req.key)
-        }
-        copy_bytes(req.get_key(), &mut key);
-        let lock = self.store.clone();
-        let f = lock.read()
-            .map(move |guard| {
-                if let Some(value) = guard.get(&key) {
-                    response.set_success(true);
-                    response.set_value(value.to_vec());
-                } else {
-                    response.set_success(false);
-                    response.set_errorMessage("key not found".to_string())
+        if let Err(message) = self.limits.check_key(&req.key) {
+            response.set_success(false);
+            response.set_errorMessage(message);
+            let f = sink.success(response)
+                .map_err(|e| error!("failed to reply: {:?}", e));
+            return ctx.spawn(f);
+        }
+        let key = req.key.clone();
+        let read_ts = req.read_ts;
+        let read = if read_ts == 0 {
+            // a single key is read at the latest version without opening a snapshot,
+            // it sees all the writes until the published version at least
+            Ok((self.store.published(), self.store.get(&key)))
+        } else {
+            self.store.read_at(read_ts, |store, ts| store.get_at(&key, ts))
+        };
+        let f = future::ok::<_, ()>(read_result(read))
+            .map(move |read| read.map(|read| {
+                match read {
+                    Ok((ts, Some(versioned))) => {
+                        response.set_success(true);
+                        response.set_value(versioned.value);
+                        response.set_version(versioned.version);
+                        response.set_read_ts(ts);
+                    }
+                    Ok((ts, None)) => {
+                        response.set_success(false);
+                        response.set_errorMessage("key not found".to_string());
+                        response.set_read_ts(ts);
+                    }
+                    Err(message) => {
+                        response.set_success(false);
+                        response.set_errorMessage(message);
+                    }
                 }
                 response
-            }).then(move |response| {
-            let response = response.unwrap();
-            sink.success(response)
+            })).then(move |response| {
+            reply(sink, response.unwrap())
                 .map_err(move |e| error!("failed to reply {:?}: {:?}", req, e))
                 .map(|_| ())
         });
         ctx.spawn(f)
     }
 
-    fn put(&mut self, ctx: RpcContext<'_>, req: PutRequest, sink: UnarySink<PutResponse>) {
+    fn put(&mut self, ctx: RpcContext<'_>, mut req: PutRequest, sink: UnarySink<PutResponse>) {
         debug!("PUT {:?}", req.key);
         let mut response = PutResponse::default();
-        response.set_success(true);
-        let mut key = [0u8; 8];
-        let mut value = [0u8; 256];
-        if req.key.len() != 8 {
-            warn!("Put is called with a key {:?} which length is not 8, will padding/truncate it to 8 bytes", req.key)
-        }
-        copy_bytes(&req.key, &mut key);
-        if req.value.len() != 256 {
-            warn!("Put is called with a value {:?} which length is not 256, will padding/truncate it to 256 bytes", req.value)
-        }
-        copy_bytes(&req.value, &mut value);
-        let f = self.store.write()
-            .map(move |mut it| {
-                it.put(key, value);
-            })
-            .then(move |_| {
-                sink.success(response)
+        if let Err(message) = self.limits.check_key(&req.key)
+            .and_then(|_| self.limits.check_value(&req.value))
+            .and_then(|_| self.limits.check_value(&req.get_condition().value)) {
+            response.set_success(false);
+            response.set_errorMessage(message);
+            let f = sink.success(response)
+                .map_err(|e| error!("failed to reply: {:?}", e));
+            return ctx.spawn(f);
+        }
+        let key = req.key.clone();
+        let value = req.take_value();
+        let condition = to_condition(req.get_condition());
+        let ttl = to_ttl(req.ttl_ms);
+        let written = match condition {
+            Some(condition) => self.store.put_if(key, value, ttl, &condition),
+            None => self.store.put_with_ttl(key, value, ttl).map(|(version, durable)| (Ok(version), durable)),
+        };
+        let f = future::result(written)
+            // reply after the put is persisted
+            .and_then(|(checked, durable)| durable.then(move |result| Ok((checked, result.is_ok()))))
+            .then(move |result| {
+                let response = result.map(|result| {
+                    match result {
+                        (Ok(version), true) => {
+                            response.set_success(true);
+                            response.set_version(version);
+                        }
+                        (Ok(_), false) => {
+                            response.set_success(false);
+                            response.set_errorMessage("failed to persist the put".to_string());
+                        }
+                        (Err(current), _) => {
+                            response.set_success(false);
+                            response.set_errorMessage("condition failed".to_string());
+                            response.set_condition_failed(true);
+                            if let Some(current) = current {
+                                response.set_exists(true);
+                                response.set_current_value(current.value);
+                                response.set_version(current.version);
+                            }
+                        }
+                    }
+                    response
+                });
+                reply(sink, response)
                     .map_err(move |e| error!("failed to reply {:?}: {:?}", req, e))
                     .map(|_| ())
             });
@@ -70,54 +417,897 @@ impl rpc::minikv_grpc::MiniKvServer for KVServer {
     fn delete(&mut self, ctx: RpcContext<'_>, req: DeleteRequest, sink: UnarySink<DeleteResponse>) {
         debug!("DELETE {:?}", req.key);
         let mut response = DeleteResponse::default();
-        let mut key = [0u8; 8];
-        if req.key.len() != 8 {
-            warn!("Delete is called with a key {:?} which length is not 8, will padding/truncate it to 8 bytes", req.key)
-        }
-        copy_bytes(req.get_key(), &mut key);
-        let f = self.store.write()
-            .map(move |mut guard| {
-                guard.delete(key)
-            })
-            .then(|removed| {
-                if removed.is_ok() && removed.unwrap().is_some() {
-                    response.set_success(true);
-                } else {
-                    response.set_success(false);
-                    response.set_errorMessage("key not found".to_string());
-                }
-                sink.success(response)
+        if let Err(message) = self.limits.check_key(&req.key)
+            .and_then(|_| self.limits.check_value(&req.get_condition().value)) {
+            response.set_success(false);
+            response.set_errorMessage(message);
+            let f = sink.success(response)
+                .map_err(|e| error!("failed to reply: {:?}", e));
+            return ctx.spawn(f);
+        }
+        let key = req.key.clone();
+        let condition = to_condition(req.get_condition());
+        let deleted = match condition {
+            Some(condition) => self.store.delete_if(&key, &condition),
+            None => self.store.delete(&key).map(|(removed, durable)| (Ok(removed), durable)),
+        };
+        let f = future::result(deleted)
+            // reply after the delete is persisted
+            .and_then(|(checked, durable)| durable.then(move |result| Ok((checked, result.is_ok()))))
+            .then(|result| {
+                let response = result.map(|result| {
+                    match result {
+                        (Ok(Some(removed)), true) => {
+                            response.set_success(true);
+                            response.set_version(removed.version);
+                        }
+                        (Ok(Some(_)), false) => {
+                            response.set_success(false);
+                            response.set_errorMessage("failed to persist the delete".to_string());
+                        }
+                        (Ok(None), _) => {
+                            response.set_success(false);
+                            response.set_errorMessage("key not found".to_string());
+                        }
+                        (Err(current), _) => {
+                            response.set_success(false);
+                            response.set_errorMessage("condition failed".to_string());
+                            response.set_condition_failed(true);
+                            if let Some(current) = current {
+                                response.set_exists(true);
+                                response.set_current_value(current.value);
+                                response.set_version(current.version);
+                            }
+                        }
+                    }
+                    response
+                });
+                reply(sink, response)
                     .map_err(move |e| error!("failed to reply {:?}: {:?}", req, e))
                     .map(|_| ())
             });
         ctx.spawn(f)
     }
 
+    fn compare_and_swap(&mut self, ctx: RpcContext<'_>, mut req: CompareAndSwapRequest, sink: UnarySink<CompareAndSwapResponse>) {
+        debug!("COMPARE AND SWAP {:?}", req.key);
+        let mut response = CompareAndSwapResponse::default();
+        if let Err(message) = self.limits.check_key(&req.key)
+            .and_then(|_| self.limits.check_value(&req.get_expected().value))
+            .and_then(|_| self.limits.check_value(&req.new_value)) {
+            response.set_success(false);
+            response.set_errorMessage(message);
+            let f = sink.success(response)
+                .map_err(|e| error!("failed to reply: {:?}", e));
+            return ctx.spawn(f);
+        }
+        let key = req.take_key();
+        let new_value = req.take_new_value();
+        let condition = to_condition(req.get_expected());
+        let written = match condition {
+            Some(condition) => self.store.put_if(key, new_value.clone(), None, &condition),
+            None => self.store.put(key, new_value.clone()).map(|(version, durable)| (Ok(version), durable)),
+        };
+        let swapped = written.map(|(checked, durable)| {
+            (checked.map(|version| Versioned { version, expire_at: None, value: new_value }), durable)
+        });
+        let f = future::result(swapped)
+            // reply after the swap is persisted
+            .and_then(|(checked, durable)| durable.then(move |result| Ok((checked, result.is_ok()))))
+            .then(move |result| {
+                let response = result.map(|result| {
+                    match result {
+                        (Ok(current), persisted) => {
+                            response.set_success(persisted);
+                            if !persisted {
+                                response.set_errorMessage("failed to persist the swap".to_string());
+                            }
+                            response.set_swapped(true);
+                            response.set_exists(true);
+                            response.set_current_value(current.value);
+                            response.set_version(current.version);
+                        }
+                        (Err(current), _) => {
+                            response.set_success(true);
+                            if let Some(current) = current {
+                                response.set_exists(true);
+                                response.set_current_value(current.value);
+                                response.set_version(current.version);
+                            }
+                        }
+                    }
+                    response
+                });
+                reply(sink, response)
+                    .map_err(|e| error!("failed to reply: {:?}", e))
+                    .map(|_| ())
+            });
+        ctx.spawn(f)
+    }
+
     fn scan(&mut self, ctx: RpcContext<'_>, req: ScanRequest, sink: UnarySink<ScanResponse>) {
-        debug!("SCAN at index: {:?}", req.cursor);
+        debug!("SCAN at cursor: {:?}", req.cursor);
         let mut response = ScanResponse::default();
-        let f = self.store.read()
-            .map(move |guard| {
-                guard.scan(req.cursor as _, 16)
-            }).then(|result| {
-            let result = result.unwrap();
-            response.set_cursor(result.0 as _);
-            let keys: Vec<_> = result.1.iter()
-                .map(|it| it.to_vec())
-                .collect();
-            response.set_result(keys.into());
+        let count = if req.count == 0 {
+            DEFAULT_SCAN_COUNT.min(self.limits.max_page_size)
+        } else {
+            self.limits.page_size(req.count as usize)
+        };
+        let with_values = req.with_values;
+        let read_ts = req.read_ts;
+        let store = &self.store;
+        let page = store.cursor(req.cursor)
+            .ok_or_else(|| "invalid or expired cursor".to_string())
+            .and_then(|start| read_page(store, read_ts, Some(start), count, |store, from, count, ts| {
+                store.range_scan(from.unwrap_or_default(), None, count, false, ts)
+            }))
+            // remember where the next page starts
+            .map(|(ts, (pairs, next_key))| (ts, pairs, next_key.map(|key| store.new_cursor(key)).unwrap_or(0)));
+        let f = future::ok::<_, ()>(page)
+            .then(move |result| {
+                match result.unwrap() {
+                    Ok((ts, pairs, cursor)) => {
+                        response.set_success(true);
+                        response.set_cursor(cursor);
+                        response.set_read_ts(ts);
+                        let (keys, values): (Vec<_>, Vec<_>) = pairs.into_iter().unzip();
+                        response.set_result(keys.into());
+                        if with_values {
+                            response.set_values(values.into());
+                        }
+                    }
+                    Err(message) => {
+                        response.set_success(false);
+                        response.set_errorMessage(message);
+                    }
+                }
+                sink.success(response)
+                    .map_err(move |e| error!("failed to reply: {:?}", e))
+                    .map(|_| ())
+            });
+        ctx.spawn(f)
+    }
+
+    fn range_scan(&mut self, ctx: RpcContext<'_>, req: RangeScanRequest, sink: UnarySink<RangeScanResponse>) {
+        debug!("RANGE SCAN from {:?} to {:?}, reverse: {}", req.start_key, req.end_key, req.reverse);
+        let mut response = RangeScanResponse::default();
+        if let Err(message) = self.limits.check_key(&req.start_key)
+            .and_then(|_| self.limits.check_key(&req.end_key)) {
+            response.set_success(false);
+            response.set_errorMessage(message);
+            let f = sink.success(response)
+                .map_err(|e| error!("failed to reply: {:?}", e));
+            return ctx.spawn(f);
+        }
+        let limit = self.limits.page_size(req.limit as usize);
+        let start = req.get_start_key().to_vec();
+        let end = Some(req.get_end_key().to_vec()).filter(|it| !it.is_empty());
+        let page = if req.reverse {
+            // a reverse scan continues from its end
+            read_page(&self.store, req.read_ts, end, limit, |store, end, count, ts| {
+                store.range_scan(&start, end, count, true, ts)
+            })
+        } else {
+            read_page(&self.store, req.read_ts, Some(start), limit, |store, start, count, ts| {
+                store.range_scan(start.unwrap_or_default(), end.as_deref(), count, false, ts)
+            })
+        };
+        let f = future::ok::<_, ()>(page).then(|result| {
+            match result.unwrap() {
+                Ok((ts, (pairs, next_key))) => {
+                    response.set_success(true);
+                    response.set_read_ts(ts);
+                    let pairs: Vec<_> = pairs.into_iter()
+                        .map(|(key, value)| {
+                            let mut pair = KvPair::default();
+                            pair.set_key(key);
+                            pair.set_value(value);
+                            pair
+                        })
+                        .collect();
+                    response.set_pairs(pairs.into());
+                    if let Some(next_key) = next_key {
+                        response.set_next_key(next_key);
+                    }
+                }
+                Err(message) => {
+                    response.set_success(false);
+                    response.set_errorMessage(message);
+                }
+            }
             sink.success(response)
                 .map_err(move |e| error!("failed to reply: {:?}", e))
                 .map(|_| ())
         });
         ctx.spawn(f)
     }
+
+    fn prefix_scan(&mut self, ctx: RpcContext<'_>, req: PrefixScanRequest, sink: UnarySink<PrefixScanResponse>) {
+        debug!("PREFIX SCAN {:?} from {:?}", req.prefix, req.start_key);
+        let mut response = PrefixScanResponse::default();
+        if let Err(message) = self.limits.check_key(&req.prefix)
+            .and_then(|_| self.limits.check_key(&req.start_key)) {
+            response.set_success(false);
+            response.set_errorMessage(message);
+            let f = sink.success(response)
+                .map_err(|e| error!("failed to reply: {:?}", e));
+            return ctx.spawn(f);
+        }
+        let limit = self.limits.page_size(req.limit as usize);
+        let with_values = req.with_values;
+        let prefix = req.get_prefix().to_vec();
+        let start = req.get_start_key().to_vec();
+        let page = read_page(&self.store, req.read_ts, Some(start), limit, |store, start, count, ts| {
+            store.prefix_scan(&prefix, start.unwrap_or_default(), count, ts)
+        });
+        let f = future::ok::<_, ()>(page).then(move |result| {
+            match result.unwrap() {
+                Ok((ts, (pairs, next_key))) => {
+                    response.set_success(true);
+                    response.set_read_ts(ts);
+                    let pairs: Vec<_> = pairs.into_iter()
+                        .map(|(key, value)| {
+                            let mut pair = KvPair::default();
+                            pair.set_key(key);
+                            if with_values {
+                                pair.set_value(value);
+                            }
+                            pair
+                        })
+                        .collect();
+                    response.set_pairs(pairs.into());
+                    if let Some(next_key) = next_key {
+                        response.set_next_key(next_key);
+                    }
+                }
+                Err(message) => {
+                    response.set_success(false);
+                    response.set_errorMessage(message);
+                }
+            }
+            sink.success(response)
+                .map_err(move |e| error!("failed to reply: {:?}", e))
+                .map(|_| ())
+        });
+        ctx.spawn(f)
+    }
+
+    fn batch_get(&mut self, ctx: RpcContext<'_>, req: BatchGetRequest, sink: UnarySink<BatchGetResponse>) {
+        debug!("BATCH GET {} keys", req.keys.len());
+        let mut response = BatchGetResponse::default();
+        if let Err(message) = self.limits.check_keys(&req.keys) {
+            response.set_success(false);
+            response.set_errorMessage(message);
+            let f = sink.success(response)
+                .map_err(|e| error!("failed to reply: {:?}", e));
+            return ctx.spawn(f);
+        }
+        let f = future::ok::<_, ()>(read_result(self.store.read_at(req.read_ts, |store, ts| store.batch_get(&req.keys, ts))))
+            .then(move |values| {
+                let response = values.unwrap().map(|values| {
+                    match values {
+                        Ok((ts, values)) => {
+                            let results: Vec<_> = values.into_iter()
+                                .map(|value| {
+                                    let mut result = GetResponse::default();
+                                    if let Some(versioned) = value {
+                                        result.set_success(true);
+                                        result.set_value(versioned.value);
+                                        result.set_version(versioned.version);
+                                    } else {
+                                        result.set_success(false);
+                                        result.set_errorMessage("key not found".to_string());
+                                    }
+                                    result
+                                })
+                                .collect();
+                            response.set_success(true);
+                            response.set_results(results.into());
+                            response.set_read_ts(ts);
+                        }
+                        Err(message) => {
+                            response.set_success(false);
+                            response.set_errorMessage(message);
+                        }
+                    }
+                    response
+                });
+                reply(sink, response)
+                    .map_err(move |e| error!("failed to reply: {:?}", e))
+                    .map(|_| ())
+            });
+        ctx.spawn(f)
+    }
+
+    fn batch_put(&mut self, ctx: RpcContext<'_>, mut req: BatchPutRequest, sink: UnarySink<BatchPutResponse>) {
+        debug!("BATCH PUT {} pairs", req.pairs.len());
+        let mut response = BatchPutResponse::default();
+        if let Err(message) = self.limits.check_pairs(&req.pairs) {
+            response.set_success(false);
+            response.set_errorMessage(message);
+            let f = sink.success(response)
+                .map_err(|e| error!("failed to reply: {:?}", e));
+            return ctx.spawn(f);
+        }
+        let pairs: Vec<_> = req.take_pairs().into_iter()
+            .map(|mut pair| (pair.take_key(), pair.take_value()))
+            .collect();
+        let count = pairs.len();
+        let f = future::result(self.store.batch_put(pairs))
+            // reply after the whole batch is persisted
+            .and_then(|(version, durable)| durable.then(move |result| Ok((version, result.is_ok()))))
+            .then(move |result| {
+                let response = result.map(|(version, persisted)| {
+                    let mut put = PutResponse::default();
+                    if persisted {
+                        put.set_success(true);
+                        put.set_version(version);
+                    } else {
+                        put.set_success(false);
+                        put.set_errorMessage("failed to persist the put".to_string());
+                    }
+                    response.set_success(true);
+                    response.set_results(vec![put; count].into());
+                    response
+                });
+                reply(sink, response)
+                    .map_err(move |e| error!("failed to reply: {:?}", e))
+                    .map(|_| ())
+            });
+        ctx.spawn(f)
+    }
+
+    fn batch_delete(&mut self, ctx: RpcContext<'_>, req: BatchDeleteRequest, sink: UnarySink<BatchDeleteResponse>) {
+        debug!("BATCH DELETE {} keys", req.keys.len());
+        let mut response = BatchDeleteResponse::default();
+        if let Err(message) = self.limits.check_keys(&req.keys) {
+            response.set_success(false);
+            response.set_errorMessage(message);
+            let f = sink.success(response)
+                .map_err(|e| error!("failed to reply: {:?}", e));
+            return ctx.spawn(f);
+        }
+        let f = future::result(self.store.batch_delete(&req.keys))
+            // reply after all the deletes are persisted
+            .and_then(|(removed, durable)| durable.then(move |result| Ok((removed, result.is_ok()))))
+            .then(move |result| {
+                let response = result.map(|(removed, persisted)| {
+                    let results: Vec<_> = removed.into_iter()
+                        .map(|removed| {
+                            let mut result = DeleteResponse::default();
+                            match removed {
+                                Some(removed) if persisted => {
+                                    result.set_success(true);
+                                    result.set_version(removed.version);
+                                }
+                                Some(_) => {
+                                    result.set_success(false);
+                                    result.set_errorMessage("failed to persist the delete".to_string());
+                                }
+                                None => {
+                                    result.set_success(false);
+                                    result.set_errorMessage("key not found".to_string());
+                                }
+                            }
+                            result
+                        })
+                        .collect();
+                    response.set_success(true);
+                    response.set_results(results.into());
+                    response
+                });
+                reply(sink, response)
+                    .map_err(move |e| error!("failed to reply: {:?}", e))
+                    .map(|_| ())
+            });
+        ctx.spawn(f)
+    }
+
+    fn expire(&mut self, ctx: RpcContext<'_>, req: ExpireRequest, sink: UnarySink<ExpireResponse>) {
+        debug!("EXPIRE {:?} after {}ms", req.key, req.ttl_ms);
+        let mut response = ExpireResponse::default();
+        if let Err(message) = self.limits.check_key(&req.key) {
+            response.set_success(false);
+            response.set_errorMessage(message);
+            let f = sink.success(response)
+                .map_err(|e| error!("failed to reply: {:?}", e));
+            return ctx.spawn(f);
+        }
+        let ttl = to_ttl(req.ttl_ms);
+        let f = future::result(self.store.expire(&req.key, ttl))
+            // reply after the new deadline is persisted
+            .and_then(|(found, durable)| durable.then(move |result| Ok((found, result.is_ok()))))
+            .then(move |result| {
+                let response = result.map(|result| {
+                    match result {
+                        (true, true) => response.set_success(true),
+                        (false, _) => {
+                            response.set_success(false);
+                            response.set_errorMessage("key not found".to_string());
+                        }
+                        (true, false) => {
+                            response.set_success(false);
+                            response.set_errorMessage("failed to persist the expiry".to_string());
+                        }
+                    }
+                    response
+                });
+                reply(sink, response)
+                    .map_err(|e| error!("failed to reply: {:?}", e))
+                    .map(|_| ())
+            });
+        ctx.spawn(f)
+    }
+
+    fn ttl(&mut self, ctx: RpcContext<'_>, req: TtlRequest, sink: UnarySink<TtlResponse>) {
+        debug!("TTL {:?}", req.key);
+        let mut response = TtlResponse::default();
+        if let Err(message) = self.limits.check_key(&req.key) {
+            response.set_success(false);
+            response.set_errorMessage(message);
+            let f = sink.success(response)
+                .map_err(|e| error!("failed to reply: {:?}", e));
+            return ctx.spawn(f);
+        }
+        let f = future::ok::<_, ()>(self.store.ttl(&req.key))
+            .then(move |result| {
+                let response = result.unwrap().map(|result| {
+                    match result {
+                        Some(ttl) => {
+                            response.set_success(true);
+                            if let Some(ttl) = ttl {
+                                response.set_expires(true);
+                                response.set_ttl_ms(ttl.as_millis() as u64);
+                            }
+                        }
+                        None => {
+                            response.set_success(false);
+                            response.set_errorMessage("key not found".to_string());
+                        }
+                    }
+                    response
+                });
+                reply(sink, response)
+                    .map_err(|e| error!("failed to reply: {:?}", e))
+                    .map(|_| ())
+            });
+        ctx.spawn(f)
+    }
+
+    fn txn(&mut self, ctx: RpcContext<'_>, mut req: TxnRequest, sink: UnarySink<TxnResponse>) {
+        debug!("TXN at {} with {} writes", req.start_ts, req.writes.len());
+        let mut response = TxnResponse::default();
+        if let Err(message) = self.limits.check_txn(&req) {
+            response.set_success(false);
+            response.set_errorMessage(message);
+            let f = sink.success(response)
+                .map_err(|e| error!("failed to reply: {:?}", e));
+            return ctx.spawn(f);
+        }
+        let mut txn = Txn::new(req.start_ts);
+        for key in req.take_read_keys().into_iter() {
+            txn.read(key);
+        }
+        for mut condition in req.take_conditions().into_iter() {
+            if let Some(checked) = to_condition(condition.get_condition()) {
+                txn.check(condition.take_key(), checked);
+            }
+        }
+        for mut write in req.take_writes().into_iter() {
+            match write.kind {
+                TxnWrite_Kind::PUT => txn.put(write.take_key(), write.take_value()),
+                TxnWrite_Kind::DELETE => txn.delete(write.take_key()),
+            };
+        }
+        let f = future::result(self.store.commit(txn))
+            // reply after the writes are persisted
+            .and_then(|(result, durable)| durable.then(move |persisted| Ok((result, persisted.is_ok()))))
+            .then(move |result| {
+                let response = result.map(|result| {
+                    match result {
+                        (Ok(version), true) => {
+                            response.set_success(true);
+                            response.set_version(version);
+                        }
+                        (Ok(_), false) => {
+                            response.set_success(false);
+                            response.set_errorMessage("failed to persist the transaction".to_string());
+                        }
+                        (Err(TxnError::Snapshot(message)), _) => {
+                            response.set_success(false);
+                            response.set_errorMessage(message);
+                            response.set_conflict(true);
+                        }
+                        (Err(TxnError::Conflict(key)), _) => {
+                            response.set_success(false);
+                            response.set_errorMessage("write conflict".to_string());
+                            response.set_conflict(true);
+                            response.set_conflict_key(key);
+                        }
+                        (Err(TxnError::ConditionFailed(i, current)), _) => {
+                            response.set_success(false);
+                            response.set_errorMessage(format!("condition {} failed", i));
+                            response.set_condition_failed(true);
+                            response.set_failed_condition(i as u32);
+                            if let Some(current) = current {
+                                response.set_exists(true);
+                                response.set_current_value(current.value);
+                                response.set_version(current.version);
+                            }
+                        }
+                    }
+                    response
+                });
+                reply(sink, response)
+                    .map_err(|e| error!("failed to reply: {:?}", e))
+                    .map(|_| ())
+            });
+        ctx.spawn(f)
+    }
+
+    fn get_timestamp(&mut self, ctx: RpcContext<'_>, _req: GetTimestampRequest, sink: UnarySink<GetTimestampResponse>) {
+        debug!("GET TIMESTAMP");
+        let mut response = GetTimestampResponse::default();
+        match self.oracle.lock().unwrap().next() {
+            Ok(timestamp) => {
+                response.set_success(true);
+                response.set_timestamp(timestamp);
+            }
+            Err(e) => {
+                error!("failed to save the timestamp limit: {:?}", e);
+                response.set_success(false);
+                response.set_errorMessage("failed to persist the timestamp limit".to_string());
+            }
+        }
+        let f = sink.success(response)
+            .map_err(|e| error!("failed to reply: {:?}", e));
+        ctx.spawn(f)
+    }
+
+    fn kv_get(&mut self, ctx: RpcContext<'_>, req: KvGetRequest, sink: UnarySink<KvGetResponse>) {
+        debug!("KV GET {:?} at {}", req.key, req.start_ts);
+        let mut response = KvGetResponse::default();
+        if let Err(message) = self.limits.check_key(&req.key) {
+            response.set_success(false);
+            response.set_errorMessage(message);
+            let f = sink.success(response)
+                .map_err(|e| error!("failed to reply: {:?}", e));
+            return ctx.spawn(f);
+        }
+        let f = self.percolator.read()
+            .map(move |guard| guard.get(&req.key, req.start_ts))
+            .then(move |result| {
+                let response = result.unwrap().map(|result| {
+                    match result {
+                        Ok(value) => {
+                            response.set_success(true);
+                            if let Some(value) = value {
+                                response.set_exists(true);
+                                response.set_value(value);
+                            }
+                        }
+                        Err(e) => {
+                            response.set_success(false);
+                            response.set_errorMessage(e.to_string());
+                            if let PercolatorError::Locked(key, lock) = e {
+                                response.set_locked(to_lock_info(key, lock));
+                            }
+                        }
+                    }
+                    response
+                });
+                reply(sink, response)
+                    .map_err(|e| error!("failed to reply: {:?}", e))
+                    .map(|_| ())
+            });
+        ctx.spawn(f)
+    }
+
+    fn kv_prewrite(&mut self, ctx: RpcContext<'_>, mut req: KvPrewriteRequest, sink: UnarySink<KvPrewriteResponse>) {
+        debug!("KV PREWRITE {} keys at {}", req.mutations.len(), req.start_ts);
+        let mut response = KvPrewriteResponse::default();
+        if let Err(message) = self.limits.check_prewrite(&req) {
+            response.set_success(false);
+            response.set_errorMessage(message);
+            let f = sink.success(response)
+                .map_err(|e| error!("failed to reply: {:?}", e));
+            return ctx.spawn(f);
+        }
+        let mutations: Vec<_> = req.take_mutations().into_iter().map(to_mutation).collect();
+        let ttl = to_ttl(req.lock_ttl_ms).unwrap_or(DEFAULT_LOCK_TTL);
+        let f = self.percolator.write()
+            .then(move |guard| guard.unwrap().prewrite(mutations, &req.primary, req.start_ts, ttl))
+            // reply after the locks are persisted
+            .and_then(|(result, durable)| durable.then(move |persisted| Ok((result, persisted.is_ok()))))
+            .then(move |result| {
+                let response = result.map(|result| {
+                    match result {
+                        (Ok(()), true) => response.set_success(true),
+                        (Ok(()), false) => {
+                            response.set_success(false);
+                            response.set_errorMessage("failed to persist the prewrite".to_string());
+                        }
+                        (Err(e), _) => {
+                            response.set_success(false);
+                            response.set_errorMessage(e.to_string());
+                            match e {
+                                PercolatorError::Locked(key, lock) => response.set_locked(to_lock_info(key, lock)),
+                                PercolatorError::WriteConflict(key, commit_ts) => {
+                                    response.set_conflict(true);
+                                    response.set_conflict_key(key);
+                                    response.set_conflict_ts(commit_ts);
+                                }
+                                _ => {}
+                            }
+                        }
+                    }
+                    response
+                });
+                reply(sink, response)
+                    .map_err(|e| error!("failed to reply: {:?}", e))
+                    .map(|_| ())
+            });
+        ctx.spawn(f)
+    }
+
+    fn kv_commit(&mut self, ctx: RpcContext<'_>, req: KvCommitRequest, sink: UnarySink<KvCommitResponse>) {
+        debug!("KV COMMIT {} keys at {}, started at {}", req.keys.len(), req.commit_ts, req.start_ts);
+        let mut response = KvCommitResponse::default();
+        let checked = if req.start_ts == 0 || req.commit_ts <= req.start_ts {
+            Err("commit_ts must be larger than start_ts".to_string())
+        } else {
+            self.limits.check_txn_keys(&req.keys)
+        };
+        if let Err(message) = checked {
+            response.set_success(false);
+            response.set_errorMessage(message);
+            let f = sink.success(response)
+                .map_err(|e| error!("failed to reply: {:?}", e));
+            return ctx.spawn(f);
+        }
+        let f = self.percolator.write()
+            .then(move |guard| guard.unwrap().commit(&req.keys, req.start_ts, req.commit_ts))
+            // reply after the commit is persisted
+            .and_then(|(result, durable)| durable.then(move |persisted| Ok((result, persisted.is_ok()))))
+            .then(move |result| {
+                let response = result.map(|result| {
+                    match result {
+                        (Ok(()), true) => response.set_success(true),
+                        (Ok(()), false) => {
+                            response.set_success(false);
+                            response.set_errorMessage("failed to persist the commit".to_string());
+                        }
+                        (Err(e), _) => {
+                            response.set_success(false);
+                            response.set_errorMessage(e.to_string());
+                            response.set_rolled_back(matches!(e, PercolatorError::LockNotFound(_)));
+                        }
+                    }
+                    response
+                });
+                reply(sink, response)
+                    .map_err(|e| error!("failed to reply: {:?}", e))
+                    .map(|_| ())
+            });
+        ctx.spawn(f)
+    }
+
+    fn kv_rollback(&mut self, ctx: RpcContext<'_>, req: KvRollbackRequest, sink: UnarySink<KvRollbackResponse>) {
+        debug!("KV ROLLBACK {} keys started at {}", req.keys.len(), req.start_ts);
+        let mut response = KvRollbackResponse::default();
+        let checked = if req.start_ts == 0 {
+            Err("start_ts is not given".to_string())
+        } else {
+            self.limits.check_txn_keys(&req.keys)
+        };
+        if let Err(message) = checked {
+            response.set_success(false);
+            response.set_errorMessage(message);
+            let f = sink.success(response)
+                .map_err(|e| error!("failed to reply: {:?}", e));
+            return ctx.spawn(f);
+        }
+        let f = self.percolator.write()
+            .then(move |guard| guard.unwrap().rollback(&req.keys, req.start_ts))
+            // reply after the rollback is persisted
+            .and_then(|(result, durable)| durable.then(move |persisted| Ok((result, persisted.is_ok()))))
+            .then(move |result| {
+                let response = result.map(|result| {
+                    match result {
+                        (Ok(()), true) => response.set_success(true),
+                        (Ok(()), false) => {
+                            response.set_success(false);
+                            response.set_errorMessage("failed to persist the rollback".to_string());
+                        }
+                        (Err(e), _) => {
+                            response.set_success(false);
+                            response.set_errorMessage(e.to_string());
+                            if let PercolatorError::Committed(_, commit_ts) = e {
+                                response.set_committed(true);
+                                response.set_commit_ts(commit_ts);
+                            }
+                        }
+                    }
+                    response
+                });
+                reply(sink, response)
+                    .map_err(|e| error!("failed to reply: {:?}", e))
+                    .map(|_| ())
+            });
+        ctx.spawn(f)
+    }
+
+    fn kv_check_txn_status(&mut self, ctx: RpcContext<'_>, req: KvCheckTxnStatusRequest, sink: UnarySink<KvCheckTxnStatusResponse>) {
+        debug!("KV CHECK TXN STATUS {:?} started at {}", req.primary_key, req.start_ts);
+        let mut response = KvCheckTxnStatusResponse::default();
+        let checked = if req.start_ts == 0 {
+            Err("start_ts is not given".to_string())
+        } else {
+            self.limits.check_key(&req.primary_key)
+        };
+        if let Err(message) = checked {
+            response.set_success(false);
+            response.set_errorMessage(message);
+            let f = sink.success(response)
+                .map_err(|e| error!("failed to reply: {:?}", e));
+            return ctx.spawn(f);
+        }
+        let f = self.percolator.write()
+            .then(move |guard| guard.unwrap().check_txn_status(&req.primary_key, req.start_ts))
+            // reply after the rollback is persisted, if the transaction is rolled back
+            .and_then(|(status, durable)| durable.then(move |persisted| Ok((status, persisted.is_ok()))))
+            .then(move |result| {
+                let response = result.map(|result| {
+                    match result {
+                        (_, false) => {
+                            response.set_success(false);
+                            response.set_errorMessage("failed to persist the rollback".to_string());
+                        }
+                        (TxnStatus::Locked(ttl), true) => {
+                            response.set_success(true);
+                            response.set_status(KvCheckTxnStatusResponse_Status::LOCKED);
+                            response.set_ttl_ms(ttl.as_millis() as u64);
+                        }
+                        (TxnStatus::Committed(commit_ts), true) => {
+                            response.set_success(true);
+                            response.set_status(KvCheckTxnStatusResponse_Status::COMMITTED);
+                            response.set_commit_ts(commit_ts);
+                        }
+                        (TxnStatus::RolledBack, true) => {
+                            response.set_success(true);
+                            response.set_status(KvCheckTxnStatusResponse_Status::ROLLED_BACK);
+                        }
+                    }
+                    response
+                });
+                reply(sink, response)
+                    .map_err(|e| error!("failed to reply: {:?}", e))
+                    .map(|_| ())
+            });
+        ctx.spawn(f)
+    }
+
+    fn backup(&mut self, ctx: RpcContext<'_>, _req: BackupRequest, sink: ServerStreamingSink<SnapshotChunk>) {
+        debug!("BACKUP");
+        // the store is only locked when taking the snapshot
+        let snapshot = self.store.snapshot();
+        let (sender, receiver) = mpsc::channel(CHUNKS_IN_FLIGHT);
+        let started = thread::Builder::new()
+            .name("backup".to_string())
+            .spawn(move || send_snapshot(snapshot, sender));
+        let f = match started {
+            Ok(_) => {
+                let chunks = receiver
+                    .map(|chunk| (chunk, WriteFlags::default()))
+                    .map_err(|_| grpcio::Error::RemoteStopped);
+                future::Either::A(sink.send_all(chunks).map(|_| ()))
+            }
+            // fail the call, or the client would take the empty stream for an empty backup
+            Err(e) => {
+                error!("failed to start backup: {:?}", e);
+                let status = RpcStatus::new(RpcStatusCode::INTERNAL, Some(format!("failed to start backup: {}", e)));
+                future::Either::B(sink.fail(status))
+            }
+        };
+        ctx.spawn(f.map_err(|e| error!("failed to send backup: {:?}", e)))
+    }
+
+    fn restore(&mut self, ctx: RpcContext<'_>, stream: RequestStream<SnapshotChunk>, sink: ClientStreamingSink<RestoreResponse>) {
+        debug!("RESTORE");
+        let store = self.store.clone();
+        let f = stream
+            // keep receiving after a decode error, so the client can get the response
+            .fold(Ok(SnapshotDecoder::new()), |decoder: io::Result<SnapshotDecoder>, chunk| {
+                let decoder = decoder.and_then(|mut it| it.feed(chunk.get_data()).map(|_| it));
+                Ok::<_, grpcio::Error>(decoder)
+            })
+            .map_err(|e| format!("failed to receive the snapshot: {}", e))
+            .and_then(|decoder| {
+                decoder.and_then(SnapshotDecoder::finish)
+                    .map_err(|e| format!("invalid snapshot: {}", e))
+            })
+            // the store is locked while it's replaced and a checkpoint is written, so it's done off the grpc thread
+            .and_then(move |entries| {
+                let (sender, receiver) = oneshot::channel();
+                let started = thread::Builder::new()
+                    .name("restore".to_string())
+                    .spawn(move || sender.send(store.restore(entries)).unwrap_or(()));
+                future::result(started.map_err(|e| format!("failed to start restore: {}", e)))
+                    .and_then(|_| receiver.map_err(|_| "restore stopped before it finished".to_string()))
+            })
+            .then(move |result| {
+                let mut response = RestoreResponse::default();
+                let replied = match result {
+                    Ok(Ok(count)) => {
+                        info!("restored {} entries", count);
+                        response.set_success(true);
+                        response.set_count(count);
+                        sink.success(response)
+                    }
+                    // the store failed in the middle of replacing the data, unlike the snapshot is invalid
+                    Ok(Err(e)) => {
+                        error!("store failed: {:?}", e);
+                        sink.fail(RpcStatus::new(RpcStatusCode::INTERNAL, Some(format!("store failed: {}", e))))
+                    }
+                    Err(message) => {
+                        warn!("restore failed: {}", message);
+                        response.set_success(false);
+                        response.set_errorMessage(message);
+                        sink.success(response)
+                    }
+                };
+                replied
+                    .map_err(|e| error!("failed to reply: {:?}", e))
+                    .map(|_| ())
+            });
+        ctx.spawn(f)
+    }
+
+    fn stats(&mut self, ctx: RpcContext<'_>, _req: StatsRequest, sink: UnarySink<StatsResponse>) {
+        debug!("STATS");
+        let mut response = StatsResponse::default();
+        response.set_success(true);
+        let partitions: Vec<_> = self.store.stats().into_iter().flatten().map(to_lsm_tree_stats).collect();
+        response.set_partitions(partitions.into());
+        let f = self.percolator.read()
+            .map(|guard| guard.stats())
+            .then(move |stats| {
+                if let Some(stats) = stats.unwrap() {
+                    response.set_percolator(to_lsm_tree_stats(stats));
+                }
+                sink.success(response)
+                    .map_err(|e| error!("failed to reply: {:?}", e))
+                    .map(|_| ())
+            });
+        ctx.spawn(f)
+    }
 }
 
 impl KVServer {
-    pub fn new(store: Store) -> Self {
+    pub fn new(store: Store, percolator: Percolator, oracle: TimestampOracle, limits: Limits) -> Self {
         Self {
-            store: RwLock::new(store)
+            store: Arc::new(store),
+            percolator: RwLock::new(percolator),
+            oracle: Arc::new(Mutex::new(oracle)),
+            limits,
+        }
+    }
+
+    /// start a thread which walks `count` keys every `interval`, and deletes the expired ones
+    pub fn start_sweeper(&self, interval: Duration, count: usize) {
+        let store = self.store.clone();
+        let result = thread::Builder::new()
+            .name("sweeper".to_string())
+            .spawn(move || loop {
+                thread::sleep(interval);
+                match store.sweep(count) {
+                    Ok(0) => {}
+                    Ok(deleted) => debug!("swept {} expired keys", deleted),
+                    Err(e) => error!("sweep failed: {:?}", e),
+                }
+            });
+        if let Err(e) = result {
+            error!("failed to start the sweeper: {:?}", e);
         }
     }
 }
\ No newline at end of file
@@ -13,14 +13,53 @@ use futures::{
     compat::Future01CompatExt,
 };
 use grpcio::{ChannelBuilder, Environment, ResourceQuota, ServerBuilder};
+use std::fs::{self, File};
+use std::path::Path;
 use std::collections::HashMap;
-use std::fs::{OpenOptions, File};
 use std::env::args;
 use std::str::FromStr;
-use crate::store::Store;
-use crate::kv_server::KVServer;
+use std::time::Duration;
+use crate::store::{Store, MAX_ENTRY_SIZE};
+use crate::store::mem_store::{MemStore, ConcurrentMemStore};
+use crate::store::percolator::Percolator;
+use crate::store::oracle::TimestampOracle;
+use crate::store::versioned::MAX_HEADER_SIZE;
+use crate::store::lsm::{LsmTree, LsmOptions};
+use crate::store::skiplist::SkipList;
+use crate::store::redo_log::{SegmentedLog, LogOptions, Durability};
+use crate::kv_server::{KVServer, Limits};
 use pprof::protos::Message;
 
+/// the file in the data directory which records the count of partitions
+const PARTITIONS_FILE: &str = "PARTITIONS";
+
+/// check the data in `data_path` is spread over `partitions` partitions, or record it if there's no data yet,
+/// the keys are in the partitions by their hashes, so the count can't be changed
+fn check_partitions(data_path: &Path, partitions: usize) -> io::Result<()> {
+    let path = data_path.join(PARTITIONS_FILE);
+    let recorded = match fs::read_to_string(&path) {
+        Ok(recorded) => Some(usize::from_str(recorded.trim()).map_err(|_| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("invalid {} file", PARTITIONS_FILE))
+        })?),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            fs::create_dir_all(data_path)?;
+            // the data written before there were partitions is in one partition
+            if fs::read_dir(data_path)?.next().is_some() {
+                Some(1)
+            } else {
+                None
+            }
+        }
+        Err(err) => return Err(err),
+    };
+    match recorded {
+        Some(recorded) if recorded != partitions => {
+            panic!("the data is in {} partitions, PARTITIONS can't be changed to {}", recorded, partitions)
+        }
+        _ => fs::write(&path, partitions.to_string()),
+    }
+}
+
 fn main() {
     let guard = if cfg!(profile) {
         Some(pprof::ProfilerGuard::new(100).unwrap())
@@ -28,24 +67,152 @@ fn main() {
         None
     };
     env_logger::init();
-    // todo: maybe make cq_count configurable
-    let env = Arc::new(Environment::new(1));
     let mut args = args();
-    let log_path = args.nth(1).unwrap_or_else(|| "./minikv.log".to_string());
-    let log_file = OpenOptions::new()
-        .create(true)
-        .append(true)
-        .read(true)
-        .write(true)
-        .open(log_path)
-        .unwrap_or_else(|_| {
-            panic!("Cannot read or create log file!")
+    let log_path = args.nth(1).unwrap_or_else(|| "./minikv_log".to_string());
+    let data_path = args.next().unwrap_or_else(|| "./minikv_data".to_string());
+    let partitions = std::env::var("PARTITIONS")
+        .map(|s| usize::from_str(&s).ok().filter(|it| *it != 0).unwrap_or_else(|| {
+            panic!("PARTITIONS is not valid");
+        }))
+        .unwrap_or(1);
+    check_partitions(Path::new(&data_path), partitions).unwrap_or_else(|err| {
+        panic!("Cannot open data directory: {:?}", err)
+    });
+    // the requests are handled by the threads of the completion queues, one for each partition by default
+    let grpc_threads = std::env::var("GRPC_THREADS")
+        .map(|s| usize::from_str(&s).ok().filter(|it| *it != 0).unwrap_or_else(|| {
+            panic!("GRPC_THREADS is not valid");
+        }))
+        .unwrap_or(partitions);
+    let env = Arc::new(Environment::new(grpc_threads));
+    let mut lsm_options = LsmOptions::default();
+    if let Ok(threads) = std::env::var("COMPACTION_THREADS") {
+        lsm_options.compaction_threads = usize::from_str(&threads).unwrap_or_else(|_| {
+            panic!("COMPACTION_THREADS is not valid");
+        });
+    }
+    if let Ok(bits_per_key) = std::env::var("BLOOM_BITS_PER_KEY") {
+        lsm_options.bloom_bits_per_key = usize::from_str(&bits_per_key).unwrap_or_else(|_| {
+            panic!("BLOOM_BITS_PER_KEY is not valid");
+        });
+    }
+    if let Ok(rate_limit) = std::env::var("COMPACTION_RATE_LIMIT") {
+        lsm_options.compaction_rate_limit = Some(u64::from_str(&rate_limit).unwrap_or_else(|_| {
+            panic!("COMPACTION_RATE_LIMIT is not valid");
+        }));
+    }
+    // the Percolator transactions are kept apart from the other keys, in a store of their own
+    let percolator_lsm_tree = LsmTree::open(Path::new(&data_path).join("percolator"), lsm_options.clone())
+        .unwrap_or_else(|err| {
+            panic!("Cannot open data directory: {:?}", err)
+        });
+    let storage = std::env::var("STORAGE").unwrap_or_else(|_| "lsm".to_string());
+    let mem_stores: Vec<Box<dyn MemStore>> = match storage.as_str() {
+        // the data of a single partition is in the data directory itself, like before there were partitions
+        "lsm" => (0..partitions)
+            .map(|i| if partitions == 1 {
+                Path::new(&data_path).to_path_buf()
+            } else {
+                Path::new(&data_path).join(i.to_string())
+            })
+            .map(|path| LsmTree::open(path, lsm_options.clone()).unwrap_or_else(|err| {
+                panic!("Cannot open data directory: {:?}", err)
+            }))
+            .map(|it| Box::new(it) as Box<dyn MemStore>)
+            .collect(),
+        // the data is only in memory, it's recovered from the redo log and its checkpoints
+        "skiplist" => (0..partitions)
+            .map(|_| Box::new(Arc::new(SkipList::new()) as Arc<dyn ConcurrentMemStore>) as Box<dyn MemStore>)
+            .collect(),
+        // in memory too, but each scan walks and sorts the whole partition
+        "hashmap" => (0..partitions)
+            .map(|_| Box::new(HashMap::new()) as Box<dyn MemStore>)
+            .collect(),
+        _ => panic!("STORAGE is not valid"),
+    };
+    let mut log_options = LogOptions::default();
+    if let Ok(durability) = std::env::var("DURABILITY") {
+        log_options.durability = Durability::from_str(&durability).unwrap_or_else(|err| {
+            panic!("DURABILITY is not valid: {}", err);
+        });
+    }
+    if let Ok(max_batch) = std::env::var("GROUP_COMMIT_MAX_BATCH") {
+        log_options.max_batch = usize::from_str(&max_batch).unwrap_or_else(|_| {
+            panic!("GROUP_COMMIT_MAX_BATCH is not valid");
+        });
+    }
+    if let Ok(segment_size) = std::env::var("LOG_SEGMENT_SIZE") {
+        log_options.segment_size = u64::from_str(&segment_size).unwrap_or_else(|_| {
+            panic!("LOG_SEGMENT_SIZE is not valid");
         });
-    let store = Store::new(HashMap::new(), log_file);
-    let server = KVServer::new(store);
+    }
+    if let Ok(checkpoint_segments) = std::env::var("CHECKPOINT_SEGMENTS") {
+        log_options.checkpoint_segments = usize::from_str(&checkpoint_segments).unwrap_or_else(|_| {
+            panic!("CHECKPOINT_SEGMENTS is not valid");
+        });
+    }
+    let percolator_redo_log = SegmentedLog::open(Path::new(&log_path).join("percolator"), log_options)
+        .unwrap_or_else(|err| {
+            panic!("Cannot open redo log: {:?}", err)
+        });
+    let redo_log = SegmentedLog::open(&log_path, log_options)
+        .unwrap_or_else(|err| {
+            panic!("Cannot open redo log: {:?}", err)
+        });
+    let mut limits = Limits::default();
+    if let Ok(max_key_size) = std::env::var("MAX_KEY_SIZE") {
+        limits.max_key_size = usize::from_str(&max_key_size).unwrap_or_else(|_| {
+            panic!("MAX_KEY_SIZE is not valid");
+        });
+    }
+    if let Ok(max_value_size) = std::env::var("MAX_VALUE_SIZE") {
+        limits.max_value_size = usize::from_str(&max_value_size).unwrap_or_else(|_| {
+            panic!("MAX_VALUE_SIZE is not valid");
+        });
+    }
+    if let Ok(max_page_size) = std::env::var("MAX_PAGE_SIZE") {
+        limits.max_page_size = usize::from_str(&max_page_size).unwrap_or_else(|_| {
+            panic!("MAX_PAGE_SIZE is not valid");
+        });
+    }
+    if limits.max_key_size + limits.max_value_size + MAX_HEADER_SIZE > MAX_ENTRY_SIZE {
+        panic!("MAX_KEY_SIZE + MAX_VALUE_SIZE must be at most {}", MAX_ENTRY_SIZE - MAX_HEADER_SIZE);
+    }
+    let sweep_interval = std::env::var("EXPIRE_SWEEP_INTERVAL")
+        .map(|s| u64::from_str(&s).unwrap_or_else(|_| {
+            panic!("EXPIRE_SWEEP_INTERVAL is not valid");
+        }))
+        .unwrap_or(100);
+    let sweep_count = std::env::var("EXPIRE_SWEEP_COUNT")
+        .map(|s| usize::from_str(&s).unwrap_or_else(|_| {
+            panic!("EXPIRE_SWEEP_COUNT is not valid");
+        }))
+        .unwrap_or(256);
+    let mut store = Store::with_partitions(mem_stores, redo_log);
+    if let Ok(lease) = std::env::var("SNAPSHOT_LEASE") {
+        let lease = u64::from_str(&lease).unwrap_or_else(|_| {
+            panic!("SNAPSHOT_LEASE is not valid");
+        });
+        store.set_snapshot_lease(Duration::from_millis(lease));
+    }
+    let percolator = Percolator::new(Store::new(percolator_lsm_tree, percolator_redo_log));
+    // the limit of the timestamps is saved with the redo log of the transactions
+    let oracle = TimestampOracle::open(Path::new(&log_path).join("percolator"), || percolator.max_timestamp())
+        .unwrap_or_else(|err| {
+            panic!("Cannot open timestamp oracle: {:?}", err)
+        });
+    let server = KVServer::new(store, percolator, oracle, limits);
+    if sweep_interval != 0 && sweep_count != 0 {
+        server.start_sweeper(Duration::from_millis(sweep_interval), sweep_count);
+    }
     let service = rpc::minikv_grpc::create_mini_kv_server(server);
     let quota = ResourceQuota::new(Some("MiniKVServerQuota")).resize_memory(1024 * 1024);
-    let ch_builder = ChannelBuilder::new(env.clone()).set_resource_quota(quota);
+    // leave some room for the other fields of the messages
+    let max_message_size = (4 * 1024 * 1024).max(limits.max_key_size + limits.max_value_size + 64 * 1024);
+    let ch_builder = ChannelBuilder::new(env.clone())
+        .set_resource_quota(quota)
+        .max_receive_message_len(max_message_size as i32)
+        .max_send_message_len(max_message_size as i32);
     let host = std::env::var("HOST").unwrap_or_else(|_| "127.0.0.1".to_string());
     let port = std::env::var("PORT")
         .map(|s| u16::from_str(&s).unwrap_or_else(|_| {
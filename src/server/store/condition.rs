@@ -0,0 +1,44 @@
+//! Conditions of conditional writes, checked against the current value of a key
+//! under the same lock as the write, so nothing can change the key between them
+
+use crate::store::versioned::Versioned;
+
+/// A condition on the current value of a key
+#[derive(Clone, Debug, PartialEq)]
+pub enum Condition {
+    /// the key does not exist
+    Absent,
+    /// the key exists and its value is this
+    Equal(Vec<u8>),
+    /// the key exists and its version is this
+    Version(u64),
+}
+
+impl Condition {
+    /// whether the condition holds on `current`, the current value of the key
+    pub fn holds(&self, current: Option<&Versioned>) -> bool {
+        match self {
+            Condition::Absent => current.is_none(),
+            Condition::Equal(expected) => current.map(|it| &it.value) == Some(expected),
+            Condition::Version(expected) => current.map(|it| it.version) == Some(*expected),
+        }
+    }
+}
+
+/// `Ok` if the condition held, or `Err` with the current value of the key
+pub type Checked<T> = Result<T, Option<Versioned>>;
+
+#[test]
+fn test_condition() {
+    let empty = Versioned { version: 1, expire_at: None, value: vec![] };
+    let a = Versioned { version: 2, expire_at: None, value: b"a".to_vec() };
+    assert!(Condition::Absent.holds(None));
+    assert!(!Condition::Absent.holds(Some(&empty)));
+    assert!(Condition::Equal(vec![]).holds(Some(&empty)));
+    assert!(!Condition::Equal(vec![]).holds(None));
+    assert!(Condition::Equal(b"a".to_vec()).holds(Some(&a)));
+    assert!(!Condition::Equal(b"b".to_vec()).holds(Some(&a)));
+    assert!(Condition::Version(2).holds(Some(&a)));
+    assert!(!Condition::Version(1).holds(Some(&a)));
+    assert!(!Condition::Version(0).holds(None));
+}
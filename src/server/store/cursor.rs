@@ -0,0 +1,73 @@
+//! Cursors of the `Scan` API
+//!
+//! A cursor is an opaque number standing for the key a scan continues from, so a scan walks
+//! the keys in order, and every key present during the whole scan is returned exactly once,
+//! no matter what is put or deleted between the pages.
+//!
+//! The keys are kept in memory, only the latest `capacity` cursors are remembered,
+//! and all of them are forgotten when the server restarts.
+
+use std::collections::{HashMap, VecDeque};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub struct CursorTable {
+    next_id: u64,
+    keys: HashMap<u64, Vec<u8>>,
+    /// ids from the oldest to the newest
+    order: VecDeque<u64>,
+    capacity: usize,
+}
+
+impl CursorTable {
+    /// create a table which remembers at most `capacity` cursors
+    pub fn new(capacity: usize) -> Self {
+        // start from a different id each time, so the cursors before a restart are unlikely to be valid
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|it| it.as_nanos() as u64)
+            .unwrap_or(0);
+        Self {
+            // 0 is not a valid cursor, it means the start or the end of a scan
+            next_id: (seed << 16).max(1),
+            keys: HashMap::new(),
+            order: VecDeque::new(),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// remember `key` and return a new cursor standing for it
+    pub fn insert(&mut self, key: Vec<u8>) -> u64 {
+        let id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1).max(1);
+        if self.order.len() >= self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.keys.remove(&oldest);
+            }
+        }
+        self.keys.insert(id, key);
+        self.order.push_back(id);
+        id
+    }
+
+    /// the key `cursor` stands for, `None` if the cursor is unknown or forgotten
+    pub fn get(&self, cursor: u64) -> Option<&[u8]> {
+        self.keys.get(&cursor).map(|it| it.as_slice())
+    }
+}
+
+#[test]
+fn test_cursor_table() {
+    let mut table = CursorTable::new(2);
+    let first = table.insert(b"a".to_vec());
+    let second = table.insert(b"b".to_vec());
+    assert_ne!(first, 0);
+    assert_ne!(first, second);
+    assert_eq!(table.get(first), Some(&b"a"[..]));
+    // a cursor can be used more than once, like when a page is retried
+    assert_eq!(table.get(first), Some(&b"a"[..]));
+    let third = table.insert(b"c".to_vec());
+    assert!(table.get(first).is_none());
+    assert_eq!(table.get(second), Some(&b"b"[..]));
+    assert_eq!(table.get(third), Some(&b"c"[..]));
+    assert!(table.get(0).is_none());
+}
@@ -0,0 +1,303 @@
+//! Group commit for the redo log
+//!
+//! Writers enqueue their records into a `CommitQueue` and get a `Durable` for them.
+//! A dedicated writer thread, the leader, takes all the queued records as a batch, writes them
+//! into the log file with one write and syncs the file once as required by `Durability`,
+//! then notifies all the `Durable`s in the batch.
+
+use std::fs::File;
+use std::io::{self, Seek, SeekFrom, Write};
+use std::mem;
+use std::sync::{Arc, Condvar, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::thread::{self, JoinHandle};
+use std::time::Instant;
+use futures01::sync::oneshot;
+use crate::store::redo_log::{Durability, Durable};
+
+/// A command for the writer thread
+enum Command {
+    /// write an encoded record
+    Write(Vec<u8>),
+    /// write the records after into this file instead
+    Rotate(File),
+    /// notify after all the records before are persisted
+    Notify(oneshot::Sender<()>),
+}
+
+#[derive(Default)]
+struct QueueState {
+    commands: Vec<Command>,
+    shutdown: bool,
+}
+
+#[derive(Default)]
+struct Shared {
+    state: Mutex<QueueState>,
+    cond: Condvar,
+    batches: AtomicU64,
+    records: AtomicU64,
+    /// a write has failed, its records are lost even if the ones after it are persisted
+    failed: AtomicBool,
+}
+
+pub struct CommitQueue {
+    shared: Arc<Shared>,
+    writer: Option<JoinHandle<()>>,
+}
+
+/// take at most `max_batch` records from the front of `commands`, with the commands between them
+fn take_batch(commands: &mut Vec<Command>, max_batch: usize) -> Vec<Command> {
+    let mut records = 0;
+    let end = commands.iter()
+        .position(|it| {
+            if let Command::Write(_) = it {
+                records += 1;
+            }
+            records > max_batch
+        })
+        .unwrap_or(commands.len());
+    let rest = commands.split_off(end);
+    mem::replace(commands, rest)
+}
+
+/// The log file the writer thread writes into
+struct LogFile {
+    file: File,
+    /// a failed write can't be cut off the file, so nothing more can be written into it
+    broken: bool,
+}
+
+impl LogFile {
+    fn new(file: File) -> Self {
+        Self { file, broken: false }
+    }
+
+    /// write `buffer` at the end of the file and sync it as required by `durability`
+    /// a failed write is cut off the file, or the replay would stop at the torn record, and lose the records written after it
+    fn write(&mut self, durability: Durability, buffer: &[u8]) -> io::Result<()> {
+        if self.broken {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "a failed write is left in the redo log file"));
+        }
+        let size = self.file.seek(SeekFrom::End(0))?;
+        let file = &mut self.file;
+        let result = file.write_all(buffer).and_then(|_| {
+            if durability != Durability::Os {
+                file.sync_data()
+            } else {
+                Ok(())
+            }
+        });
+        if result.is_err() {
+            if let Err(err) = file.set_len(size).and_then(|_| file.sync_data()) {
+                error!("failed to cut a failed write off the redo log, no more records are written into it: {:?}", err);
+                self.broken = true;
+            }
+        }
+        result
+    }
+
+    /// write into `file` from now on
+    fn rotate(&mut self, file: File) {
+        *self = Self::new(file);
+    }
+}
+
+/// write all the records in `batch`, and notify the waiters of the records persisted
+/// a rotate in the batch is done even if the records before it fail, so the records after it go into the new file,
+/// and their waiters are notified if they are written
+fn write_batch(file: &mut LogFile, durability: Durability, batch: Vec<Command>, failed: &AtomicBool) {
+    let mut buffer = vec![];
+    let mut senders = vec![];
+    for command in batch {
+        match command {
+            Command::Write(record) => buffer.extend_from_slice(&record),
+            Command::Rotate(new_file) => {
+                write_and_notify(file, durability, &buffer, mem::take(&mut senders), failed);
+                buffer.clear();
+                file.rotate(new_file);
+            }
+            Command::Notify(sender) => senders.push(sender),
+        }
+    }
+    write_and_notify(file, durability, &buffer, senders, failed);
+}
+
+/// write `buffer` into `file`, notify `senders` if it's persisted, or set `failed`
+fn write_and_notify(file: &mut LogFile, durability: Durability, buffer: &[u8], senders: Vec<oneshot::Sender<()>>, failed: &AtomicBool) {
+    match file.write(durability, buffer) {
+        Ok(()) => senders.into_iter().for_each(|it| it.send(()).unwrap_or(())),
+        Err(err) => {
+            error!("write redolog failed: {:?}", err);
+            // set before the senders are dropped, so the waiters see it when they know the write failed
+            failed.store(true, Ordering::SeqCst);
+        }
+    }
+}
+
+fn writer_thread(file: File, durability: Durability, max_batch: usize, shared: Arc<Shared>) {
+    let mut file = LogFile::new(file);
+    loop {
+        let mut state = shared.state.lock().unwrap();
+        while state.commands.is_empty() && !state.shutdown {
+            state = shared.cond.wait(state).unwrap();
+        }
+        if state.commands.is_empty() {
+            info!(
+                "redo log writer exits, {} records written in {} batches",
+                shared.records.load(Ordering::Relaxed),
+                shared.batches.load(Ordering::Relaxed)
+            );
+            return;
+        }
+        if let Durability::GroupCommit(interval) = durability {
+            // wait for more writes to join this batch, the new writes wake us up before the deadline
+            let deadline = Instant::now() + interval;
+            while !state.shutdown {
+                let now = Instant::now();
+                if now >= deadline {
+                    break;
+                }
+                state = shared.cond.wait_timeout(state, deadline - now).unwrap().0;
+            }
+        }
+        let batch = take_batch(&mut state.commands, max_batch);
+        drop(state);
+        let records = batch.iter().filter(|it| matches!(it, Command::Write(_))).count();
+        // count before notifying, so the waiters see the batch counted
+        shared.batches.fetch_add(1, Ordering::Relaxed);
+        shared.records.fetch_add(records as u64, Ordering::Relaxed);
+        write_batch(&mut file, durability, batch, &shared.failed);
+        debug!("group committed {} records", records);
+    }
+}
+
+impl CommitQueue {
+    /// start a writer thread writing into `file`, which writes at most `max_batch` records at once
+    pub fn new(file: File, durability: Durability, max_batch: usize) -> io::Result<Self> {
+        let shared = Arc::new(Shared::default());
+        let thread_shared = shared.clone();
+        let writer = thread::Builder::new()
+            .name("redo-log-writer".to_string())
+            .spawn(move || writer_thread(file, durability, max_batch.max(1), thread_shared))?;
+        Ok(Self {
+            shared,
+            writer: Some(writer),
+        })
+    }
+
+    fn push(&self, command: Command) {
+        self.shared.state.lock().unwrap().commands.push(command);
+        self.shared.cond.notify_one();
+    }
+
+    /// enqueue an encoded record
+    pub fn write(&self, record: Vec<u8>) {
+        self.push(Command::Write(record));
+    }
+
+    /// enqueue a command to write the records after into `file`
+    pub fn rotate(&self, file: File) {
+        self.push(Command::Rotate(file));
+    }
+
+    /// return a `Durable` which resolves when all the records enqueued before are persisted
+    pub fn durable(&self) -> Durable {
+        let (sender, receiver) = oneshot::channel();
+        self.push(Command::Notify(sender));
+        receiver
+    }
+
+    /// whether any write has failed since the queue is started
+    pub fn failed(&self) -> bool {
+        self.shared.failed.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for CommitQueue {
+    fn drop(&mut self) {
+        self.shared.state.lock().unwrap().shutdown = true;
+        self.shared.cond.notify_one();
+        if let Some(writer) = self.writer.take() {
+            writer.join().unwrap_or(());
+        }
+    }
+}
+
+#[test]
+fn test_group_commit() {
+    use tempfile::tempfile;
+    use std::io::Read;
+    use std::time::Duration;
+    use futures01::Future;
+
+    let mut old_file = tempfile().unwrap();
+    let mut file = tempfile().unwrap();
+    let queue = CommitQueue::new(old_file.try_clone().unwrap(), Durability::Fsync, 4).unwrap();
+    queue.write(vec![42]);
+    queue.rotate(file.try_clone().unwrap());
+    let durables: Vec<_> = (0..10u8)
+        .map(|i| {
+            queue.write(vec![i]);
+            queue.durable()
+        })
+        .collect();
+    for durable in durables {
+        assert!(durable.wait().is_ok());
+    }
+    assert!(queue.shared.batches.load(Ordering::Relaxed) >= 3);
+    let mut content = vec![];
+    old_file.seek(SeekFrom::Start(0)).unwrap();
+    old_file.read_to_end(&mut content).unwrap();
+    assert_eq!(content, vec![42]);
+    let mut content = vec![];
+    file.seek(SeekFrom::Start(0)).unwrap();
+    file.read_to_end(&mut content).unwrap();
+    assert_eq!(content, vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+    let queue = CommitQueue::new(tempfile().unwrap(), Durability::GroupCommit(Duration::from_millis(50)), 1024).unwrap();
+    let durables: Vec<_> = (0..100u8)
+        .map(|i| {
+            queue.write(vec![i]);
+            queue.durable()
+        })
+        .collect();
+    for durable in durables {
+        assert!(durable.wait().is_ok());
+    }
+    assert!(queue.shared.batches.load(Ordering::Relaxed) <= 2);
+    assert_eq!(queue.shared.records.load(Ordering::Relaxed), 100);
+    assert!(!queue.failed());
+
+    // a file opened read-only fails every write, and the failed writes can't be cut off it
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("log");
+    File::create(&path).unwrap();
+    let queue = CommitQueue::new(File::open(&path).unwrap(), Durability::Fsync, 4).unwrap();
+    queue.write(vec![1]);
+    assert!(queue.durable().wait().is_err());
+    queue.write(vec![2]);
+    assert!(queue.durable().wait().is_err());
+    // the records after a rotate are written into the new file
+    let mut file = tempfile().unwrap();
+    queue.rotate(file.try_clone().unwrap());
+    queue.write(vec![3]);
+    assert!(queue.durable().wait().is_ok());
+    // the records failed are still lost
+    assert!(queue.failed());
+    let mut content = vec![];
+    file.seek(SeekFrom::Start(0)).unwrap();
+    file.read_to_end(&mut content).unwrap();
+    assert_eq!(content, vec![3]);
+
+    // the same in one batch, only the waiters of the failed write fail
+    let queue = CommitQueue::new(File::open(&path).unwrap(), Durability::GroupCommit(Duration::from_millis(50)), 1024).unwrap();
+    queue.write(vec![4]);
+    let failed = queue.durable();
+    queue.rotate(tempfile().unwrap());
+    queue.write(vec![5]);
+    let written = queue.durable();
+    assert!(failed.wait().is_err());
+    assert!(written.wait().is_ok());
+    assert_eq!(queue.shared.batches.load(Ordering::Relaxed), 1);
+}
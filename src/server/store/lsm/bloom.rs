@@ -0,0 +1,100 @@
+//! Bloom filter over the keys in a sstable
+
+/// A bloom filter which uses double hashing to simulate `hash_count` hash functions
+pub struct BloomFilter {
+    bits: Vec<u8>,
+    hash_count: u8,
+}
+
+/// a 64 bit hash of the key, FNV-1a followed by the finalizer of murmur3 to mix the bits
+pub fn hash_key(key: &[u8]) -> u64 {
+    let mut h = 0xcbf2_9ce4_8422_2325u64;
+    for byte in key {
+        h ^= *byte as u64;
+        h = h.wrapping_mul(0x0100_0000_01b3);
+    }
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+    h ^= h >> 33;
+    h
+}
+
+impl BloomFilter {
+    /// build a filter with about `bits_per_key` bits for each of the key `hashes`
+    /// if `bits_per_key` is 0, the filter contains nothing and `may_contain` always returns `true`
+    pub fn build(hashes: &[u64], bits_per_key: usize) -> Self {
+        if bits_per_key == 0 {
+            return Self {
+                bits: vec![],
+                hash_count: 0,
+            };
+        }
+        // 0.69 =~ ln(2), which minimizes the false positive rate
+        let hash_count = ((bits_per_key as f64 * 0.69) as u8).clamp(1, 30);
+        let bit_count = (hashes.len() * bits_per_key).max(64);
+        let mut filter = Self {
+            bits: vec![0u8; bit_count.div_ceil(8)],
+            hash_count,
+        };
+        for hash in hashes {
+            for bit in filter.bit_positions(*hash) {
+                filter.bits[bit / 8] |= 1 << (bit % 8);
+            }
+        }
+        filter
+    }
+
+    fn bit_positions(&self, hash: u64) -> impl Iterator<Item=usize> {
+        let bit_count = (self.bits.len() * 8) as u64;
+        let delta = (hash >> 32) | 1;
+        (0..self.hash_count as u64)
+            .map(move |i| (hash.wrapping_add(i.wrapping_mul(delta)) % bit_count) as usize)
+    }
+
+    /// return `false` if the key with `hash` is definitely not in the filter
+    pub fn may_contain(&self, hash: u64) -> bool {
+        if self.hash_count == 0 {
+            return true;
+        }
+        self.bit_positions(hash)
+            .all(|bit| self.bits[bit / 8] & (1 << (bit % 8)) != 0)
+    }
+
+    /// encode the filter as hash_count(1 byte) + bits
+    pub fn encode(&self) -> Vec<u8> {
+        let mut result = Vec::with_capacity(self.bits.len() + 1);
+        result.push(self.hash_count);
+        result.extend_from_slice(&self.bits);
+        result
+    }
+
+    /// decode the filter encoded by `encode`, return `None` if the content is invalid
+    pub fn decode(content: &[u8]) -> Option<Self> {
+        let (hash_count, bits) = content.split_first()?;
+        if *hash_count != 0 && bits.is_empty() {
+            return None;
+        }
+        Some(Self {
+            bits: bits.to_vec(),
+            hash_count: *hash_count,
+        })
+    }
+}
+
+#[test]
+fn test_bloom_filter() {
+    let hashes: Vec<_> = (0..1000u64).map(|i| hash_key(&(i * 2).to_le_bytes())).collect();
+    let filter = BloomFilter::build(&hashes, 10);
+    let filter = BloomFilter::decode(&filter.encode()).unwrap();
+    assert!(hashes.iter().all(|it| filter.may_contain(*it)));
+    let false_positives = (0..1000u64)
+        .filter(|i| filter.may_contain(hash_key(&(i * 2 + 1).to_le_bytes())))
+        .count();
+    // the expected false positive rate for 10 bits per key is about 1%
+    assert!(false_positives < 50);
+
+    let filter = BloomFilter::build(&hashes, 0);
+    assert!(filter.may_contain(hash_key(&[1u8; 8])));
+}
@@ -0,0 +1,357 @@
+//! Leveled compaction
+//!
+//! Level 0 contains the sstables flushed from the memtable, they may overlap with each other.
+//! When there are too many tables in level 0, they are merged with the overlapping tables
+//! in level 1.
+//! For other levels, the tables in the same level never overlap, and each level can hold
+//! `level_size_multiplier` times more data than the level above. When a level is too large,
+//! one of its tables is merged with the overlapping tables in the next level.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+use std::io;
+use crate::store::lsm::{Shared, Version, LsmOptions, MergeIter, table_path};
+use crate::store::lsm::sstable::{SsTable, SsTableIter, Entry, entry_size};
+
+/// how long to wait before retrying when a compaction failed
+const RETRY_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Counters about flush and compaction
+#[derive(Default)]
+pub struct Counters {
+    pub flushed_bytes: AtomicU64,
+    pub compaction_read_bytes: AtomicU64,
+    pub compaction_written_bytes: AtomicU64,
+    pub compactions: AtomicU64,
+    pub bloom_false_positives: AtomicU64,
+}
+
+/// Statistics of the lsm-tree, useful for tuning compaction
+pub struct LsmStats {
+    /// bytes written into level 0 by flushing memtable
+    pub flushed_bytes: u64,
+    /// bytes read by compactions
+    pub compaction_read_bytes: u64,
+    /// bytes written by compactions
+    pub compaction_written_bytes: u64,
+    /// how many compactions has been done
+    pub compactions: u64,
+    /// estimated bytes need to be compacted to make each level fits its size limit
+    pub pending_compaction_bytes: u64,
+    /// total size of sstables in each level
+    pub level_bytes: Vec<u64>,
+    /// how many times the bloom filter said a key may exist but actually it's not
+    pub bloom_false_positives: u64,
+}
+
+impl LsmStats {
+    /// bytes written into disk for each byte flushed from memtable
+    pub fn write_amplification(&self) -> f64 {
+        if self.flushed_bytes == 0 {
+            return 0.0;
+        }
+        (self.flushed_bytes + self.compaction_written_bytes) as f64 / self.flushed_bytes as f64
+    }
+}
+
+impl fmt::Display for LsmStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "flushed {} bytes, compactions {}, compaction read {} bytes, compaction written {} bytes, \
+            write amplification {:.2}, pending compaction {} bytes, level sizes {:?}, \
+            bloom filter false positives {}",
+            self.flushed_bytes,
+            self.compactions,
+            self.compaction_read_bytes,
+            self.compaction_written_bytes,
+            self.write_amplification(),
+            self.pending_compaction_bytes,
+            self.level_bytes,
+            self.bloom_false_positives
+        )
+    }
+}
+
+#[derive(Default)]
+pub struct CompactionState {
+    pub shutdown: bool,
+    /// ids of the tables being compacted
+    busy: HashSet<u64>,
+    /// the max key of the last table compacted in each level,
+    /// so we can compact the tables in a level in a round-robin way
+    compact_pointer: Vec<Option<Vec<u8>>>,
+}
+
+impl CompactionState {
+    /// whether there are compactions running
+    pub fn is_busy(&self) -> bool {
+        !self.busy.is_empty()
+    }
+}
+
+struct CompactionTask {
+    /// compact from `level` to `level + 1`
+    level: usize,
+    inputs: Vec<Arc<SsTable>>,
+    next_level_inputs: Vec<Arc<SsTable>>,
+}
+
+fn level_bytes(level: &[Arc<SsTable>]) -> u64 {
+    level.iter().map(|it| it.file_size()).sum()
+}
+
+fn max_bytes_for_level(options: &LsmOptions, level: usize) -> u64 {
+    let mut result = options.level1_max_bytes;
+    for _ in 1..level {
+        result = result.saturating_mul(options.level_size_multiplier);
+    }
+    result
+}
+
+pub fn pending_compaction_bytes(options: &LsmOptions, version: &Version) -> u64 {
+    let mut result = 0;
+    for (level, tables) in version.levels.iter().enumerate() {
+        let bytes = level_bytes(tables);
+        if level == 0 {
+            if tables.len() >= options.level0_compaction_trigger {
+                result += bytes;
+            }
+        } else if level + 1 < options.max_levels {
+            result += bytes.saturating_sub(max_bytes_for_level(options, level));
+        }
+    }
+    result
+}
+
+pub fn stats(shared: &Shared) -> LsmStats {
+    let version = shared.current();
+    LsmStats {
+        flushed_bytes: shared.counters.flushed_bytes.load(Ordering::Relaxed),
+        compaction_read_bytes: shared.counters.compaction_read_bytes.load(Ordering::Relaxed),
+        compaction_written_bytes: shared.counters.compaction_written_bytes.load(Ordering::Relaxed),
+        compactions: shared.counters.compactions.load(Ordering::Relaxed),
+        pending_compaction_bytes: pending_compaction_bytes(&shared.options, &version),
+        level_bytes: version.levels.iter().map(|it| level_bytes(it)).collect(),
+        bloom_false_positives: shared.counters.bloom_false_positives.load(Ordering::Relaxed),
+    }
+}
+
+/// key range covered by all `tables`
+fn key_range(tables: &[Arc<SsTable>]) -> (Vec<u8>, Vec<u8>) {
+    let min_key = tables.iter().map(|it| it.min_key()).min().unwrap_or(&[]).to_vec();
+    let max_key = tables.iter().map(|it| it.max_key()).max().unwrap_or(&[]).to_vec();
+    (min_key, max_key)
+}
+
+fn overlapping(tables: &[Arc<SsTable>], min_key: &[u8], max_key: &[u8]) -> Vec<Arc<SsTable>> {
+    tables.iter()
+        .filter(|it| it.overlaps(min_key, max_key))
+        .cloned()
+        .collect()
+}
+
+/// build a task if `inputs` and the overlapping tables in the next level are not being compacted
+fn try_task(version: &Version, state: &CompactionState, level: usize, inputs: Vec<Arc<SsTable>>) -> Option<CompactionTask> {
+    let (min_key, max_key) = key_range(&inputs);
+    let next_level_inputs = version.levels.get(level + 1)
+        .map(|it| overlapping(it, &min_key, &max_key))
+        .unwrap_or_default();
+    let busy = inputs.iter()
+        .chain(next_level_inputs.iter())
+        .any(|it| state.busy.contains(&it.id()));
+    if busy {
+        None
+    } else {
+        Some(CompactionTask {
+            level,
+            inputs,
+            next_level_inputs,
+        })
+    }
+}
+
+fn pick(options: &LsmOptions, version: &Version, state: &mut CompactionState) -> Option<CompactionTask> {
+    let mut task = None;
+    if version.levels[0].len() >= options.level0_compaction_trigger {
+        // level 0 tables may overlap with each other, so we need to compact all of them together
+        task = try_task(version, state, 0, version.levels[0].clone());
+    }
+    for level in 1..version.levels.len().min(options.max_levels - 1) {
+        if task.is_some() {
+            break;
+        }
+        let tables = &version.levels[level];
+        if level_bytes(tables) <= max_bytes_for_level(options, level) {
+            continue;
+        }
+        if state.compact_pointer.len() <= level {
+            state.compact_pointer.resize(level + 1, None);
+        }
+        let pointer = &state.compact_pointer[level];
+        let start = tables.iter()
+            .position(|it| pointer.as_ref().map(|pointer| it.min_key() > pointer.as_slice()).unwrap_or(true))
+            .unwrap_or(0);
+        task = tables[start..].iter()
+            .chain(tables[..start].iter())
+            .filter_map(|table| try_task(version, state, level, vec![table.clone()]))
+            .next();
+        if let Some(task) = &task {
+            state.compact_pointer[level] = Some(task.inputs[0].max_key().to_vec());
+        }
+    }
+    if let Some(task) = &task {
+        for table in task.inputs.iter().chain(task.next_level_inputs.iter()) {
+            state.busy.insert(table.id());
+        }
+    }
+    task
+}
+
+/// Limit the speed of writing
+struct RateLimiter {
+    bytes_per_second: Option<u64>,
+    start: Instant,
+    written: u64,
+}
+
+impl RateLimiter {
+    fn new(bytes_per_second: Option<u64>) -> Self {
+        Self {
+            bytes_per_second,
+            start: Instant::now(),
+            written: 0,
+        }
+    }
+
+    /// record `bytes` has been written, sleep if we are writing too fast
+    fn consume(&mut self, bytes: u64) {
+        self.written += bytes;
+        if let Some(bytes_per_second) = self.bytes_per_second {
+            let expected = Duration::from_secs_f64(self.written as f64 / bytes_per_second as f64);
+            let elapsed = self.start.elapsed();
+            if expected > elapsed {
+                thread::sleep(expected - elapsed);
+            }
+        }
+    }
+}
+
+/// write the merged result of `task` into new tables in `task.level + 1`
+fn run(shared: &Shared, version: &Version, task: &CompactionTask) -> io::Result<Vec<Arc<SsTable>>> {
+    let deeper_tables: Vec<_> = version.levels.iter()
+        .skip(task.level + 2)
+        .flatten()
+        .collect();
+    // a tombstone is useless if there's no older data in deeper levels it can shadow
+    let shadows_nothing = |key: &[u8]| !deeper_tables.iter().any(|it| it.overlaps(key, key));
+    let sources: Vec<Box<dyn Iterator<Item=Entry> + Send>> = task.inputs.iter()
+        .chain(task.next_level_inputs.iter())
+        .map(|it| Box::new(SsTableIter::new(it.clone())) as Box<dyn Iterator<Item=Entry> + Send>)
+        .collect();
+    let mut merged = MergeIter::new(sources)
+        .filter(|(key, value)| value.is_some() || !shadows_nothing(key))
+        .peekable();
+    let mut rate_limiter = RateLimiter::new(shared.options.compaction_rate_limit);
+    let mut outputs = vec![];
+    while merged.peek().is_some() {
+        // cut a new table once the entries reach the table size, a table has one entry at least
+        let mut entries = vec![];
+        let mut size = 0;
+        while let Some(entry) = merged.next_if(|_| entries.is_empty() || size < shared.options.table_size) {
+            size += entry_size(&entry);
+            entries.push(entry);
+        }
+        let id = shared.allocate_id();
+        match SsTable::create(id, &table_path(&shared.dir, id), entries, shared.options.bloom_bits_per_key) {
+            Ok(table) => {
+                rate_limiter.consume(table.file_size());
+                outputs.push(Arc::new(table));
+            }
+            Err(err) => {
+                outputs.iter().for_each(|it| it.mark_obsolete());
+                return Err(err);
+            }
+        }
+    }
+    Ok(outputs)
+}
+
+fn compact(shared: &Shared, task: &CompactionTask) -> io::Result<()> {
+    let version = shared.current();
+    let outputs = run(shared, &version, task)?;
+    let input_ids: HashSet<_> = task.inputs.iter()
+        .chain(task.next_level_inputs.iter())
+        .map(|it| it.id())
+        .collect();
+    let output_level = task.level + 1;
+    let installed = shared.install(|version| {
+        let mut version = version.clone();
+        if version.levels.len() <= output_level {
+            version.levels.resize(output_level + 1, vec![]);
+        }
+        for level in &mut version.levels[task.level..=output_level] {
+            level.retain(|it| !input_ids.contains(&it.id()));
+        }
+        version.levels[output_level].extend(outputs.iter().cloned());
+        version.levels[output_level].sort_by(|a, b| a.min_key().cmp(b.min_key()));
+        version
+    });
+    if let Err(err) = installed {
+        outputs.iter().for_each(|it| it.mark_obsolete());
+        return Err(err);
+    }
+    for table in task.inputs.iter().chain(task.next_level_inputs.iter()) {
+        table.mark_obsolete();
+    }
+    let read_bytes: u64 = task.inputs.iter()
+        .chain(task.next_level_inputs.iter())
+        .map(|it| it.file_size())
+        .sum();
+    let written_bytes: u64 = outputs.iter().map(|it| it.file_size()).sum();
+    shared.counters.compaction_read_bytes.fetch_add(read_bytes, Ordering::Relaxed);
+    shared.counters.compaction_written_bytes.fetch_add(written_bytes, Ordering::Relaxed);
+    shared.counters.compactions.fetch_add(1, Ordering::Relaxed);
+    info!(
+        "compacted {} tables in level {} and {} tables in level {} into {} tables",
+        task.inputs.len(), task.level, task.next_level_inputs.len(), output_level, outputs.len()
+    );
+    Ok(())
+}
+
+/// find a compaction task and run it, until the lsm-tree is shutdown
+pub fn compaction_thread(shared: Arc<Shared>) {
+    loop {
+        let task = {
+            let mut state = shared.compaction.lock().unwrap();
+            loop {
+                if state.shutdown {
+                    return;
+                }
+                if let Some(task) = pick(&shared.options, &shared.current(), &mut state) {
+                    break task;
+                }
+                state = shared.compaction_cond.wait(state).unwrap();
+            }
+        };
+        let result = compact(&shared, &task);
+        let mut state = shared.compaction.lock().unwrap();
+        for table in task.inputs.iter().chain(task.next_level_inputs.iter()) {
+            state.busy.remove(&table.id());
+        }
+        match result {
+            Ok(()) => {
+                info!("lsm-tree stats: {}", stats(&shared));
+                shared.compaction_cond.notify_all();
+            }
+            Err(err) => {
+                error!("compaction failed: {:?}", err);
+                let _ = shared.compaction_cond.wait_timeout(state, RETRY_INTERVAL).unwrap();
+            }
+        }
+    }
+}
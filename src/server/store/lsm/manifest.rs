@@ -0,0 +1,114 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+const MANIFEST_FILE: &str = "MANIFEST";
+const MANIFEST_TMP_FILE: &str = "MANIFEST.tmp";
+
+/// Records which sstable is in which level
+/// It is stored in a plain text file like:
+/// ```text
+/// next_id 5
+/// 0 4
+/// 0 3
+/// 1 1
+/// ```
+/// Each line after the first one is `<level> <sstable id>`,
+/// tables in level 0 are ordered from newest to oldest,
+/// tables in other levels are ordered by their key range.
+pub struct Manifest {
+    dir: PathBuf,
+    pub next_id: u64,
+    pub levels: Vec<Vec<u64>>,
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+impl Manifest {
+    /// load the manifest in `dir`, create an empty one if not exists
+    pub fn load(dir: &Path) -> io::Result<Self> {
+        let mut manifest = Self {
+            dir: dir.to_path_buf(),
+            next_id: 1,
+            levels: vec![vec![]],
+        };
+        let path = dir.join(MANIFEST_FILE);
+        if !path.exists() {
+            manifest.save()?;
+            return Ok(manifest);
+        }
+        let mut content = String::new();
+        File::open(path)?.read_to_string(&mut content)?;
+        let mut lines = content.lines();
+        let first_line = lines.next().ok_or_else(|| invalid_data("empty manifest"))?;
+        let mut parts = first_line.split(' ');
+        manifest.next_id = match (parts.next(), parts.next().map(u64::from_str)) {
+            (Some("next_id"), Some(Ok(next_id))) => next_id,
+            _ => return Err(invalid_data("invalid next_id in manifest")),
+        };
+        for line in lines.filter(|it| !it.is_empty()) {
+            let mut parts = line.split(' ').map(u64::from_str);
+            match (parts.next(), parts.next()) {
+                (Some(Ok(level)), Some(Ok(id))) => {
+                    let level = level as usize;
+                    if manifest.levels.len() <= level {
+                        manifest.levels.resize(level + 1, vec![]);
+                    }
+                    manifest.levels[level].push(id);
+                }
+                _ => return Err(invalid_data("invalid line in manifest")),
+            }
+        }
+        Ok(manifest)
+    }
+
+    /// allocate an id for a new sstable
+    pub fn allocate_id(&mut self) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+
+    /// write the manifest into disk
+    /// we write to a temporary file first and then rename it, so the manifest is either
+    /// the old one or the new one when crashed
+    pub fn save(&self) -> io::Result<()> {
+        let mut content = format!("next_id {}\n", self.next_id);
+        for (level, ids) in self.levels.iter().enumerate() {
+            for id in ids {
+                content.push_str(&format!("{} {}\n", level, id));
+            }
+        }
+        let tmp_path = self.dir.join(MANIFEST_TMP_FILE);
+        let mut file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(&tmp_path)?;
+        file.write_all(content.as_bytes())?;
+        file.sync_all()?;
+        fs::rename(tmp_path, self.dir.join(MANIFEST_FILE))?;
+        File::open(&self.dir)?.sync_all()
+    }
+}
+
+#[test]
+fn test_manifest() {
+    use tempfile::tempdir;
+
+    let dir = tempdir().unwrap();
+    let mut manifest = Manifest::load(dir.path()).unwrap();
+    assert_eq!(manifest.next_id, 1);
+    let id = manifest.allocate_id();
+    manifest.levels[0].push(id);
+    let id = manifest.allocate_id();
+    manifest.levels.push(vec![id]);
+    manifest.save().unwrap();
+
+    let manifest = Manifest::load(dir.path()).unwrap();
+    assert_eq!(manifest.next_id, 3);
+    assert_eq!(manifest.levels, vec![vec![1], vec![2]]);
+}
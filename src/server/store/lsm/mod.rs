@@ -0,0 +1,623 @@
+//! A simple lsm-tree based storage engine
+//!
+//! New writes go into the memtable, when the memtable is large enough, it will be
+//! flushed into an immutable sstable file in level 0.
+//! Background threads compact the sstables level by level, see `compaction` for detail.
+//! Which sstable belongs to which level is tracked by the manifest.
+//! Reads merge the memtable and all the sstables, newer data shadows older data.
+
+mod bloom;
+mod compaction;
+mod manifest;
+mod sstable;
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::iter::{self, Peekable};
+use std::ops::Bound;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Arc, Condvar, Mutex, RwLock};
+use std::sync::atomic::Ordering;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+use crate::store::mem_store::MemStore;
+use crate::store::snapshot::Snapshot;
+use crate::store::lsm::compaction::{CompactionState, Counters, compaction_thread};
+use crate::store::lsm::manifest::Manifest;
+use crate::store::lsm::sstable::{SsTable, SsTableIter, SsTableRevIter, Entry};
+
+pub use crate::store::lsm::compaction::LsmStats;
+
+#[derive(Clone)]
+pub struct LsmOptions {
+    /// flush the memtable into disk when it contains so many entries
+    pub memtable_size: usize,
+    /// bits used for each key in the bloom filter of sstables, 0 for no bloom filter
+    pub bloom_bits_per_key: usize,
+    /// compact level 0 into level 1 when there are so many sstables in level 0
+    pub level0_compaction_trigger: usize,
+    /// max total size of sstables in level 1, in bytes
+    pub level1_max_bytes: u64,
+    /// each level can hold so many times more data than the level above
+    pub level_size_multiplier: u64,
+    /// max count of levels, the last level is never compacted
+    pub max_levels: usize,
+    /// target size of sstables generated by compaction, in bytes
+    pub table_size: u64,
+    /// how many background threads are used for compaction
+    pub compaction_threads: usize,
+    /// max bytes can be written by each compaction thread per second, `None` for no limit
+    pub compaction_rate_limit: Option<u64>,
+}
+
+impl Default for LsmOptions {
+    fn default() -> Self {
+        Self {
+            memtable_size: 4096,
+            bloom_bits_per_key: 10,
+            level0_compaction_trigger: 4,
+            level1_max_bytes: 16 * 1024 * 1024,
+            level_size_multiplier: 10,
+            max_levels: 7,
+            table_size: 2 * 1024 * 1024,
+            compaction_threads: 1,
+            compaction_rate_limit: None,
+        }
+    }
+}
+
+/// The sstables in each level at some point
+/// Tables in level 0 are ordered from newest to oldest, tables in other levels are ordered by key
+#[derive(Clone)]
+pub struct Version {
+    levels: Vec<Vec<Arc<SsTable>>>,
+}
+
+/// States shared by the lsm-tree and the compaction threads
+pub struct Shared {
+    dir: PathBuf,
+    options: LsmOptions,
+    version: RwLock<Arc<Version>>,
+    manifest: Mutex<Manifest>,
+    compaction: Mutex<CompactionState>,
+    compaction_cond: Condvar,
+    counters: Counters,
+}
+
+impl Shared {
+    fn current(&self) -> Arc<Version> {
+        self.version.read().unwrap().clone()
+    }
+
+    fn allocate_id(&self) -> u64 {
+        self.manifest.lock().unwrap().allocate_id()
+    }
+
+    /// replace current version with the one returned by `edit`, and write it into the manifest
+    fn install<F: FnOnce(&Version) -> Version>(&self, edit: F) -> io::Result<()> {
+        let mut manifest = self.manifest.lock().unwrap();
+        let version = edit(&self.current());
+        let old_levels = manifest.levels.clone();
+        manifest.levels = version.levels.iter()
+            .map(|level| level.iter().map(|it| it.id()).collect())
+            .collect();
+        if let Err(err) = manifest.save() {
+            manifest.levels = old_levels;
+            return Err(err);
+        }
+        *self.version.write().unwrap() = Arc::new(version);
+        Ok(())
+    }
+}
+
+pub struct LsmTree {
+    memtable: BTreeMap<Vec<u8>, Option<Vec<u8>>>,
+    shared: Arc<Shared>,
+    compaction_threads: Vec<JoinHandle<()>>,
+}
+
+fn table_path(dir: &Path, id: u64) -> PathBuf {
+    dir.join(format!("{:06}.sst", id))
+}
+
+/// Merge several sorted iterators into one
+/// If a key appears in several iterators, the one from the iterator with smaller index wins
+pub struct MergeIter<'a> {
+    sources: Vec<Peekable<Box<dyn Iterator<Item=Entry> + Send + 'a>>>,
+    /// the sources are sorted in reverse key order
+    reverse: bool,
+}
+
+impl<'a> MergeIter<'a> {
+    pub fn new(sources: Vec<Box<dyn Iterator<Item=Entry> + Send + 'a>>) -> Self {
+        Self {
+            sources: sources.into_iter().map(Iterator::peekable).collect(),
+            reverse: false,
+        }
+    }
+
+    /// merge `sources` sorted in reverse key order
+    pub fn new_rev(sources: Vec<Box<dyn Iterator<Item=Entry> + Send + 'a>>) -> Self {
+        Self {
+            reverse: true,
+            ..Self::new(sources)
+        }
+    }
+}
+
+impl<'a> Iterator for MergeIter<'a> {
+    type Item = Entry;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let keys = self.sources.iter_mut()
+            .filter_map(|it| it.peek().map(|entry| &entry.0));
+        let min_key = if self.reverse { keys.max() } else { keys.min() }?.clone();
+        let mut result = None;
+        for source in self.sources.iter_mut() {
+            if source.peek().map(|entry| entry.0 == min_key).unwrap_or(false) {
+                let entry = source.next();
+                if result.is_none() {
+                    result = entry;
+                }
+            }
+        }
+        result
+    }
+}
+
+impl LsmTree {
+    /// open the lsm-tree stored in `dir`, create one if not exists
+    pub fn open<P: AsRef<Path>>(dir: P, options: LsmOptions) -> io::Result<Self> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+        let manifest = Manifest::load(dir)?;
+        let mut levels = vec![];
+        for ids in manifest.levels.iter() {
+            let mut level = vec![];
+            for id in ids {
+                level.push(Arc::new(SsTable::open(*id, &table_path(dir, *id))?));
+            }
+            levels.push(level);
+        }
+        // sstables not in the manifest are written by an unfinished flush or compaction,
+        // or are compacted but not removed yet, just remove them
+        for dir_entry in fs::read_dir(dir)? {
+            let path = dir_entry?.path();
+            if path.extension().map(|it| it != "sst").unwrap_or(true) {
+                continue;
+            }
+            let id = path.file_stem()
+                .and_then(|it| it.to_str())
+                .and_then(|it| u64::from_str(it).ok());
+            let in_manifest = id
+                .map(|id| manifest.levels.iter().flatten().any(|it| *it == id))
+                .unwrap_or(false);
+            if !in_manifest {
+                warn!("removing sstable {:?} which is not in manifest", path);
+                fs::remove_file(path)?;
+            }
+        }
+        let thread_count = options.compaction_threads;
+        let shared = Arc::new(Shared {
+            dir: dir.to_path_buf(),
+            options,
+            version: RwLock::new(Arc::new(Version { levels })),
+            manifest: Mutex::new(manifest),
+            compaction: Mutex::new(CompactionState::default()),
+            compaction_cond: Condvar::new(),
+            counters: Counters::default(),
+        });
+        let compaction_threads = (0..thread_count)
+            .map(|i| {
+                let shared = shared.clone();
+                thread::Builder::new()
+                    .name(format!("compaction-{}", i))
+                    .spawn(move || compaction_thread(shared))
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+        Ok(Self {
+            memtable: BTreeMap::new(),
+            shared,
+            compaction_threads,
+        })
+    }
+
+    /// write the memtable into a new sstable in level 0
+    fn flush(&mut self) -> io::Result<()> {
+        if self.memtable.is_empty() {
+            return Ok(());
+        }
+        let id = self.shared.allocate_id();
+        let path = table_path(&self.shared.dir, id);
+        let table = SsTable::create(
+            id,
+            &path,
+            self.memtable.iter().map(|(k, v)| (k.clone(), v.clone())),
+            self.shared.options.bloom_bits_per_key,
+        )?;
+        let table = Arc::new(table);
+        let installed = self.shared.install(|version| {
+            let mut version = version.clone();
+            version.levels[0].insert(0, table.clone());
+            version
+        });
+        if let Err(err) = installed {
+            table.mark_obsolete();
+            return Err(err);
+        }
+        info!("flushed {} entries into sstable {}", table.len(), id);
+        self.shared.counters.flushed_bytes.fetch_add(table.file_size(), Ordering::Relaxed);
+        self.memtable.clear();
+        self.shared.compaction_cond.notify_all();
+        Ok(())
+    }
+
+}
+
+impl Drop for LsmTree {
+    fn drop(&mut self) {
+        self.shared.compaction.lock().unwrap().shutdown = true;
+        self.shared.compaction_cond.notify_all();
+        for thread in self.compaction_threads.drain(..) {
+            thread.join().unwrap_or(());
+        }
+    }
+}
+
+impl MemStore for LsmTree {
+    fn put(&mut self, key: Vec<u8>, value: Vec<u8>) {
+        self.memtable.insert(key, Some(value));
+    }
+
+    fn get(&self, key: &[u8]) -> io::Result<Option<Vec<u8>>> {
+        if let Some(entry) = self.memtable.get(key) {
+            return Ok(entry.clone());
+        }
+        for table in self.shared.current().levels.iter().flatten() {
+            if !table.may_contain(key) {
+                continue;
+            }
+            match table.get(key) {
+                Ok(Some(entry)) => return Ok(entry),
+                Ok(None) => {
+                    self.shared.counters.bloom_false_positives.fetch_add(1, Ordering::Relaxed);
+                }
+                Err(err) => {
+                    error!("read sstable {:?} failed: {:?}", table.path(), err);
+                    return Err(err);
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    fn delete(&mut self, key: &[u8]) -> io::Result<Option<Vec<u8>>> {
+        let result = self.get(key);
+        // the key may be in an sstable which can't be read, so it's only skipped if it's surely not there
+        if result.as_ref().map(Option::is_some).unwrap_or(true) {
+            self.memtable.insert(key.to_vec(), None);
+        }
+        result
+    }
+
+    fn range<'a>(&'a self, start: &[u8], end: Option<&[u8]>) -> Box<dyn Iterator<Item=(Vec<u8>, Vec<u8>)> + 'a> {
+        if end.map(|end| end <= start).unwrap_or(false) {
+            return Box::new(iter::empty());
+        }
+        let memtable_end = end.map(Bound::Excluded).unwrap_or(Bound::Unbounded);
+        let mut sources: Vec<Box<dyn Iterator<Item=Entry> + Send + 'a>> = vec![Box::new(
+            self.memtable.range::<[u8], _>((Bound::Included(start), memtable_end))
+                .map(|(k, v)| (k.clone(), v.clone()))
+        )];
+        for table in self.shared.current().levels.iter().flatten() {
+            let before_end = end.map(|end| table.min_key() < end).unwrap_or(true);
+            if table.max_key() >= start && before_end {
+                sources.push(Box::new(SsTableIter::seek(table.clone(), start)));
+            }
+        }
+        let end = end.map(|it| it.to_vec());
+        Box::new(
+            MergeIter::new(sources)
+                .take_while(move |(key, _)| end.as_ref().map(|end| key < end).unwrap_or(true))
+                .filter_map(|(key, value)| value.map(|value| (key, value)))
+        )
+    }
+
+    fn range_rev<'a>(&'a self, start: &[u8], end: Option<&[u8]>) -> Box<dyn Iterator<Item=(Vec<u8>, Vec<u8>)> + 'a> {
+        if end.map(|end| end <= start).unwrap_or(false) {
+            return Box::new(iter::empty());
+        }
+        let memtable_end = end.map(Bound::Excluded).unwrap_or(Bound::Unbounded);
+        let mut sources: Vec<Box<dyn Iterator<Item=Entry> + Send + 'a>> = vec![Box::new(
+            self.memtable.range::<[u8], _>((Bound::Included(start), memtable_end))
+                .rev()
+                .map(|(k, v)| (k.clone(), v.clone()))
+        )];
+        for table in self.shared.current().levels.iter().flatten() {
+            let before_end = end.map(|end| table.min_key() < end).unwrap_or(true);
+            if table.max_key() >= start && before_end {
+                sources.push(Box::new(SsTableRevIter::seek(table.clone(), end)));
+            }
+        }
+        let start = start.to_vec();
+        Box::new(
+            MergeIter::new_rev(sources)
+                .take_while(move |(key, _)| key >= &start)
+                .filter_map(|(key, value)| value.map(|value| (key, value)))
+        )
+    }
+
+    /// only the memtable is copied, the sstables are immutable and kept alive by the snapshot
+    fn snapshot(&self) -> Snapshot {
+        let memtable: Vec<Entry> = self.memtable.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+        let mut sources: Vec<Box<dyn Iterator<Item=Entry> + Send>> = vec![Box::new(memtable.into_iter())];
+        for table in self.shared.current().levels.iter().flatten() {
+            sources.push(Box::new(SsTableIter::new(table.clone())));
+        }
+        Snapshot::new(MergeIter::new(sources).filter_map(|(key, value)| value.map(|value| (key, value))))
+    }
+
+    fn clear(&mut self) {
+        // wait for the running compactions, and keep new ones from starting until the tables are removed,
+        // or their outputs would bring the removed data back
+        let mut state = self.shared.compaction.lock().unwrap();
+        while state.is_busy() {
+            state = self.shared.compaction_cond.wait_timeout(state, Duration::from_millis(100)).unwrap().0;
+        }
+        let old_version = self.shared.current();
+        let installed = self.shared.install(|version| Version {
+            levels: vec![vec![]; version.levels.len()],
+        });
+        drop(state);
+        match installed {
+            Ok(()) => old_version.levels.iter().flatten().for_each(|it| it.mark_obsolete()),
+            Err(err) => error!("remove sstables failed: {:?}", err),
+        }
+        self.memtable.clear();
+        info!("lsm-tree cleared");
+    }
+
+    fn persist(&mut self) -> bool {
+        if self.memtable.len() < self.shared.options.memtable_size {
+            return false;
+        }
+        match self.flush() {
+            Ok(()) => true,
+            Err(err) => {
+                error!("flush memtable failed: {:?}", err);
+                false
+            }
+        }
+    }
+
+    fn stats(&self) -> Option<LsmStats> {
+        Some(compaction::stats(&self.shared))
+    }
+}
+
+#[test]
+fn test_lsm_store() {
+    use tempfile::tempdir;
+    use crate::store::mem_store::do_test;
+
+    let dir = tempdir().unwrap();
+    let mut store = LsmTree::open(dir.path(), LsmOptions::default()).unwrap();
+    do_test(&mut store);
+}
+
+#[test]
+fn test_lsm_flush_and_reopen() {
+    use tempfile::tempdir;
+
+    let dir = tempdir().unwrap();
+    let options = LsmOptions {
+        memtable_size: 16,
+        compaction_threads: 0,
+        ..LsmOptions::default()
+    };
+    let mut store = LsmTree::open(dir.path(), options).unwrap();
+    for i in 0..100u8 {
+        store.put(vec![i; 8], vec![i; 256]);
+        store.persist();
+    }
+    for i in (0..100u8).step_by(2) {
+        assert!(store.delete(&[i; 8]).unwrap().is_some());
+        store.persist();
+    }
+    store.put(vec![1; 8], vec![42; 256]);
+    assert!(store.flush().is_ok());
+    assert!(store.shared.current().levels[0].len() > 1);
+    drop(store);
+
+    let mut store = LsmTree::open(dir.path(), LsmOptions::default()).unwrap();
+    assert!(store.get(&[0; 8]).unwrap().is_none());
+    assert_eq!(store.get(&[1; 8]).unwrap().unwrap()[0], 42);
+    assert_eq!(store.get(&[99; 8]).unwrap().unwrap()[0], 99);
+    let keys: Vec<_> = store.range(&[], None).map(|(key, _)| key).collect();
+    assert_eq!(keys.len(), 50);
+    assert_eq!(keys[0], [1; 8]);
+    assert_eq!(keys[1], [3; 8]);
+    // merges the memtable and the sstables, the deleted keys are skipped
+    store.put(vec![2; 8], vec![2; 256]);
+    let pairs: Vec<_> = store.range(&[1; 8], Some(&[6; 8])).collect();
+    let keys: Vec<_> = pairs.iter().map(|(key, _)| key[0]).collect();
+    assert_eq!(keys, vec![1, 2, 3, 5]);
+    assert_eq!(pairs[0], (vec![1; 8], vec![42; 256]));
+    assert_eq!(store.range(&[97], None).count(), 2);
+    let pairs: Vec<_> = store.range_rev(&[1; 8], Some(&[6; 8])).collect();
+    let keys: Vec<_> = pairs.iter().map(|(key, _)| key[0]).collect();
+    assert_eq!(keys, vec![5, 3, 2, 1]);
+    assert_eq!(pairs[3], (vec![1; 8], vec![42; 256]));
+    let keys: Vec<_> = store.range_rev(&[], None).take(2).map(|(key, _)| key[0]).collect();
+    assert_eq!(keys, vec![99, 97]);
+}
+
+#[test]
+fn test_lsm_read_error() {
+    use std::fs::OpenOptions;
+    use tempfile::tempdir;
+
+    let dir = tempdir().unwrap();
+    let options = LsmOptions {
+        compaction_threads: 0,
+        ..LsmOptions::default()
+    };
+    let mut store = LsmTree::open(dir.path(), options).unwrap();
+    for i in 0..10u8 {
+        store.put(vec![i; 8], vec![i; 256]);
+    }
+    store.flush().unwrap();
+    let path = store.shared.current().levels[0][0].path().to_path_buf();
+    OpenOptions::new().write(true).open(&path).unwrap().set_len(16).unwrap();
+    // the key can't be told missing while its table can't be read
+    assert!(store.get(&[5; 8]).is_err());
+    assert!(store.delete(&[5; 8]).is_err());
+    // the tombstone is written anyway
+    assert!(store.get(&[5; 8]).unwrap().is_none());
+    assert!(store.get(&[6; 8]).is_err());
+}
+
+#[test]
+fn test_lsm_compaction() {
+    use tempfile::tempdir;
+    use std::time::{Duration, Instant};
+
+    let dir = tempdir().unwrap();
+    let options = LsmOptions {
+        memtable_size: 64,
+        level1_max_bytes: 64 * 1024,
+        level_size_multiplier: 2,
+        table_size: 16 * 1024,
+        compaction_threads: 2,
+        ..LsmOptions::default()
+    };
+    let mut store = LsmTree::open(dir.path(), options).unwrap();
+    for round in 0..8u8 {
+        for i in 0..=255u8 {
+            let key = [round % 2, 0, 0, 0, 0, 0, 0, i];
+            if round >= 6 && i % 2 == 0 {
+                store.delete(&key).unwrap();
+            } else {
+                store.put(key.to_vec(), vec![round; 256]);
+            }
+            store.persist();
+        }
+    }
+    store.flush().unwrap();
+    let start = Instant::now();
+    while compaction::stats(&store.shared).pending_compaction_bytes > 0 {
+        assert!(start.elapsed() < Duration::from_secs(10), "compaction takes too long");
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    let stats = compaction::stats(&store.shared);
+    assert!(stats.compactions > 0);
+    assert!(stats.write_amplification() > 1.0);
+    assert!(store.shared.current().levels[0].len() < 4);
+
+    assert!(store.get(&[0, 0, 0, 0, 0, 0, 0, 0]).unwrap().is_none());
+    assert_eq!(store.get(&[0, 0, 0, 0, 0, 0, 0, 1]).unwrap().unwrap()[0], 6);
+    assert_eq!(store.get(&[1, 0, 0, 0, 0, 0, 0, 1]).unwrap().unwrap()[0], 7);
+    assert_eq!(store.range(&[], None).count(), 256);
+    drop(store);
+
+    let table_files = fs::read_dir(dir.path()).unwrap()
+        .filter(|it| it.as_ref().unwrap().path().extension().map(|it| it == "sst").unwrap_or(false))
+        .count();
+    let store = LsmTree::open(dir.path(), LsmOptions::default()).unwrap();
+    assert_eq!(table_files, store.shared.current().levels.iter().flatten().count());
+    assert_eq!(store.get(&[1, 0, 0, 0, 0, 0, 0, 255]).unwrap().unwrap()[0], 7);
+}
+
+#[test]
+fn test_lsm_bloom_filter() {
+    use tempfile::tempdir;
+
+    let dir = tempdir().unwrap();
+    let options = LsmOptions {
+        memtable_size: 64,
+        compaction_threads: 0,
+        ..LsmOptions::default()
+    };
+    let mut store = LsmTree::open(dir.path(), options).unwrap();
+    for i in 0..=255u8 {
+        store.put(vec![0, 0, 0, 0, 0, 0, i, 0], vec![i; 256]);
+        store.persist();
+    }
+    for i in 0..=255u8 {
+        assert!(store.get(&[0, 0, 0, 0, 0, 0, i, 1]).unwrap().is_none());
+    }
+    let stats = compaction::stats(&store.shared);
+    assert!(stats.bloom_false_positives < 26);
+
+    let options = LsmOptions {
+        bloom_bits_per_key: 0,
+        compaction_threads: 0,
+        ..LsmOptions::default()
+    };
+    drop(store);
+    // the table without bloom filter can only tell a key is missing by reading the file
+    let mut store = LsmTree::open(dir.path(), options).unwrap();
+    store.put(vec![0, 0, 0, 0, 0, 0, 0, 0], vec![1; 256]);
+    store.put(vec![0, 0, 0, 0, 0, 0, 0, 2], vec![1; 256]);
+    store.flush().unwrap();
+    assert!(store.get(&[0, 0, 0, 0, 0, 0, 0, 1]).unwrap().is_none());
+    assert!(compaction::stats(&store.shared).bloom_false_positives >= 1);
+}
+
+#[test]
+fn test_lsm_snapshot() {
+    use tempfile::tempdir;
+
+    let dir = tempdir().unwrap();
+    let options = LsmOptions {
+        memtable_size: 16,
+        level0_compaction_trigger: 2,
+        ..LsmOptions::default()
+    };
+    let mut store = LsmTree::open(dir.path(), options).unwrap();
+    for i in 0..50u8 {
+        store.put(vec![i; 8], vec![i; 256]);
+        store.persist();
+    }
+    let snapshot = store.snapshot();
+    // the snapshot keeps reading the tables replaced by the flushes and compactions after it
+    for i in 0..50u8 {
+        store.delete(&[i; 8]).unwrap();
+        store.put(vec![i + 100; 8], vec![i; 256]);
+        store.persist();
+    }
+    assert!(store.flush().is_ok());
+    let entries: Vec<_> = snapshot.collect();
+    assert_eq!(entries.len(), 50);
+    assert!(entries.iter().enumerate().all(|(i, (key, value))| key[0] == i as u8 && value[0] == i as u8));
+}
+
+#[test]
+fn test_lsm_clear() {
+    use tempfile::tempdir;
+
+    let dir = tempdir().unwrap();
+    let options = LsmOptions {
+        memtable_size: 16,
+        level0_compaction_trigger: 2,
+        ..LsmOptions::default()
+    };
+    let mut store = LsmTree::open(dir.path(), options).unwrap();
+    for i in 0..100u8 {
+        store.put(vec![i; 8], vec![i; 256]);
+        store.persist();
+    }
+    store.clear();
+    assert!(store.get(&[1; 8]).unwrap().is_none());
+    assert_eq!(store.range(&[], None).count(), 0);
+    store.put(vec![200; 8], vec![200; 256]);
+    assert!(store.flush().is_ok());
+    drop(store);
+
+    let store = LsmTree::open(dir.path(), LsmOptions::default()).unwrap();
+    assert!(store.get(&[1; 8]).unwrap().is_none());
+    let keys: Vec<_> = store.range(&[], None).map(|(key, _)| key).collect();
+    assert_eq!(keys, vec![[200; 8]]);
+}
@@ -0,0 +1,499 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufWriter, Write};
+use std::os::unix::fs::FileExt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use crate::store::lsm::bloom::{BloomFilter, hash_key};
+
+/// magic number at the beginning of every sstable file
+const MAGIC: &[u8; 8] = b"MKVSST04";
+/// magic(8 bytes) + entry count(8 bytes)
+const HEADER_SIZE: u64 = 16;
+/// key length(4 bytes) + tag(1 byte) + value length(4 bytes)
+const ENTRY_HEADER_SIZE: u64 = 4 + 1 + 4;
+/// index offset(8 bytes) + bloom filter offset(8 bytes)
+const FOOTER_SIZE: u64 = 16;
+
+/// how many entries to read at once when iterating
+const ENTRIES_PER_READ: usize = 64;
+
+const TAG_TOMBSTONE: u8 = 0;
+const TAG_VALUE: u8 = 1;
+
+/// An entry in sstable or memtable
+/// `None` means the key is deleted
+pub type Entry = (Vec<u8>, Option<Vec<u8>>);
+
+/// size of `entry` in a sstable file
+pub fn entry_size(entry: &Entry) -> u64 {
+    ENTRY_HEADER_SIZE + entry.0.len() as u64 + entry.1.as_ref().map(|it| it.len() as u64).unwrap_or(0)
+}
+
+/// An immutable sorted table on disk
+/// The file is made up of header, entries, an index, a bloom filter over all the keys and a footer
+/// Each entry is `key length(4 bytes) + tag(1 byte) + value length(4 bytes) + key + value`,
+/// the index holds the offset of each entry in 8 bytes, and the footer holds the offsets of
+/// the index and the bloom filter
+/// The values begin with their versions since `MKVSST04`, see `store::versioned`
+/// The index and the bloom filter are kept in memory, so we can do binary search on the file directly,
+/// and skip reading the file for most missing keys
+/// The file is removed when the table is dropped after `mark_obsolete` is called,
+/// so readers still holding the table can keep reading it after it is compacted
+pub struct SsTable {
+    id: u64,
+    path: PathBuf,
+    file: File,
+    file_size: u64,
+    /// offsets of the entries, followed by the end of the last entry
+    offsets: Vec<u64>,
+    min_key: Vec<u8>,
+    max_key: Vec<u8>,
+    bloom_filter: BloomFilter,
+    obsolete: AtomicBool,
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn encode_entry(entry: &Entry, buffer: &mut Vec<u8>) {
+    buffer.extend_from_slice(&(entry.0.len() as u32).to_le_bytes());
+    match &entry.1 {
+        Some(value) => {
+            buffer.push(TAG_VALUE);
+            buffer.extend_from_slice(&(value.len() as u32).to_le_bytes());
+            buffer.extend_from_slice(&entry.0);
+            buffer.extend_from_slice(value);
+        }
+        None => {
+            buffer.push(TAG_TOMBSTONE);
+            buffer.extend_from_slice(&0u32.to_le_bytes());
+            buffer.extend_from_slice(&entry.0);
+        }
+    }
+}
+
+fn read_u32(buffer: &[u8]) -> u32 {
+    let mut bytes = [0u8; 4];
+    bytes.copy_from_slice(&buffer[..4]);
+    u32::from_le_bytes(bytes)
+}
+
+fn read_u64(buffer: &[u8]) -> u64 {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&buffer[..8]);
+    u64::from_le_bytes(bytes)
+}
+
+/// decode an entry which takes the whole `buffer`
+fn decode_entry(buffer: &[u8]) -> io::Result<Entry> {
+    if buffer.len() < ENTRY_HEADER_SIZE as usize {
+        return Err(invalid_data("truncated entry in sstable"));
+    }
+    let key_len = read_u32(buffer) as usize;
+    let value_len = read_u32(&buffer[5..]) as usize;
+    let body = &buffer[ENTRY_HEADER_SIZE as usize..];
+    if body.len() != key_len + value_len {
+        return Err(invalid_data("invalid entry length in sstable"));
+    }
+    let key = body[..key_len].to_vec();
+    match buffer[4] {
+        TAG_VALUE => Ok((key, Some(body[key_len..].to_vec()))),
+        TAG_TOMBSTONE => Ok((key, None)),
+        _ => Err(invalid_data("invalid entry tag in sstable")),
+    }
+}
+
+impl SsTable {
+    /// write `entries` into a new sstable file at `path`, with a bloom filter uses
+    /// about `bloom_bits_per_key` bits for each key
+    /// `entries` must be sorted by key and contain no duplicated keys
+    pub fn create<I: IntoIterator<Item=Entry>>(
+        id: u64,
+        path: &Path,
+        entries: I,
+        bloom_bits_per_key: usize,
+    ) -> io::Result<Self> {
+        let file = OpenOptions::new()
+            .create_new(true)
+            .write(true)
+            .open(path)?;
+        let mut writer = BufWriter::new(file);
+        writer.write_all(MAGIC)?;
+        writer.write_all(&0u64.to_le_bytes())?;
+        let mut offset = HEADER_SIZE;
+        let mut offsets = vec![];
+        let mut hashes = vec![];
+        let mut buffer = vec![];
+        for entry in entries {
+            buffer.clear();
+            encode_entry(&entry, &mut buffer);
+            writer.write_all(&buffer)?;
+            offsets.push(offset);
+            offset += buffer.len() as u64;
+            hashes.push(hash_key(&entry.0));
+        }
+        let index_offset = offset;
+        for entry_offset in &offsets {
+            writer.write_all(&entry_offset.to_le_bytes())?;
+        }
+        let bloom_filter_offset = index_offset + offsets.len() as u64 * 8;
+        writer.write_all(&BloomFilter::build(&hashes, bloom_bits_per_key).encode())?;
+        writer.write_all(&index_offset.to_le_bytes())?;
+        writer.write_all(&bloom_filter_offset.to_le_bytes())?;
+        let len = offsets.len() as u64;
+        let file = writer.into_inner().map_err(|e| e.into_error())?;
+        file.write_all_at(&len.to_le_bytes(), MAGIC.len() as u64)?;
+        file.sync_all()?;
+        Self::open(id, path)
+    }
+
+    /// open an existing sstable file
+    pub fn open(id: u64, path: &Path) -> io::Result<Self> {
+        let file = File::open(path)?;
+        let mut header = [0u8; HEADER_SIZE as usize];
+        file.read_exact_at(&mut header, 0)?;
+        if &header[..8] != MAGIC {
+            return Err(invalid_data("not a sstable file"));
+        }
+        let len = read_u64(&header[8..]);
+        if len == 0 {
+            return Err(invalid_data("empty sstable file"));
+        }
+        let file_size = file.metadata()?.len();
+        if file_size < HEADER_SIZE + FOOTER_SIZE {
+            return Err(invalid_data("sstable file is truncated"));
+        }
+        let mut footer = [0u8; FOOTER_SIZE as usize];
+        file.read_exact_at(&mut footer, file_size - FOOTER_SIZE)?;
+        let index_offset = read_u64(&footer);
+        let bloom_filter_offset = read_u64(&footer[8..]);
+        if index_offset < HEADER_SIZE
+            || bloom_filter_offset != index_offset + len * 8
+            || bloom_filter_offset >= file_size - FOOTER_SIZE {
+            return Err(invalid_data("invalid footer in sstable"));
+        }
+        let mut index = vec![0u8; (len * 8) as usize];
+        file.read_exact_at(&mut index, index_offset)?;
+        let mut offsets: Vec<u64> = index.chunks(8).map(read_u64).collect();
+        offsets.push(index_offset);
+        if offsets.windows(2).any(|it| it[0] + ENTRY_HEADER_SIZE > it[1]) {
+            return Err(invalid_data("invalid index in sstable"));
+        }
+        let mut bloom_filter = vec![0u8; (file_size - FOOTER_SIZE - bloom_filter_offset) as usize];
+        file.read_exact_at(&mut bloom_filter, bloom_filter_offset)?;
+        let bloom_filter = BloomFilter::decode(&bloom_filter)
+            .ok_or_else(|| invalid_data("invalid bloom filter in sstable"))?;
+        let mut table = Self {
+            id,
+            path: path.to_path_buf(),
+            file,
+            file_size,
+            offsets,
+            min_key: vec![],
+            max_key: vec![],
+            bloom_filter,
+            obsolete: AtomicBool::new(false),
+        };
+        table.min_key = table.entry_at(0)?.0;
+        table.max_key = table.entry_at(len - 1)?.0;
+        Ok(table)
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn len(&self) -> u64 {
+        self.offsets.len() as u64 - 1
+    }
+
+    /// size of the sstable file in bytes
+    pub fn file_size(&self) -> u64 {
+        self.file_size
+    }
+
+    pub fn min_key(&self) -> &[u8] {
+        &self.min_key
+    }
+
+    pub fn max_key(&self) -> &[u8] {
+        &self.max_key
+    }
+
+    /// whether the key range of this table overlaps with [`min_key`, `max_key`]
+    pub fn overlaps(&self, min_key: &[u8], max_key: &[u8]) -> bool {
+        self.min_key.as_slice() <= max_key && self.max_key.as_slice() >= min_key
+    }
+
+    /// remove the file when the table is dropped
+    pub fn mark_obsolete(&self) {
+        self.obsolete.store(true, Ordering::SeqCst);
+    }
+
+    /// read the entries in [`start`, `end`) in one read
+    fn read_entries(&self, start: usize, end: usize) -> io::Result<Vec<u8>> {
+        let mut buffer = vec![0u8; (self.offsets[end] - self.offsets[start]) as usize];
+        self.file.read_exact_at(&mut buffer, self.offsets[start])?;
+        Ok(buffer)
+    }
+
+    /// decode the entry at `index` from `buffer`, which holds the entries from `buffer_start`
+    fn decode_buffered(&self, buffer: &[u8], buffer_start: usize, index: usize) -> io::Result<Entry> {
+        let base = self.offsets[buffer_start];
+        let start = (self.offsets[index] - base) as usize;
+        let end = (self.offsets[index + 1] - base) as usize;
+        decode_entry(&buffer[start..end])
+    }
+
+    fn entry_at(&self, index: u64) -> io::Result<Entry> {
+        decode_entry(&self.read_entries(index as usize, index as usize + 1)?)
+    }
+
+    /// return `false` if `key` is definitely not in this table, without reading the file
+    pub fn may_contain(&self, key: &[u8]) -> bool {
+        key >= self.min_key.as_slice()
+            && key <= self.max_key.as_slice()
+            && self.bloom_filter.may_contain(hash_key(key))
+    }
+
+    /// index of the first entry which key is not less than `key`
+    fn lower_bound(&self, key: &[u8]) -> io::Result<u64> {
+        let (mut low, mut high) = (0, self.len());
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if self.entry_at(mid)?.0.as_slice() < key {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        Ok(low)
+    }
+
+    /// find `key` in this table
+    /// return `None` if the table knows nothing about the key,
+    /// `Some(None)` if the key is deleted
+    pub fn get(&self, key: &[u8]) -> io::Result<Option<Option<Vec<u8>>>> {
+        if !self.may_contain(key) {
+            return Ok(None);
+        }
+        let index = self.lower_bound(key)?;
+        if index == self.len() {
+            return Ok(None);
+        }
+        let (found, value) = self.entry_at(index)?;
+        Ok(if found.as_slice() == key { Some(value) } else { None })
+    }
+}
+
+impl Drop for SsTable {
+    fn drop(&mut self) {
+        if self.obsolete.load(Ordering::SeqCst) {
+            debug!("removing obsolete sstable {:?}", self.path);
+            fs::remove_file(&self.path).unwrap_or_else(|err| {
+                error!("remove sstable {:?} failed: {:?}", self.path, err)
+            });
+        }
+    }
+}
+
+/// Iterate over all entries in a table in key order
+pub struct SsTableIter {
+    table: Arc<SsTable>,
+    next: usize,
+    buffer: Vec<u8>,
+    /// index of the first entry in `buffer`
+    buffer_start: usize,
+    /// index of the entry after the last one in `buffer`
+    buffer_end: usize,
+}
+
+impl SsTableIter {
+    pub fn new(table: Arc<SsTable>) -> Self {
+        Self {
+            table,
+            next: 0,
+            buffer: vec![],
+            buffer_start: 0,
+            buffer_end: 0,
+        }
+    }
+
+    /// iterate over the entries which keys are not less than `key`
+    pub fn seek(table: Arc<SsTable>, key: &[u8]) -> Self {
+        let next = if key <= table.min_key() {
+            Ok(0)
+        } else {
+            table.lower_bound(key)
+        };
+        let next = next.unwrap_or_else(|err| {
+            error!("read sstable {:?} failed: {:?}", table.path, err);
+            table.len()
+        });
+        Self {
+            next: next as usize,
+            ..Self::new(table)
+        }
+    }
+
+    fn fill_buffer(&mut self) -> io::Result<()> {
+        let end = (self.next + ENTRIES_PER_READ).min(self.table.len() as usize);
+        self.buffer = self.table.read_entries(self.next, end)?;
+        self.buffer_start = self.next;
+        self.buffer_end = end;
+        Ok(())
+    }
+}
+
+impl Iterator for SsTableIter {
+    type Item = Entry;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.table.len() as usize;
+        if self.next >= len {
+            return None;
+        }
+        if self.next >= self.buffer_end {
+            if let Err(err) = self.fill_buffer() {
+                error!("read sstable {:?} failed: {:?}", self.table.path, err);
+                self.next = len;
+                return None;
+            }
+        }
+        let entry = self.table.decode_buffered(&self.buffer, self.buffer_start, self.next);
+        self.next += 1;
+        match entry {
+            Ok(entry) => Some(entry),
+            Err(err) => {
+                error!("read sstable {:?} failed: {:?}", self.table.path, err);
+                self.next = len;
+                None
+            }
+        }
+    }
+}
+
+/// Iterate over the entries in a table in reverse key order
+pub struct SsTableRevIter {
+    table: Arc<SsTable>,
+    /// the entries before this index are not returned yet
+    remaining: usize,
+    buffer: Vec<u8>,
+    /// index of the first entry in `buffer`
+    buffer_start: usize,
+}
+
+impl SsTableRevIter {
+    /// iterate over the entries which keys are less than `key`, or all the entries if `key` is `None`
+    pub fn seek(table: Arc<SsTable>, key: Option<&[u8]>) -> Self {
+        let remaining = match key {
+            Some(key) if key <= table.max_key() => table.lower_bound(key).unwrap_or_else(|err| {
+                error!("read sstable {:?} failed: {:?}", table.path, err);
+                0
+            }),
+            _ => table.len(),
+        };
+        Self {
+            table,
+            remaining: remaining as usize,
+            buffer: vec![],
+            buffer_start: remaining as usize,
+        }
+    }
+
+    fn fill_buffer(&mut self) -> io::Result<()> {
+        let start = self.remaining.saturating_sub(ENTRIES_PER_READ);
+        self.buffer = self.table.read_entries(start, self.remaining)?;
+        self.buffer_start = start;
+        Ok(())
+    }
+}
+
+impl Iterator for SsTableRevIter {
+    type Item = Entry;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        if self.remaining <= self.buffer_start {
+            if let Err(err) = self.fill_buffer() {
+                error!("read sstable {:?} failed: {:?}", self.table.path, err);
+                self.remaining = 0;
+                return None;
+            }
+        }
+        self.remaining -= 1;
+        match self.table.decode_buffered(&self.buffer, self.buffer_start, self.remaining) {
+            Ok(entry) => Some(entry),
+            Err(err) => {
+                error!("read sstable {:?} failed: {:?}", self.table.path, err);
+                self.remaining = 0;
+                None
+            }
+        }
+    }
+}
+
+#[test]
+fn test_sstable() {
+    use tempfile::tempdir;
+
+    let dir = tempdir().unwrap();
+    let entries: Vec<Entry> = (0u8..100)
+        .map(|i| (vec![0, i * 2], if i % 10 == 0 { None } else { Some(vec![i; i as usize]) }))
+        .collect();
+    let path = dir.path().join("000001.sst");
+    let table = SsTable::create(1, &path, entries.clone(), 10).unwrap();
+    assert_eq!(table.len(), 100);
+    assert_eq!(table.min_key, vec![0, 0]);
+    assert_eq!(table.get(&[0, 4]).unwrap().unwrap().unwrap(), vec![2u8; 2]);
+    assert!(table.get(&[0, 20]).unwrap().unwrap().is_none());
+    assert!(table.get(&[0, 5]).unwrap().is_none());
+    assert!(table.get(&[0]).unwrap().is_none());
+    assert!(table.get(&[1]).unwrap().is_none());
+    assert!(table.may_contain(&[0, 20]));
+    let false_positives = (0u8..100)
+        .filter(|i| table.may_contain(&[0, i * 2 + 1]))
+        .count();
+    assert!(false_positives < 10);
+
+    let table = SsTable::open(1, &path).unwrap();
+    assert_eq!(table.max_key, vec![0, 198]);
+    let table = Arc::new(table);
+    let read: Vec<_> = SsTableIter::new(table.clone()).collect();
+    assert_eq!(read.len(), entries.len());
+    assert_eq!(read, entries);
+    let read: Vec<_> = SsTableIter::seek(table.clone(), &[0, 21]).collect();
+    assert_eq!(read, entries[11..]);
+    assert_eq!(SsTableIter::seek(table.clone(), &[0]).count(), 100);
+    assert_eq!(SsTableIter::seek(table.clone(), &[1]).count(), 0);
+
+    let read: Vec<_> = SsTableRevIter::seek(table.clone(), None).collect();
+    assert!(read.iter().eq(entries.iter().rev()));
+    let read: Vec<_> = SsTableRevIter::seek(table.clone(), Some(&[0, 21])).collect();
+    assert!(read.iter().eq(entries[..11].iter().rev()));
+    assert_eq!(SsTableRevIter::seek(table, Some(&[0])).count(), 0);
+}
+
+#[test]
+fn test_obsolete_sstable() {
+    use tempfile::tempdir;
+
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("000001.sst");
+    let table = Arc::new(SsTable::create(1, &path, vec![(vec![0u8; 8], None)], 10).unwrap());
+    let mut iter = SsTableIter::new(table.clone());
+    table.mark_obsolete();
+    drop(table);
+    assert!(path.exists());
+    assert!(iter.next().is_some());
+    drop(iter);
+    assert!(!path.exists());
+}
@@ -1,85 +1,180 @@
 use std::collections::{HashMap, BTreeMap};
-use std::cmp::min;
+use std::io;
+use std::ops::Bound;
+use std::sync::Arc;
+use crate::store::lsm::LsmStats;
+use crate::store::snapshot::Snapshot;
 
 /// An abstraction of Key-Value storage in memory
-/// Works like `HashMap<[u8; 8], [u8; 256]>` with some other features like scan
-pub trait MemStore: Send {
+/// Works like `HashMap<Vec<u8>, Vec<u8>>` with some other features like range iteration
+/// The methods taking `&self` may be called by several threads at the same time,
+/// the ones taking `&mut self` are called with the store locked
+pub trait MemStore: Send + Sync {
     /// put a (key, value) pair into the storage
     /// replace the old pair with key if exists
-    fn put(&mut self, key: [u8; 8], value: [u8; 256]);
+    fn put(&mut self, key: Vec<u8>, value: Vec<u8>);
 
     /// get the value associated with key
-    /// return `None` if key not exists
-    // don't remove & here, need this to infer lifetime
-    fn get(&self, key: &[u8; 8]) -> Option<&[u8; 256]>;
+    /// return `None` if key not exists, or an error if the value can't be read, which doesn't mean it's not there
+    fn get(&self, key: &[u8]) -> io::Result<Option<Vec<u8>>>;
 
     /// delete the (`key`, `value`) pair
     /// return `None` if key not exists, else return the `value`
-    fn delete(&mut self, key: [u8; 8]) -> Option<[u8; 256]>;
+    /// the key is deleted even if an error is returned because the old value can't be read
+    fn delete(&mut self, key: &[u8]) -> io::Result<Option<Vec<u8>>>;
 
-    /// scan at most `count` keys start at cursor `at`
-    /// return the new cursor and the keys it scanned
-    fn scan(&self, at: usize, count: usize) -> (usize, Vec<[u8; 8]>);
+    /// iterate over the (key, value) pairs which keys are in [`start`, `end`) in key order,
+    /// `None` `end` means no upper bound
+    fn range<'a>(&'a self, start: &[u8], end: Option<&[u8]>) -> Box<dyn Iterator<Item=(Vec<u8>, Vec<u8>)> + 'a>;
+
+    /// iterate over the (key, value) pairs which keys are in [`start`, `end`) in reverse key order,
+    /// `None` `end` means no upper bound
+    fn range_rev<'a>(&'a self, start: &[u8], end: Option<&[u8]>) -> Box<dyn Iterator<Item=(Vec<u8>, Vec<u8>)> + 'a>;
+
+    /// take a point-in-time snapshot of all the (key, value) pairs
+    /// the snapshot doesn't borrow the store, so it can be written out after the store is unlocked
+    fn snapshot(&self) -> Snapshot;
+
+    /// remove all the (key, value) pairs
+    fn clear(&mut self);
+
+    /// persist the data in memory into disk if necessary
+    /// return `true` if all the data put before are persisted, so the redo log is no longer needed
+    fn persist(&mut self) -> bool {
+        false
+    }
+
+    /// statistics of the lsm-tree the data is stored in, `None` if it's not stored in one
+    fn stats(&self) -> Option<LsmStats> {
+        None
+    }
+
+    /// the store as a `ConcurrentMemStore` shared with others, which is read without locking it,
+    /// `None` if it's only read and written with the store locked
+    fn shared(&self) -> Option<Arc<dyn ConcurrentMemStore>> {
+        None
+    }
+}
+
+/// A `MemStore` which can also be written through a shared reference,
+/// so several threads can read and write it at the same time without locking it
+/// a read running with a write to the same key sees the value either before or after the write
+pub trait ConcurrentMemStore: MemStore {
+    /// put a (key, value) pair like `MemStore::put`
+    fn put_shared(&self, key: Vec<u8>, value: Vec<u8>);
+
+    /// delete the (`key`, `value`) pair like `MemStore::delete`
+    fn delete_shared(&self, key: &[u8]) -> Option<Vec<u8>>;
+
+    /// remove all the (key, value) pairs like `MemStore::clear`, a read running meanwhile may see some of them
+    fn clear_shared(&self) {
+        let keys: Vec<_> = self.range(b"", None).map(|(key, _)| key).collect();
+        for key in keys {
+            self.delete_shared(&key);
+        }
+    }
+}
+
+/// A `ConcurrentMemStore` shared by several owners, the writes through `&mut self` go to the shared store
+impl MemStore for Arc<dyn ConcurrentMemStore> {
+    fn put(&mut self, key: Vec<u8>, value: Vec<u8>) {
+        self.put_shared(key, value);
+    }
+
+    fn get(&self, key: &[u8]) -> io::Result<Option<Vec<u8>>> {
+        (**self).get(key)
+    }
+
+    fn delete(&mut self, key: &[u8]) -> io::Result<Option<Vec<u8>>> {
+        Ok(self.delete_shared(key))
+    }
+
+    fn range<'a>(&'a self, start: &[u8], end: Option<&[u8]>) -> Box<dyn Iterator<Item=(Vec<u8>, Vec<u8>)> + 'a> {
+        (**self).range(start, end)
+    }
+
+    fn range_rev<'a>(&'a self, start: &[u8], end: Option<&[u8]>) -> Box<dyn Iterator<Item=(Vec<u8>, Vec<u8>)> + 'a> {
+        (**self).range_rev(start, end)
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        (**self).snapshot()
+    }
+
+    fn clear(&mut self) {
+        self.clear_shared();
+    }
+
+    fn stats(&self) -> Option<LsmStats> {
+        (**self).stats()
+    }
+
+    fn shared(&self) -> Option<Arc<dyn ConcurrentMemStore>> {
+        Some(self.clone())
+    }
 }
 
-impl MemStore for HashMap<[u8; 8], [u8; 256]> {
-    fn put(&mut self, key: [u8; 8], value: [u8; 256]) {
+/// A `HashMap` has no order, so each `range` walks and sorts the whole map, which costs O(n log n) even for a short page
+impl MemStore for HashMap<Vec<u8>, Vec<u8>> {
+    fn put(&mut self, key: Vec<u8>, value: Vec<u8>) {
         self.insert(key, value);
     }
 
-    fn get(&self, key: &[u8; 8]) -> Option<&[u8; 256]> {
-        self.get(key)
+    fn get(&self, key: &[u8]) -> io::Result<Option<Vec<u8>>> {
+        Ok(self.get(key).cloned())
     }
 
-    fn delete(&mut self, key: [u8; 8]) -> Option<[u8; 256]> {
-        self.remove(&key)
+    fn delete(&mut self, key: &[u8]) -> io::Result<Option<Vec<u8>>> {
+        Ok(self.remove(key))
     }
 
-    fn scan(&self, at: usize, count: usize) -> (usize, Vec<[u8; 8]>) {
-        let iter = self.keys().skip(at);
-        let mut next_cursor = at + min(iter.clone().count(), count);
-        if next_cursor >= self.len() {
-            next_cursor = 0;
-        }
-        let result: Vec<_> = iter.take(count).cloned().collect();
-        (next_cursor, result)
+    fn range<'a>(&'a self, start: &[u8], end: Option<&[u8]>) -> Box<dyn Iterator<Item=(Vec<u8>, Vec<u8>)> + 'a> {
+        let mut result: Vec<_> = self.iter()
+            .filter(|(key, _)| key.as_slice() >= start && end.map(|end| key.as_slice() < end).unwrap_or(true))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+        result.sort();
+        Box::new(result.into_iter())
+    }
+
+    fn range_rev<'a>(&'a self, start: &[u8], end: Option<&[u8]>) -> Box<dyn Iterator<Item=(Vec<u8>, Vec<u8>)> + 'a> {
+        let result: Vec<_> = MemStore::range(self, start, end).collect();
+        Box::new(result.into_iter().rev())
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot::new(self.clone().into_iter())
+    }
+
+    fn clear(&mut self) {
+        self.clear();
     }
 }
 
 #[cfg(test)]
-fn do_test<T: MemStore>(store: &mut T) {
-    use mini_kv::shared::bytes::copy_bytes;
-    use mini_kv::shared::bytes::bytes_equal;
-    let key = *b"00000001";
-    let mut value = [0u8; 256];
-    copy_bytes(b"a", &mut value);
-    store.put(key, value);
-    let key = *b"00000002";
-    let mut value = [0u8; 256];
-    copy_bytes(b"b", &mut value);
-    store.put(key, value);
-    let key = *b"00000001";
-    let mut value = [0u8; 256];
-    copy_bytes(b"c", &mut value);
-    store.put(key, value);
-
-    let key = *b"00000002";
-    let mut value = [0u8; 256];
-    copy_bytes(b"b", &mut value);
-    assert!(bytes_equal(store.get(&key).unwrap(), &value));
-    let key = *b"00000001";
-    let mut value = [0u8; 256];
-    copy_bytes(b"c", &mut value);
-    assert!(bytes_equal(store.get(&key).unwrap(), &value));
-    let scan_result = store.scan(0, 16);
-    assert_eq!(scan_result.0, 0);
-    assert_eq!(scan_result.1.len(), 2);
-
-    store.delete(key);
-    assert!(store.get(&key).is_none());
-    let scan_result = store.scan(0, 16);
-    assert_eq!(scan_result.0, 0);
-    assert_eq!(scan_result.1.len(), 1);
+pub fn do_test<T: MemStore>(store: &mut T) {
+    store.put(b"1".to_vec(), b"a".to_vec());
+    store.put(b"00000002".to_vec(), b"b".to_vec());
+    store.put(b"1".to_vec(), vec![b'c'; 1024]);
+    // the empty key and the empty value are valid too
+    store.put(vec![], vec![]);
+
+    assert_eq!(store.get(b"00000002").unwrap().unwrap(), b"b".to_vec());
+    assert_eq!(store.get(b"1").unwrap().unwrap(), vec![b'c'; 1024]);
+    assert_eq!(store.get(b"").unwrap().unwrap(), vec![]);
+    assert!(store.get(b"10").unwrap().is_none());
+    let keys: Vec<_> = store.range(b"", None).map(|(key, _)| key).collect();
+    assert_eq!(keys, vec![b"".to_vec(), b"00000002".to_vec(), b"1".to_vec()]);
+    let pairs: Vec<_> = store.range(b"0", Some(b"1")).collect();
+    assert_eq!(pairs, vec![(b"00000002".to_vec(), b"b".to_vec())]);
+    assert_eq!(store.range(b"1", Some(b"0")).count(), 0);
+    let keys: Vec<_> = store.range_rev(b"", Some(b"1")).map(|(key, _)| key).collect();
+    assert_eq!(keys, vec![b"00000002".to_vec(), b"".to_vec()]);
+
+    store.delete(b"1").unwrap();
+    assert!(store.get(b"1").unwrap().is_none());
+    store.delete(b"").unwrap();
+    assert_eq!(store.range(b"", None).count(), 1);
 }
 
 #[test]
@@ -89,29 +184,53 @@ fn test_hashmap_store() {
 }
 
 // it's sad that Rust doesn't provide an abstraction over HashMap and BTreeMap
-impl MemStore for BTreeMap<[u8; 8], [u8; 256]> {
-    fn put(&mut self, key: [u8; 8], value: [u8; 256]) {
+impl MemStore for BTreeMap<Vec<u8>, Vec<u8>> {
+    fn put(&mut self, key: Vec<u8>, value: Vec<u8>) {
         self.insert(key, value);
     }
 
-    fn get(&self, key: &[u8; 8]) -> Option<&[u8; 256]> {
-        self.get(key)
+    fn get(&self, key: &[u8]) -> io::Result<Option<Vec<u8>>> {
+        Ok(self.get(key).cloned())
+    }
+
+    fn delete(&mut self, key: &[u8]) -> io::Result<Option<Vec<u8>>> {
+        Ok(self.remove(key))
+    }
+
+    fn range<'a>(&'a self, start: &[u8], end: Option<&[u8]>) -> Box<dyn Iterator<Item=(Vec<u8>, Vec<u8>)> + 'a> {
+        if end.map(|end| end <= start).unwrap_or(false) {
+            return Box::new(std::iter::empty());
+        }
+        let end = end.map(Bound::Excluded).unwrap_or(Bound::Unbounded);
+        Box::new(
+            self.range::<[u8], _>((Bound::Included(start), end))
+                .map(|(key, value)| (key.clone(), value.clone()))
+        )
+    }
+
+    fn range_rev<'a>(&'a self, start: &[u8], end: Option<&[u8]>) -> Box<dyn Iterator<Item=(Vec<u8>, Vec<u8>)> + 'a> {
+        if end.map(|end| end <= start).unwrap_or(false) {
+            return Box::new(std::iter::empty());
+        }
+        let end = end.map(Bound::Excluded).unwrap_or(Bound::Unbounded);
+        Box::new(
+            self.range::<[u8], _>((Bound::Included(start), end))
+                .rev()
+                .map(|(key, value)| (key.clone(), value.clone()))
+        )
     }
 
-    fn delete(&mut self, key: [u8; 8]) -> Option<[u8; 256]> {
-        self.remove(&key)
+    fn snapshot(&self) -> Snapshot {
+        Snapshot::new(self.clone().into_iter())
     }
 
-    fn scan(&self, at: usize, count: usize) -> (usize, Vec<[u8; 8]>) {
-        let iter = self.keys().skip(at);
-        let next_cursor = at + min(iter.clone().count(), count);
-        let result: Vec<_> = iter.take(count).cloned().collect();
-        (next_cursor, result)
+    fn clear(&mut self) {
+        self.clear();
     }
 }
 
 #[test]
 fn test_btreemap_store() {
-    let mut store = HashMap::new();
+    let mut store = BTreeMap::new();
     do_test(&mut store);
 }
\ No newline at end of file
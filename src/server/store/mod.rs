@@ -1,12 +1,155 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::io;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread;
+use std::time::Duration;
+use crate::store::condition::{Condition, Checked};
+use crate::store::cursor::CursorTable;
+use crate::store::lsm::LsmStats;
 use crate::store::mem_store::MemStore;
-use crate::store::redo_log::RedoLog;
+use crate::store::mvcc::{History, SnapshotIter};
+use crate::store::oracle::{TimestampOracle, VERSION_LIMIT};
+use crate::store::partition::{Partition, Partitions, ReadGuard, WriteGuard, MergedIter, partition_of};
+use crate::store::redo_log::{RedoLog, Durable, durable_now};
+use crate::store::snapshot::Snapshot;
+use crate::store::txn::{Txn, TxnError};
+use crate::store::versioned::{Versioned, now_millis};
+use crate::store::write_batch::{WriteBatch, WriteOp};
 
-mod mem_store;
-mod redo_log;
+pub mod lsm;
+pub mod condition;
+mod cursor;
+pub mod mem_store;
+pub mod mvcc;
+mod partition;
+pub mod oracle;
+pub mod percolator;
+mod group_commit;
+pub mod redo_log;
+pub mod skiplist;
+pub mod snapshot;
+pub mod txn;
+pub mod versioned;
+pub mod write_batch;
 
-pub struct Store {
-    mem_store: Box<dyn MemStore>,
+/// max size of a key and its value (with the version) together, the limits of requests must fit in it
+/// the `encoded_size` of a write batch is limited by it too
+pub const MAX_ENTRY_SIZE: usize = 64 * 1024 * 1024;
+
+/// how many scan cursors are remembered
+const MAX_CURSORS: usize = 4096;
+
+/// the timestamp to read the latest values at without opening a snapshot
+pub const LATEST: u64 = u64::MAX;
+
+/// how long a snapshot is kept after the last read from it by default
+pub const DEFAULT_SNAPSHOT_LEASE: Duration = Duration::from_secs(10);
+
+/// the smallest key larger than all the keys starting with `prefix`,
+/// `None` if there's no such key, like when `prefix` is empty or all `0xff`
+pub fn prefix_end(prefix: &[u8]) -> Option<Vec<u8>> {
+    let mut end = prefix.to_vec();
+    while let Some(last) = end.pop() {
+        if last != 0xff {
+            end.push(last + 1);
+            return Some(end);
+        }
+    }
+    None
+}
+
+/// (key, value) pairs
+pub type Pairs = Vec<(Vec<u8>, Vec<u8>)>;
+
+/// the (key, value) pairs in a page of a scan, and the key to get the next page with
+pub type Page = (Pairs, Option<Vec<u8>>);
+
+/// take the versions off the values in `pairs`, and skip the expired ones
+fn live_pairs<'a>(pairs: Box<dyn Iterator<Item=(Vec<u8>, Vec<u8>)> + 'a>) -> impl Iterator<Item=(Vec<u8>, Vec<u8>)> + 'a {
+    let now = now_millis();
+    pairs.filter_map(move |(key, value)| {
+        let value = Versioned::decode(value);
+        if value.is_expired(now) {
+            None
+        } else {
+            Some((key, value.value))
+        }
+    })
+}
+
+/// the deadline of a value which expires after `ttl`
+fn deadline(ttl: Duration) -> u64 {
+    now_millis().saturating_add(ttl.as_millis() as u64)
+}
+
+/// the partitions locked for a write by their indexes
+type Locked<'a> = BTreeMap<usize, WriteGuard<'a>>;
+
+/// the partitions locked for reading, all of them in the order of their indexes
+type ReadLocked<'a> = Vec<ReadGuard<'a>>;
+
+/// a point-in-time snapshot of all the `partitions`
+fn snapshot_of(partitions: &ReadLocked) -> Snapshot {
+    let snapshots: Vec<_> = partitions.iter().map(|it| it.snapshot()).collect();
+    Snapshot::new(snapshots.into_iter().flatten())
+}
+
+/// The versions given to the writes
+struct Clock {
+    /// gives the versions, and saves their limit next to the redo log
+    oracle: TimestampOracle,
+    /// the versions given to the writes which are not applied yet
+    pending: BTreeSet<u64>,
+}
+
+/// A version given to a write, it's published once it's dropped after the write is applied or given up
+struct Pending<'a> {
+    store: &'a Store,
+    version: u64,
+}
+
+impl Drop for Pending<'_> {
+    fn drop(&mut self) {
+        self.store.publish(self.version);
+    }
+}
+
+/// The redo log shared by all the partitions, and what it must keep for them
+struct LogState {
     redo_log: Box<dyn RedoLog>,
+    /// the position of the oldest write each partition hasn't persisted, `None` if it has persisted everything
+    dirty_since: Vec<Option<u64>>,
+}
+
+impl LogState {
+    /// remove the logs of the writes all the partitions have persisted
+    fn clear(&mut self) {
+        let position = match self.dirty_since.iter().flatten().min() {
+            Some(position) => *position,
+            None => self.redo_log.position(),
+        };
+        self.redo_log.clear_before(position);
+    }
+}
+
+pub struct Store {
+    /// the keys are spread over the partitions by `partition::partition_of`, each partition is locked on its own,
+    /// so the writes to different partitions run in parallel
+    /// the partitions are always locked in the order of their indexes, and before the other locks,
+    /// the partitions on `ConcurrentMemStore`s are read without locking them
+    partitions: Vec<Partition>,
+    /// a write is logged while its partitions are locked, so the logs of a key are in the order it's written
+    log: Mutex<LogState>,
+    cursors: Mutex<CursorTable>,
+    clock: Mutex<Clock>,
+    /// all the writes of the versions until this are applied, it's read without locking,
+    /// so the snapshots are opened without waiting for the writers
+    published: AtomicU64,
+    /// the values replaced while snapshots are open
+    history: Mutex<History>,
+    /// the key the next `sweep` starts from
+    sweep_from: Mutex<Vec<u8>>,
 }
 
 impl Store {
@@ -14,31 +157,1225 @@ impl Store {
         mem_store: S,
         redo_log: L,
     ) -> Self {
-        let mut mem_store = mem_store;
+        Self::with_partitions(vec![Box::new(mem_store)], redo_log)
+    }
+
+    /// create a store with its keys spread over `partitions`,
+    /// which must be given in the same order and count every time the store is opened
+    pub fn with_partitions<L: 'static + RedoLog>(partitions: Vec<Box<dyn MemStore>>, redo_log: L) -> Self {
+        let mut partitions = Partitions::new(partitions);
         let mut redo_log = redo_log;
-        // todo: remove this when impl based on lsm-tree is ready
-        redo_log.redo(&mut mem_store);
+        // only the logs written after the last time each partition persisted its data are replayed
+        redo_log.redo(&mut partitions);
+        // the partitions written by the replay need all the logs until they persist their data
+        let dirty_since = partitions.written.iter()
+            .map(|written| Some(0).filter(|_| *written))
+            .collect();
+        // the new versions must be larger than the ones stored before, even if the wall clock went back since they were given,
+        // the versions given are below the limit saved with the redo log, including the ones just redone,
+        // the stored versions are only scanned if no limit is saved, like for the data written by an older version
+        let last_stored = || {
+            let mut last = 0;
+            let mut count = 0;
+            for partition in partitions.stores.iter() {
+                for (_, value) in partition.range(b"", None) {
+                    last = last.max(Versioned::version_of(&value));
+                    count += 1;
+                }
+            }
+            info!("the last version of {} pairs is {}", count, last);
+            last
+        };
+        let clock = match redo_log.dir() {
+            Some(dir) => TimestampOracle::open_file(dir, VERSION_LIMIT, last_stored),
+            None => TimestampOracle::unsaved(last_stored()),
+        };
+        let mut clock = clock.unwrap_or_else(|err| panic!("Cannot open revision clock: {:?}", err));
+        // the values replaced before a restart are not kept, so the snapshots can only start from now,
+        // or from the last version if no version is left, which still serves the reads
+        let now = clock.next().unwrap_or_else(|_| clock.last());
         Self {
-            mem_store: Box::new(mem_store),
-            redo_log: Box::new(redo_log),
+            partitions: partitions.stores.into_iter().map(Partition::new).collect(),
+            log: Mutex::new(LogState { redo_log: Box::new(redo_log), dirty_since }),
+            cursors: Mutex::new(CursorTable::new(MAX_CURSORS)),
+            clock: Mutex::new(Clock { oracle: clock, pending: BTreeSet::new() }),
+            published: AtomicU64::new(now),
+            history: Mutex::new(History::new(now, DEFAULT_SNAPSHOT_LEASE)),
+            sweep_from: Mutex::new(vec![]),
+        }
+    }
+
+    /// keep the snapshots for `lease` after the last read from them
+    pub fn set_snapshot_lease(&mut self, lease: Duration) {
+        self.history.get_mut().unwrap().set_lease(lease);
+    }
+
+    /// open the snapshot at `read_ts`, or at the latest version if it's 0, and pin it until it's released
+    /// return the timestamp of the snapshot, or why it can't be read
+    pub fn open_snapshot(&self, read_ts: u64) -> Result<u64, String> {
+        if read_ts > self.clock.lock().unwrap().oracle.last() {
+            return Err(format!("read timestamp {} is in the future", read_ts));
+        }
+        let ts = {
+            let mut history = self.history.lock().unwrap();
+            // the writes of the versions after it are kept in the history once the snapshot is opened
+            if read_ts == 0 {
+                history.open_latest(self.published.load(Ordering::SeqCst))
+            } else if history.open(read_ts) {
+                read_ts
+            } else {
+                return Err(format!("snapshot at {} is too old", read_ts));
+            }
+        };
+        // the writes of the versions until it may be still being applied, which only takes a moment after they are given the versions,
+        // the writers holding the partitions before that are not waited for
+        while self.published.load(Ordering::SeqCst) < ts {
+            thread::yield_now();
+        }
+        Ok(ts)
+    }
+    /// the version until which all the writes are applied, the reads at the latest version see all of them
+    pub fn published(&self) -> u64 {
+        self.published.load(Ordering::SeqCst)
+    }
+    /// unpin the snapshot at `ts`, it can be read again until its lease ends if `lease` is `true`,
+    /// otherwise it's dropped once no one else reads from it
+    pub fn release_snapshot(&self, ts: u64, lease: bool) {
+        self.history.lock().unwrap().release(ts, lease);
+    }
+    /// call `read` with the snapshot at `read_ts` opened like `open_snapshot`,
+    /// return the timestamp of the snapshot and the result of `read`
+    /// the snapshot is only leased after the read if `read_ts` is given,
+    /// a read at the latest version doesn't keep the values it replaced once it's done
+    pub fn read_at<T, F: FnOnce(&Self, u64) -> T>(&self, read_ts: u64, read: F) -> Result<(u64, T), String> {
+        self.read_and_lease(read_ts, |store, ts| (read(store, ts), read_ts != 0))
+    }
+    /// call `read` like `read_at`, which also returns whether the snapshot is read again later,
+    /// like when a page is followed by a cursor, then the snapshot is leased after the read
+    pub fn read_and_lease<T, F: FnOnce(&Self, u64) -> (T, bool)>(&self, read_ts: u64, read: F) -> Result<(u64, T), String> {
+        let ts = self.open_snapshot(read_ts)?;
+        let (result, lease) = read(self, ts);
+        self.release_snapshot(ts, lease);
+        Ok((ts, result))
+    }
+
+    /// the index of the partition `key` is in
+    fn partition_of(&self, key: &[u8]) -> usize {
+        partition_of(key, self.partitions.len())
+    }
+    /// lock the partitions of `keys` for writing
+    fn lock(&self, keys: &[&[u8]]) -> Locked<'_> {
+        let indexes: BTreeSet<_> = keys.iter().map(|key| self.partition_of(key)).collect();
+        indexes.into_iter().map(|i| (i, self.partitions[i].write())).collect()
+    }
+    /// lock all the partitions for reading
+    fn read_all(&self) -> ReadLocked<'_> {
+        self.partitions.iter().map(Partition::read).collect()
+    }
+    /// the value of `key` at the snapshot `ts` in `partition`, which is locked by the caller unless it's shared
+    /// return an error if the partition can't read the value, it's not taken as missing
+    fn read(&self, partition: &dyn MemStore, key: &[u8], ts: u64) -> io::Result<Option<Versioned>> {
+        // a write keeps the value it replaces before applying, so the value read is kept if it's replaced after it
+        let current = partition.get(key);
+        let kept = if ts == LATEST { None } else { self.history.lock().unwrap().get(key, ts) };
+        let value = match kept {
+            Some(kept) => kept,
+            None => current?,
+        };
+        Ok(value.map(Versioned::decode).filter(|it| !it.is_expired(now_millis())))
+    }
+    /// the value of `key` at the snapshot `ts`, its partition is in `locked`
+    fn read_locked(&self, locked: &Locked, key: &[u8], ts: u64) -> io::Result<Option<Versioned>> {
+        self.read(&*locked[&self.partition_of(key)], key, ts)
+    }
+
+    /// give the version of a write, which is published when the returned `Pending` is dropped after the write is applied
+    fn next_version(&self) -> io::Result<Pending<'_>> {
+        let mut clock = self.clock.lock().unwrap();
+        let version = clock.oracle.next()?;
+        clock.pending.insert(version);
+        Ok(Pending { store: self, version })
+    }
+    /// publish the versions until the oldest one still given to a write being applied
+    fn publish(&self, version: u64) {
+        let mut clock = self.clock.lock().unwrap();
+        clock.pending.remove(&version);
+        let published = match clock.pending.iter().next() {
+            Some(oldest) => oldest - 1,
+            None => clock.oracle.last(),
+        };
+        // it only grows, as it's stored with the clock locked
+        self.published.store(published, Ordering::SeqCst);
+    }
+
+    /// return an error once a log has failed to be written, the writes in it are still in memory but gone after a restart,
+    /// so no more writes are taken on top of them
+    fn check_log(&self) -> io::Result<()> {
+        if self.log.lock().unwrap().redo_log.failed() {
+            Err(io::Error::new(io::ErrorKind::PermissionDenied, "the redo log failed to be written, the store is read-only until it's restarted"))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// apply `batch` on the partitions in `locked`, which must be all the partitions of its keys
+    /// the values replaced are kept at `ts` for the open snapshots, unless `ts` is `None`
+    /// the returned `Durable` resolves when the batch is persisted
+    /// nothing is written if the values to keep can't be read, or the redo log has failed
+    fn apply(&self, mut locked: Locked, batch: WriteBatch, ts: Option<u64>) -> io::Result<Durable> {
+        self.check_log()?;
+        if let Some(ts) = ts {
+            let mut history = self.history.lock().unwrap();
+            if history.keeps(ts) {
+                let replaced = batch.ops().iter()
+                    .map(|op| locked[&self.partition_of(op.key())].get(op.key()))
+                    .collect::<io::Result<Vec<_>>>()?;
+                for (op, value) in batch.ops().iter().zip(replaced) {
+                    history.push(op.key().to_vec(), ts, value);
+                }
+            }
+        }
+        let durable = {
+            let mut guard = self.log.lock().unwrap();
+            let log = &mut *guard;
+            let position = log.redo_log.position();
+            for i in locked.keys() {
+                log.dirty_since[*i].get_or_insert(position);
+            }
+            match batch.ops() {
+                [WriteOp::Put(key, value)] => log.redo_log.log_put(key, value),
+                [WriteOp::Delete(key)] => log.redo_log.log_delete(key),
+                _ => log.redo_log.log_batch(&batch),
+            }
+            log.redo_log.durable()
+        };
+        for op in batch.into_ops() {
+            let partition = locked.get_mut(&self.partition_of(op.key())).unwrap();
+            match op {
+                WriteOp::Put(key, value) => partition.put(key, value),
+                WriteOp::Delete(key) => {
+                    // the key is deleted even if the value it had can't be read
+                    let _ = partition.delete(&key);
+                }
+            }
+        }
+        self.persist(locked);
+        Ok(durable)
+    }
+
+    /// return the version of the value put
+    /// the returned `Durable` resolves when the put is persisted
+    pub fn put(&self, key: Vec<u8>, value: Vec<u8>) -> io::Result<(u64, Durable)> {
+        self.put_with_ttl(key, value, None)
+    }
+    /// put a value which expires after `ttl`, or never expires if it's `None`
+    /// return the version of the value put
+    /// the returned `Durable` resolves when the put is persisted
+    pub fn put_with_ttl(&self, key: Vec<u8>, value: Vec<u8>, ttl: Option<Duration>) -> io::Result<(u64, Durable)> {
+        let locked = self.lock(&[&key]);
+        self.put_locked(locked, key, value, ttl)
+    }
+    /// put like `put_with_ttl` with the partition of `key` locked
+    fn put_locked(&self, locked: Locked, key: Vec<u8>, value: Vec<u8>, ttl: Option<Duration>) -> io::Result<(u64, Durable)> {
+        let pending = self.next_version()?;
+        let version = pending.version;
+        let expire_at = ttl.map(deadline);
+        Ok((version, self.write_versioned(locked, key, Versioned { version, expire_at, value }, version)?))
+    }
+    /// `ts` is when the current value is replaced, which is the version of the new value unless only the deadline changes
+    fn write_versioned(&self, locked: Locked, key: Vec<u8>, versioned: Versioned, ts: u64) -> io::Result<Durable> {
+        let mut batch = WriteBatch::new();
+        batch.put(key, versioned.encode());
+        self.apply(locked, batch, Some(ts))
+    }
+    /// get the latest value of `key`, the expired values are invisible
+    pub fn get(&self, key: &[u8]) -> io::Result<Option<Versioned>> {
+        self.get_at(key, LATEST)
+    }
+    /// get the value of `key` at the snapshot `ts`
+    pub fn get_at(&self, key: &[u8], ts: u64) -> io::Result<Option<Versioned>> {
+        // a write is not half done while its partition is locked, a shared partition has the key written or not
+        self.partitions[self.partition_of(key)].with_store(|partition| self.read(partition, key, ts))
+    }
+    /// the returned `Durable` resolves when the delete is persisted
+    pub fn delete(&self, key: &[u8]) -> io::Result<(Option<Versioned>, Durable)> {
+        let locked = self.lock(&[key]);
+        self.delete_locked(locked, key)
+    }
+    /// delete like `delete` with the partition of `key` locked
+    fn delete_locked(&self, locked: Locked, key: &[u8]) -> io::Result<(Option<Versioned>, Durable)> {
+        match locked[&self.partition_of(key)].get(key)? {
+            Some(removed) => {
+                let pending = self.next_version()?;
+                let mut batch = WriteBatch::new();
+                batch.delete(key.to_vec());
+                let durable = self.apply(locked, batch, Some(pending.version))?;
+                let removed = Versioned::decode(removed);
+                // an expired value is deleted as well, but it's not there from the view of users
+                Ok((Some(removed).filter(|it| !it.is_expired(now_millis())), durable))
+            }
+            None => Ok((None, durable_now())),
+        }
+    }
+    /// put `value` if `condition` holds on the current value of `key`, checked and written atomically
+    /// the value expires after `ttl` if it's not `None`
+    /// the returned `Durable` resolves when the put is persisted
+    /// return the version of the value put if the condition held
+    pub fn put_if(&self, key: Vec<u8>, value: Vec<u8>, ttl: Option<Duration>, condition: &Condition) -> io::Result<(Checked<u64>, Durable)> {
+        let locked = self.lock(&[&key]);
+        let current = self.read_locked(&locked, &key, LATEST)?;
+        if condition.holds(current.as_ref()) {
+            let (version, durable) = self.put_locked(locked, key, value, ttl)?;
+            Ok((Ok(version), durable))
+        } else {
+            Ok((Err(current), durable_now()))
+        }
+    }
+    /// delete `key` if `condition` holds on its current value, checked and written atomically
+    /// return the value deleted if the condition held
+    /// the returned `Durable` resolves when the delete is persisted
+    pub fn delete_if(&self, key: &[u8], condition: &Condition) -> io::Result<(Checked<Option<Versioned>>, Durable)> {
+        let locked = self.lock(&[key]);
+        let current = self.read_locked(&locked, key, LATEST)?;
+        if condition.holds(current.as_ref()) {
+            let (removed, durable) = self.delete_locked(locked, key)?;
+            Ok((Ok(removed), durable))
+        } else {
+            Ok((Err(current), durable_now()))
+        }
+    }
+    /// make `key` expire after `ttl`, or never expire if it's `None`, the version of the value is kept
+    /// return `false` if the key doesn't exist
+    /// the returned `Durable` resolves when the new deadline is persisted
+    pub fn expire(&self, key: &[u8], ttl: Option<Duration>) -> io::Result<(bool, Durable)> {
+        let locked = self.lock(&[key]);
+        match self.read_locked(&locked, key, LATEST)? {
+            Some(mut versioned) => {
+                versioned.expire_at = ttl.map(deadline);
+                let pending = self.next_version()?;
+                Ok((true, self.write_versioned(locked, key.to_vec(), versioned, pending.version)?))
+            }
+            None => Ok((false, durable_now())),
+        }
+    }
+    /// the time left before `key` expires, `Some(None)` if it never expires, or `None` if it doesn't exist
+    pub fn ttl(&self, key: &[u8]) -> io::Result<Option<Option<Duration>>> {
+        Ok(self.get(key)?.map(|versioned| {
+            versioned.expire_at.map(|expire_at| Duration::from_millis(expire_at.saturating_sub(now_millis())))
+        }))
+    }
+    /// walk at most `count` keys from where the last sweep stopped, and delete the expired ones,
+    /// start from the first key again after reaching the last one
+    /// return how many keys are deleted
+    pub fn sweep(&self, count: usize) -> io::Result<usize> {
+        let mut sweep_from = self.sweep_from.lock().unwrap();
+        let now = now_millis();
+        let mut expired = vec![];
+        let partitions = self.read_all();
+        let mut iter = MergedIter::new(partitions.iter().map(|it| it.range(&sweep_from, None)).collect(), false);
+        for (key, value) in iter.by_ref().take(count) {
+            if Versioned::decode(value).is_expired(now) {
+                expired.push(key);
+            }
+        }
+        let next = iter.next().map(|(key, _)| key).unwrap_or_default();
+        drop(iter);
+        drop(partitions);
+        *sweep_from = next;
+        let keys: Vec<_> = expired.iter().map(|it| it.as_slice()).collect();
+        let locked = self.lock(&keys);
+        let mut batch = WriteBatch::new();
+        // the keys may be written again before they are locked
+        for key in expired {
+            let value = locked[&self.partition_of(&key)].get(&key)?;
+            if value.map(|it| Versioned::decode(it).is_expired(now)).unwrap_or(false) {
+                batch.delete(key);
+            }
+        }
+        let deleted = batch.ops().len();
+        // the expired values are invisible to all the snapshots, so they are not kept
+        if deleted != 0 {
+            let _ = self.apply(locked, batch, None)?;
+        }
+        Ok(deleted)
+    }
+    /// the key a scan continues from at `cursor` of the `Scan` API, 0 to scan from the start
+    /// return `None` if the cursor is unknown
+    pub fn cursor(&self, cursor: u64) -> Option<Vec<u8>> {
+        if cursor == 0 {
+            Some(vec![])
+        } else {
+            self.cursors.lock().unwrap().get(cursor).map(|it| it.to_vec())
+        }
+    }
+    /// a new cursor of the `Scan` API to continue from `key`
+    pub fn new_cursor(&self, key: Vec<u8>) -> u64 {
+        self.cursors.lock().unwrap().insert(key)
+    }
+    /// return at most `limit` (key, value) pairs at the snapshot `ts` which keys are in [`start`, `end`) in key order,
+    /// and the key to continue from if there are more pairs in the range
+    /// if `reverse`, the pairs are in reverse key order, and the key returned is the `end` for the next page
+    pub fn range_scan(&self, start: &[u8], end: Option<&[u8]>, limit: usize, reverse: bool, ts: u64) -> Page {
+        // the first pairs of each partition are copied out with only that partition locked, or none if it's shared,
+        // one more pair to tell if there's a next page
+        let chunks = self.partitions.iter()
+            .enumerate()
+            .map(|(i, partition)| {
+                let chunk = partition.with_store(|partition| {
+                    self.scan_partition(i, partition, start, end, limit.saturating_add(1), reverse, ts)
+                });
+                Box::new(chunk.into_iter()) as Box<dyn Iterator<Item=(Vec<u8>, Vec<u8>)>>
+            })
+            .collect();
+        let mut iter = MergedIter::new(chunks, reverse);
+        let pairs: Vec<_> = iter.by_ref().take(limit).collect();
+        let next_key = match iter.next() {
+            Some(_) if reverse => pairs.last().map(|(key, _)| key.clone()),
+            Some((key, _)) => Some(key),
+            None => None,
+        };
+        (pairs, next_key)
+    }
+    /// at most `count` live (key, value) pairs at the snapshot `ts` in [`start`, `end`) of `partition`, the `i`th one
+    /// the pairs are copied out in rounds, the values kept for the snapshot are read after each round,
+    /// so they have the values replaced by the writes meanwhile, even if the partition is not locked
+    #[allow(clippy::too_many_arguments)]
+    fn scan_partition(&self, i: usize, partition: &dyn MemStore, start: &[u8], end: Option<&[u8]>, count: usize, reverse: bool, ts: u64) -> Pairs {
+        let mut pairs = vec![];
+        let mut start = start.to_vec();
+        let mut end = end.map(|it| it.to_vec());
+        loop {
+            let current: Pairs = if reverse {
+                partition.range_rev(&start, end.as_deref()).take(count).collect()
+            } else {
+                partition.range(&start, end.as_deref()).take(count).collect()
+            };
+            // the range read in this round, the rest of the range if there's no more pair
+            let (from, to) = match current.last() {
+                Some((key, _)) if current.len() == count && reverse => (key.clone(), end.clone()),
+                Some((key, _)) if current.len() == count => {
+                    let mut to = key.clone();
+                    to.push(0);
+                    (start.clone(), Some(to))
+                }
+                _ => (start.clone(), end.clone()),
+            };
+            let exhausted = current.len() < count;
+            let kept: BTreeMap<_, _> = self.history.lock().unwrap().range(&from, to.as_deref(), ts)
+                .into_iter()
+                .filter(|(key, _)| self.partition_of(key) == i)
+                .collect();
+            let current = Box::new(current.into_iter());
+            let round = if kept.is_empty() {
+                current as Box<dyn Iterator<Item=(Vec<u8>, Vec<u8>)>>
+            } else {
+                Box::new(SnapshotIter::new(current, kept, reverse))
+            };
+            pairs.extend(live_pairs(round).take(count - pairs.len()));
+            if exhausted || pairs.len() == count {
+                return pairs;
+            }
+            if reverse {
+                end = Some(from);
+            } else {
+                start = to.unwrap();
+            }
+        }
+    }
+    /// return at most `limit` (key, value) pairs at the snapshot `ts` which keys start with `prefix` in key order,
+    /// from the first key not less than `start`, and the key to continue from if there are more
+    pub fn prefix_scan(&self, prefix: &[u8], start: &[u8], limit: usize, ts: u64) -> Page {
+        let start = if start > prefix { start } else { prefix };
+        self.range_scan(start, prefix_end(prefix).as_deref(), limit, false, ts)
+    }
+    /// get the values of all `keys` at the snapshot `ts`
+    pub fn batch_get(&self, keys: &[Vec<u8>], ts: u64) -> io::Result<Vec<Option<Versioned>>> {
+        keys.iter().map(|key| self.get_at(key, ts)).collect()
+    }
+    /// apply all the writes in `batch` in order, they survive a crash all together or not at all
+    /// all the values put by the batch have the same version, which is returned
+    /// the returned `Durable` resolves when the batch is persisted
+    pub fn write_batch(&self, batch: WriteBatch) -> io::Result<(u64, Durable)> {
+        let keys: Vec<_> = batch.ops().iter().map(WriteOp::key).collect();
+        let locked = self.lock(&keys);
+        self.write_batch_locked(locked, batch)
+    }
+    /// write like `write_batch` with the partitions of the keys in `batch` locked
+    fn write_batch_locked(&self, locked: Locked, batch: WriteBatch) -> io::Result<(u64, Durable)> {
+        let pending = self.next_version()?;
+        let version = pending.version;
+        if batch.is_empty() {
+            return Ok((version, durable_now()));
+        }
+        let batch: WriteBatch = batch.into_ops().into_iter()
+            .map(|op| match op {
+                WriteOp::Put(key, value) => WriteOp::Put(key, Versioned { version, expire_at: None, value }.encode()),
+                delete => delete,
+            })
+            .collect::<Vec<_>>()
+            .into();
+        Ok((version, self.apply(locked, batch, Some(version))?))
+    }
+    /// commit `txn` if none of the keys it read or writes is written by others after its snapshot,
+    /// and all its conditions hold, the writes are applied like `write_batch`
+    /// return the version of the values put, or why it's not committed
+    /// the returned `Durable` resolves when the writes are persisted
+    pub fn commit(&self, txn: Txn) -> io::Result<(Result<u64, TxnError>, Durable)> {
+        let keys: Vec<_> = txn.keys()
+            .chain(txn.conditions().iter().map(|(key, _)| key.as_slice()))
+            .collect();
+        let locked = self.lock(&keys);
+        if txn.start_ts() != 0 {
+            let conflict = self.read_at(txn.start_ts(), |store, ts| -> io::Result<Option<Vec<u8>>> {
+                // the versions of the values are unique, so a key written after the snapshot has a different value
+                for key in txn.keys() {
+                    if store.read_locked(&locked, key, ts)? != store.read_locked(&locked, key, LATEST)? {
+                        return Ok(Some(key.to_vec()));
+                    }
+                }
+                Ok(None)
+            });
+            match conflict {
+                Err(message) => return Ok((Err(TxnError::Snapshot(message)), durable_now())),
+                Ok((_, result)) => {
+                    if let Some(key) = result? {
+                        return Ok((Err(TxnError::Conflict(key)), durable_now()));
+                    }
+                }
+            }
+        }
+        for (i, (key, condition)) in txn.conditions().iter().enumerate() {
+            let current = self.read_locked(&locked, key, LATEST)?;
+            if !condition.holds(current.as_ref()) {
+                return Ok((Err(TxnError::ConditionFailed(i, current)), durable_now()));
+            }
+        }
+        let (version, durable) = self.write_batch_locked(locked, txn.into_writes())?;
+        Ok((Ok(version), durable))
+    }
+    /// put all `pairs` atomically, return the version of the values put
+    /// the returned `Durable` resolves when the puts are persisted
+    pub fn batch_put(&self, pairs: Pairs) -> io::Result<(u64, Durable)> {
+        let mut batch = WriteBatch::new();
+        for (key, value) in pairs {
+            batch.put(key, value);
+        }
+        self.write_batch(batch)
+    }
+    /// delete all `keys` atomically, return the values deleted
+    /// the returned `Durable` resolves when the deletes are persisted
+    pub fn batch_delete(&self, keys: &[Vec<u8>]) -> io::Result<(Vec<Option<Versioned>>, Durable)> {
+        let locked = self.lock(&keys.iter().map(|it| it.as_slice()).collect::<Vec<_>>());
+        let mut removed = Vec::with_capacity(keys.len());
+        let mut deleted = HashSet::new();
+        let mut batch = WriteBatch::new();
+        for key in keys {
+            let value = self.read_locked(&locked, key, LATEST)?;
+            // only the first delete of a key removes something
+            if value.is_some() && deleted.insert(key) {
+                batch.delete(key.clone());
+                removed.push(value);
+            } else {
+                removed.push(None);
+            }
+        }
+        let (_, durable) = self.write_batch_locked(locked, batch)?;
+        Ok((removed, durable))
+    }
+    /// take a point-in-time snapshot of the store, the values in it carry their versions
+    /// it only holds the store for a short time,
+    /// write it out with `Snapshot::save` or `Snapshot::write_to` after the store is unlocked
+    pub fn snapshot(&self) -> Snapshot {
+        snapshot_of(&self.read_all())
+    }
+
+    /// statistics of the lsm-tree of each partition, `None` for the partitions not stored in lsm-trees
+    pub fn stats(&self) -> Vec<Option<LsmStats>> {
+        self.partitions.iter().map(|it| it.with_store(|it| it.stats())).collect()
+    }
+
+    /// replace all the data with `entries` from a snapshot, return the count of entries
+    pub fn restore(&self, entries: BTreeMap<Vec<u8>, Vec<u8>>) -> io::Result<u64> {
+        let mut locked: Locked = self.partitions.iter()
+            .enumerate()
+            .map(|(i, it)| (i, it.write()))
+            .collect();
+        self.check_log()?;
+        let pending = {
+            let mut clock = self.clock.lock().unwrap();
+            // the versions of the restored values may come from another clock,
+            // which is only followed if a version is left after them
+            let mut restored = clock.oracle.clone();
+            for value in entries.values() {
+                restored.observe(Versioned::version_of(value))?;
+            }
+            let ts = restored.next()?;
+            clock.oracle = restored;
+            clock.pending.insert(ts);
+            Pending { store: self, version: ts }
+        };
+        let ts = pending.version;
+        let mut history = self.history.lock().unwrap();
+        if history.keeps(ts) {
+            let mut missing = vec![];
+            for key in entries.keys() {
+                if locked[&self.partition_of(key)].get(key)?.is_none() {
+                    missing.push(key.clone());
+                }
+            }
+            for partition in locked.values() {
+                for (key, value) in partition.range(b"", None) {
+                    history.push(key, ts, Some(value));
+                }
+            }
+            for key in missing {
+                history.push(key, ts, None);
+            }
+        }
+        drop(history);
+        {
+            let mut guard = self.log.lock().unwrap();
+            let log = &mut *guard;
+            // checkpoint first, so a crash in the middle of restoring loses nothing,
+            // and the data isn't replaced if it can't be redone
+            log.redo_log.checkpoint(Snapshot::new(entries.clone().into_iter()))?;
+            let position = log.redo_log.position();
+            log.dirty_since.iter_mut().for_each(|it| *it = Some(position));
+        }
+        locked.values_mut().for_each(|it| it.clear());
+        let count = entries.len() as u64;
+        for (key, value) in entries {
+            let i = self.partition_of(&key);
+            locked.get_mut(&i).unwrap().put(key, value);
+        }
+        self.persist(locked);
+        Ok(count)
+    }
+
+    /// persist the data of the partitions in `locked` if they need to, and remove the logs no longer needed
+    fn persist(&self, mut locked: Locked) {
+        let persisted: Vec<_> = locked.iter_mut()
+            .filter_map(|(i, partition)| Some(*i).filter(|_| partition.persist()))
+            .collect();
+        let checkpoint = {
+            let mut log = self.log.lock().unwrap();
+            if !persisted.is_empty() {
+                for i in persisted {
+                    log.dirty_since[i] = None;
+                }
+                log.clear();
+            }
+            log.redo_log.should_checkpoint()
+        };
+        drop(locked);
+        if checkpoint {
+            self.checkpoint();
+        }
+    }
+
+    /// write all the data into a checkpoint, so the logs before it can be removed
+    /// the store is only locked while the snapshot is taken, it's written after the store is unlocked
+    fn checkpoint(&self) {
+        // no write is logged but not applied while all the partitions are locked
+        let (mut checkpoint, snapshot) = {
+            let partitions = self.read_all();
+            // another write may have begun it
+            let checkpoint = match self.log.lock().unwrap().redo_log.begin_checkpoint() {
+                Some(checkpoint) => checkpoint,
+                None => return,
+            };
+            (checkpoint, snapshot_of(&partitions))
+        };
+        if let Err(err) = checkpoint.write(snapshot) {
+            error!("failed to write the checkpoint: {:?}", err);
+        }
+        let position = checkpoint.position();
+        let mut log = self.log.lock().unwrap();
+        if log.redo_log.finish_checkpoint(checkpoint) {
+            // the data not persisted before the checkpoint is in it
+            log.dirty_since.iter_mut().flatten().for_each(|it| *it = (*it).max(position));
         }
     }
+}
+
+/// open the redo log of a test store in `dir`, it's not synced to keep the tests fast
+#[cfg(test)]
+fn test_log(dir: &std::path::Path, options: redo_log::LogOptions) -> redo_log::SegmentedLog {
+    let options = redo_log::LogOptions { durability: redo_log::Durability::Os, ..options };
+    redo_log::SegmentedLog::open(dir, options).unwrap()
+}
+
+/// a store on a `BTreeMap` with its redo log in `dir`
+#[cfg(test)]
+fn test_store(dir: &std::path::Path) -> Store {
+    Store::new(BTreeMap::new(), test_log(dir, redo_log::LogOptions::default()))
+}
+
+#[test]
+fn test_range_and_prefix_scan() {
+    use tempfile::tempdir;
+    use futures01::Future;
+
+    assert_eq!(prefix_end(b"ab"), Some(b"ac".to_vec()));
+    assert_eq!(prefix_end(&[1, 0xff, 0xff]), Some(vec![2]));
+    assert_eq!(prefix_end(&[0xff]), None);
+    assert_eq!(prefix_end(b""), None);
 
-    pub fn put(&mut self, key: [u8; 8], value: [u8; 256]) {
-        self.mem_store.put(key, value);
-        self.redo_log.log_put(key, &value);
+    let dir = tempdir().unwrap();
+    let store = test_store(dir.path());
+    for key in &[&b"a"[..], b"tenant1/a", b"tenant1/b", b"tenant1/c", b"tenant10/a", b"tenant2/a", &[0xff, 0xff]] {
+        store.put(key.to_vec(), key.to_vec()).unwrap().1.wait().unwrap();
     }
-    pub fn get(&self, key: &[u8; 8]) -> Option<&[u8; 256]> {
-        self.mem_store.get(key)
+    let (pairs, next_key) = store.prefix_scan(b"tenant1/", b"", 2, LATEST);
+    let keys: Vec<_> = pairs.iter().map(|(key, _)| key.as_slice()).collect();
+    assert_eq!(keys, vec![&b"tenant1/a"[..], b"tenant1/b"]);
+    assert_eq!(next_key, Some(b"tenant1/c".to_vec()));
+    let (pairs, next_key) = store.prefix_scan(b"tenant1/", &next_key.unwrap(), 2, LATEST);
+    assert_eq!(pairs, vec![(b"tenant1/c".to_vec(), b"tenant1/c".to_vec())]);
+    assert!(next_key.is_none());
+    assert_eq!(store.prefix_scan(b"tenant1", b"", 16, LATEST).0.len(), 4);
+    assert_eq!(store.prefix_scan(b"tenant1/", b"tenant2", 16, LATEST).0.len(), 0);
+    assert_eq!(store.prefix_scan(&[0xff], b"", 16, LATEST).0.len(), 1);
+    assert_eq!(store.prefix_scan(b"", b"", 16, LATEST).0.len(), 7);
+
+    let (pairs, next_key) = store.range_scan(b"tenant1/", None, 2, true, LATEST);
+    let keys: Vec<_> = pairs.iter().map(|(key, _)| key.as_slice()).collect();
+    assert_eq!(keys, vec![&[0xff, 0xff][..], b"tenant2/a"]);
+    assert_eq!(next_key, Some(b"tenant2/a".to_vec()));
+    let (pairs, next_key) = store.range_scan(b"tenant1/", next_key.as_deref(), 2, true, LATEST);
+    let keys: Vec<_> = pairs.iter().map(|(key, _)| key.as_slice()).collect();
+    assert_eq!(keys, vec![&b"tenant10/a"[..], b"tenant1/c"]);
+    assert!(next_key.is_some());
+    let (pairs, next_key) = store.range_scan(b"tenant1/", next_key.as_deref(), 2, true, LATEST);
+    assert_eq!(pairs.len(), 2);
+    assert!(next_key.is_none());
+}
+
+#[test]
+fn test_scan_cursor() {
+    use std::collections::HashSet;
+    use futures01::Future;
+    use tempfile::tempdir;
+
+    let dir = tempdir().unwrap();
+    let store = test_store(dir.path());
+    for i in 0..100u8 {
+        store.put(vec![i * 2], vec![]).unwrap().1.wait().unwrap();
     }
-    pub fn delete(&mut self, key: [u8; 8]) -> Option<[u8; 256]> {
-        let result = self.mem_store.delete(key);
-        if result.is_some() {
-            self.redo_log.log_delete(key);
+    // a page of the `Scan` API
+    let scan = |store: &Store, cursor: u64| -> Option<(u64, Pairs)> {
+        let start = store.cursor(cursor)?;
+        let (pairs, next_key) = store.range_scan(&start, None, 16, false, LATEST);
+        Some((next_key.map(|key| store.new_cursor(key)).unwrap_or(0), pairs))
+    };
+    let mut cursor = 0;
+    let mut scanned = HashSet::new();
+    let mut page = 0u8;
+    loop {
+        let (next_cursor, pairs) = scan(&store, cursor).unwrap();
+        for (key, _) in pairs {
+            // every key is returned only once
+            assert!(scanned.insert(key));
+        }
+        if next_cursor == 0 {
+            break;
         }
-        result
+        cursor = next_cursor;
+        // the keys put or deleted during the scan don't affect the other keys
+        store.put(vec![page * 2 + 1], vec![]).unwrap().1.wait().unwrap();
+        store.delete(&[198 - page * 2]).unwrap().1.wait().unwrap();
+        page += 1;
     }
-    pub fn scan(&self, at: usize, count: usize) -> (usize, Vec<[u8; 8]>) {
-        self.mem_store.scan(at, count)
+    for i in 0..75u8 {
+        assert!(scanned.contains(&vec![i * 2]));
     }
+    assert!(scan(&store, 12345).is_none());
+    // a `BTreeMap` has no statistics
+    assert!(store.stats()[0].is_none());
+}
+
+#[test]
+fn test_version() {
+    use futures01::Future;
+    use tempfile::tempdir;
+    use crate::store::versioned::MAX_VERSION;
+
+    let dir = tempdir().unwrap();
+    let store = test_store(dir.path());
+    let (first, durable) = store.put(b"a".to_vec(), b"1".to_vec()).unwrap();
+    durable.wait().unwrap();
+    let (second, _) = store.put(b"a".to_vec(), b"2".to_vec()).unwrap();
+    assert!(second > first);
+    assert_eq!(store.get(b"a").unwrap(), Some(Versioned { version: second, expire_at: None, value: b"2".to_vec() }));
+    let (removed, _) = store.delete(b"a").unwrap();
+    assert_eq!(removed.unwrap().version, second);
+    let (third, _) = store.put(b"a".to_vec(), b"3".to_vec()).unwrap();
+    assert!(third > second);
+    let (fourth, durable) = store.batch_put(vec![(b"a".to_vec(), vec![]), (b"b".to_vec(), vec![])]).unwrap();
+    durable.wait().unwrap();
+    assert!(fourth > third);
+    assert_eq!(store.get(b"b").unwrap().unwrap().version, fourth);
+    // the values in scans don't carry the versions
+    assert_eq!(store.range_scan(b"", None, 16, false, LATEST).0, vec![(b"a".to_vec(), vec![]), (b"b".to_vec(), vec![])]);
+    drop(store);
+
+    // the versions survive a restart
+    let store = test_store(dir.path());
+    assert_eq!(store.get(b"a").unwrap(), Some(Versioned { version: fourth, expire_at: None, value: vec![] }));
+    assert!(store.put(b"a".to_vec(), vec![]).unwrap().0 > fourth);
+
+    // the versions keep increasing after a restart even if the wall clock is behind the versions stored
+    let mut data = BTreeMap::new();
+    let future = Versioned { version: MAX_VERSION / 2, expire_at: Some(u64::MAX), value: vec![] };
+    data.insert(b"b".to_vec(), future.encode());
+    let dir = tempdir().unwrap();
+    let store = Store::new(data, test_log(dir.path(), redo_log::LogOptions::default()));
+    let (last, _) = store.put(b"a".to_vec(), vec![]).unwrap();
+    assert!(last > MAX_VERSION / 2);
+    store.delete(b"a").unwrap().1.wait().unwrap();
+    store.delete(b"b").unwrap().1.wait().unwrap();
+    drop(store);
+    // the limit of the versions is saved, so they keep increasing without the values which carry them
+    let store = test_store(dir.path());
+    assert_eq!(store.range_scan(b"", None, 16, false, LATEST).0.len(), 0);
+    assert!(store.put(b"a".to_vec(), vec![]).unwrap().0 > last);
+
+    // the versions never repeat, the writes are refused once the clock is exhausted
+    let mut data = BTreeMap::new();
+    data.insert(b"b".to_vec(), Versioned { version: MAX_VERSION - 1, expire_at: None, value: vec![] }.encode());
+    assert!(store.restore(data.clone()).is_err());
+    assert!(store.put(b"a".to_vec(), vec![]).is_ok());
+    let dir = tempdir().unwrap();
+    let store = Store::new(data, test_log(dir.path(), redo_log::LogOptions::default()));
+    assert!(store.put(b"a".to_vec(), vec![]).is_err());
+    assert!(store.delete(b"b").is_err());
+    assert_eq!(store.get(b"b").unwrap().unwrap().version, MAX_VERSION - 1);
+}
+
+#[test]
+fn test_ttl() {
+    use std::thread::sleep;
+    use futures01::Future;
+    use tempfile::tempdir;
+
+    let dir = tempdir().unwrap();
+    let store = test_store(dir.path());
+    store.put_with_ttl(b"a".to_vec(), b"1".to_vec(), Some(Duration::from_millis(20))).unwrap().1.wait().unwrap();
+    store.put_with_ttl(b"b".to_vec(), b"2".to_vec(), Some(Duration::from_secs(3600))).unwrap().1.wait().unwrap();
+    store.put(b"c".to_vec(), b"3".to_vec()).unwrap().1.wait().unwrap();
+    assert_eq!(store.ttl(b"c").unwrap(), Some(None));
+    assert!(store.ttl(b"b").unwrap().unwrap().unwrap() > Duration::from_secs(3500));
+    assert_eq!(store.ttl(b"d").unwrap(), None);
+    let (found, durable) = store.expire(b"c", Some(Duration::from_millis(20))).unwrap();
+    durable.wait().unwrap();
+    assert!(found);
+    assert!(!store.expire(b"d", None).unwrap().0);
+    assert_eq!(store.range_scan(b"", None, 16, false, LATEST).0.len(), 3);
+
+    sleep(Duration::from_millis(30));
+    // expired keys are invisible before they are swept
+    assert_eq!(store.get(b"a").unwrap(), None);
+    assert_eq!(store.ttl(b"c").unwrap(), None);
+    assert_eq!(store.range_scan(b"", None, 16, false, LATEST).0, vec![(b"b".to_vec(), b"2".to_vec())]);
+    assert!(store.put_if(b"a".to_vec(), vec![], None, &Condition::Absent).unwrap().0.is_ok());
+    assert_eq!(store.read_all().iter().map(|it| it.range(b"", None).count()).sum::<usize>(), 3);
+
+    // the sweep goes on from where it stopped
+    assert_eq!(store.sweep(2).unwrap(), 0);
+    assert_eq!(store.sweep(2).unwrap(), 1);
+    assert_eq!(store.read_all().iter().map(|it| it.range(b"", None).count()).sum::<usize>(), 2);
+    let version = store.get(b"a").unwrap().unwrap().version;
+    store.expire(b"a", Some(Duration::from_secs(60))).unwrap().1.wait().unwrap();
+    assert_eq!(store.get(b"a").unwrap().unwrap().version, version);
+    drop(store);
+
+    // the deadlines and the deletes of the sweeper survive a restart
+    let store = test_store(dir.path());
+    assert_eq!(store.read_all().iter().map(|it| it.range(b"", None).count()).sum::<usize>(), 2);
+    assert!(store.ttl(b"a").unwrap().unwrap().unwrap() > Duration::from_secs(50));
+    assert!(store.ttl(b"b").unwrap().unwrap().unwrap() > Duration::from_secs(3500));
+    store.expire(b"b", None).unwrap().1.wait().unwrap();
+    assert_eq!(store.ttl(b"b").unwrap(), Some(None));
+}
+
+#[test]
+fn test_conditional_write() {
+    use futures01::Future;
+    use tempfile::tempdir;
+
+    let dir = tempdir().unwrap();
+    let store = test_store(dir.path());
+    let (checked, durable) = store.put_if(b"a".to_vec(), b"1".to_vec(), None, &Condition::Absent).unwrap();
+    durable.wait().unwrap();
+    let version = checked.unwrap();
+    let current = Some(Versioned { version, expire_at: None, value: b"1".to_vec() });
+    let (checked, _) = store.put_if(b"a".to_vec(), b"2".to_vec(), None, &Condition::Absent).unwrap();
+    assert_eq!(checked, Err(current.clone()));
+    let (checked, _) = store.put_if(b"a".to_vec(), b"2".to_vec(), None, &Condition::Equal(b"0".to_vec())).unwrap();
+    assert_eq!(checked, Err(current.clone()));
+    let (checked, _) = store.put_if(b"a".to_vec(), b"2".to_vec(), None, &Condition::Version(version + 1)).unwrap();
+    assert_eq!(checked, Err(current));
+    let (checked, durable) = store.put_if(b"a".to_vec(), b"2".to_vec(), None, &Condition::Equal(b"1".to_vec())).unwrap();
+    durable.wait().unwrap();
+    let version = checked.unwrap();
+    assert_eq!(store.get(b"a").unwrap().unwrap().value, b"2".to_vec());
+
+    let (checked, _) = store.delete_if(b"a", &Condition::Equal(b"1".to_vec())).unwrap();
+    assert_eq!(checked.unwrap_err().unwrap().value, b"2".to_vec());
+    let (checked, _) = store.delete_if(b"b", &Condition::Equal(b"1".to_vec())).unwrap();
+    assert_eq!(checked, Err(None));
+    let (checked, durable) = store.delete_if(b"a", &Condition::Version(version)).unwrap();
+    durable.wait().unwrap();
+    assert_eq!(checked, Ok(Some(Versioned { version, expire_at: None, value: b"2".to_vec() })));
+    assert_eq!(store.get(b"a").unwrap(), None);
+}
+
+#[test]
+fn test_batch() {
+    use futures01::Future;
+    use tempfile::tempdir;
+
+    let dir = tempdir().unwrap();
+    let store = test_store(dir.path());
+    let pairs = (0..10u8).map(|i| (vec![i], vec![i; 10])).collect();
+    store.batch_put(pairs).unwrap().1.wait().unwrap();
+    let (removed, durable) = store.batch_delete(&[vec![1], vec![100], vec![1]]).unwrap();
+    durable.wait().unwrap();
+    let removed: Vec<_> = removed.into_iter().map(|it| it.map(|it| it.value)).collect();
+    assert_eq!(removed, vec![Some(vec![1; 10]), None, None]);
+    let values = |store: &Store| -> Vec<_> {
+        store.batch_get(&[vec![0], vec![1], vec![9]], LATEST).unwrap().into_iter().map(|it| it.map(|it| it.value)).collect()
+    };
+    assert_eq!(values(&store), vec![Some(vec![0; 10]), None, Some(vec![9; 10])]);
+    drop(store);
+
+    let store = test_store(dir.path());
+    assert_eq!(values(&store), vec![Some(vec![0; 10]), None, Some(vec![9; 10])]);
+}
+
+#[test]
+fn test_snapshot_read() {
+    use futures01::Future;
+    use tempfile::tempdir;
+
+    let dir = tempdir().unwrap();
+    let mut store = test_store(dir.path());
+    store.set_snapshot_lease(Duration::from_secs(0));
+    let (first, _) = store.put(b"a".to_vec(), b"1".to_vec()).unwrap();
+    let (version, _) = store.put(b"b".to_vec(), b"1".to_vec()).unwrap();
+    let ts = store.open_snapshot(0).unwrap();
+    assert_eq!(ts, version);
+    assert!(store.open_snapshot(ts + 1).is_err());
+
+    store.put(b"a".to_vec(), b"2".to_vec()).unwrap().1.wait().unwrap();
+    store.delete(b"b").unwrap().1.wait().unwrap();
+    store.batch_put(vec![(b"a".to_vec(), b"3".to_vec()), (b"c".to_vec(), b"3".to_vec())]).unwrap().1.wait().unwrap();
+    store.expire(b"a", Some(Duration::from_secs(60))).unwrap().1.wait().unwrap();
+    // the snapshot sees the values before it
+    assert_eq!(store.get_at(b"a", ts).unwrap(), Some(Versioned { version: first, expire_at: None, value: b"1".to_vec() }));
+    assert_eq!(store.get_at(b"b", ts).unwrap().unwrap().value, b"1".to_vec());
+    assert_eq!(store.get_at(b"c", ts).unwrap(), None);
+    let pairs = vec![(b"a".to_vec(), b"1".to_vec()), (b"b".to_vec(), b"1".to_vec())];
+    assert_eq!(store.range_scan(b"", None, 16, false, ts).0, pairs);
+    let (page, next_key) = store.range_scan(b"", None, 1, true, ts);
+    assert_eq!(page, vec![(b"b".to_vec(), b"1".to_vec())]);
+    assert_eq!(store.range_scan(b"", next_key.as_deref(), 1, true, ts).0, vec![(b"a".to_vec(), b"1".to_vec())]);
+    // the latest values are not affected
+    assert_eq!(store.get(b"a").unwrap().unwrap().value, b"3".to_vec());
+    assert!(store.get(b"a").unwrap().unwrap().expire_at.is_some());
+    assert_eq!(store.range_scan(b"", None, 16, false, LATEST).0.len(), 2);
+
+    // the restore is invisible to the snapshot too
+    let latest = store.open_snapshot(0).unwrap();
+    let mut entries = BTreeMap::new();
+    entries.insert(b"d".to_vec(), Versioned { version: 1, expire_at: None, value: b"4".to_vec() }.encode());
+    store.restore(entries).unwrap();
+    let found: Vec<_> = store.batch_get(&[b"a".to_vec(), b"d".to_vec()], ts).unwrap().into_iter().map(|it| it.is_some()).collect();
+    assert_eq!(found, vec![true, false]);
+    assert_eq!(store.range_scan(b"", None, 16, false, latest).0.len(), 2);
+    assert_eq!(store.range_scan(b"", None, 16, false, LATEST).0.len(), 1);
+
+    // the values are dropped after the snapshots are released
+    store.release_snapshot(ts, true);
+    store.release_snapshot(latest, true);
+    assert!(store.open_snapshot(ts).is_err());
+    assert_eq!(store.history.lock().unwrap().get(b"a", ts), None);
+    let (_, value) = store.read_at(0, |store, ts| store.get_at(b"d", ts).unwrap()).unwrap();
+    assert_eq!(value.unwrap().value, b"4".to_vec());
+}
+
+#[test]
+fn test_snapshot_lease() {
+    use futures01::Future;
+    use tempfile::tempdir;
+
+    let dir = tempdir().unwrap();
+    let store = test_store(dir.path());
+    store.put(b"a".to_vec(), b"1".to_vec()).unwrap().1.wait().unwrap();
+    // the reads at the latest version don't keep the old values while the writes go on
+    let mut read_ts = vec![];
+    for i in 2..10u8 {
+        let (ts, value) = store.read_at(0, |store, ts| store.get_at(b"a", ts).unwrap()).unwrap();
+        assert_eq!(value.unwrap().value, vec![b'0' + i - 1]);
+        read_ts.push(ts);
+        store.put(b"a".to_vec(), vec![b'0' + i]).unwrap().1.wait().unwrap();
+    }
+    assert!(read_ts.iter().all(|ts| store.history.lock().unwrap().get(b"a", *ts).is_none()));
+    assert!(store.open_snapshot(read_ts[0]).is_err());
+
+    // the snapshots read at a given timestamp, or followed by more pages, are leased
+    let (ts, _) = store.read_at(0, |_, _| ()).unwrap();
+    store.read_at(ts, |_, _| ()).unwrap();
+    store.put(b"a".to_vec(), b"x".to_vec()).unwrap().1.wait().unwrap();
+    let (page_ts, _) = store.read_and_lease(0, |_, _| ((), true)).unwrap();
+    store.put(b"a".to_vec(), b"y".to_vec()).unwrap().1.wait().unwrap();
+    assert_eq!(store.get_at(b"a", ts).unwrap().unwrap().value, b"9".to_vec());
+    assert_eq!(store.get_at(b"a", page_ts).unwrap().unwrap().value, b"x".to_vec());
+}
+
+#[test]
+fn test_txn() {
+    use futures01::Future;
+    use tempfile::tempdir;
+
+    let dir = tempdir().unwrap();
+    let mut store = test_store(dir.path());
+    store.set_snapshot_lease(Duration::from_secs(0));
+    store.batch_put(vec![(b"a".to_vec(), b"10".to_vec()), (b"b".to_vec(), b"0".to_vec())]).unwrap().1.wait().unwrap();
+    // move 5 from a to b
+    let ts = store.open_snapshot(0).unwrap();
+    let values: Vec<_> = store.batch_get(&[b"a".to_vec(), b"b".to_vec()], ts).unwrap().into_iter().map(|it| it.unwrap().value).collect();
+    assert_eq!(values, vec![b"10".to_vec(), b"0".to_vec()]);
+    let mut txn = Txn::new(ts);
+    txn.read(b"a".to_vec())
+        .read(b"b".to_vec())
+        .put(b"a".to_vec(), b"5".to_vec())
+        .put(b"b".to_vec(), b"5".to_vec());
+    let (result, durable) = store.commit(txn.clone()).unwrap();
+    durable.wait().unwrap();
+    let version = result.unwrap();
+    assert_eq!(store.get(b"b").unwrap(), Some(Versioned { version, expire_at: None, value: b"5".to_vec() }));
+    // the same transaction conflicts with itself committed
+    assert_eq!(store.commit(txn).unwrap().0, Err(TxnError::Conflict(b"a".to_vec())));
+
+    // a key put and deleted again after the snapshot is still absent, so it doesn't conflict
+    let mut txn = Txn::new(ts);
+    txn.read(b"c".to_vec()).put(b"c".to_vec(), vec![]);
+    store.put(b"c".to_vec(), vec![]).unwrap().1.wait().unwrap();
+    store.delete(b"c").unwrap().1.wait().unwrap();
+    assert_eq!(store.commit(txn.clone()).unwrap().0, Ok(store.clock.lock().unwrap().oracle.last()));
+    store.release_snapshot(ts, true);
+    assert!(matches!(store.commit(txn).unwrap().0, Err(TxnError::Snapshot(_))));
+
+    let mut txn = Txn::new(0);
+    txn.check(b"a".to_vec(), Condition::Equal(b"5".to_vec()))
+        .check(b"d".to_vec(), Condition::Absent)
+        .check(b"b".to_vec(), Condition::Version(version + 1))
+        .delete(b"a".to_vec());
+    let (result, _) = store.commit(txn).unwrap();
+    assert_eq!(result, Err(TxnError::ConditionFailed(2, store.get(b"b").unwrap())));
+    assert!(store.get(b"a").unwrap().is_some());
+    drop(store);
+
+    // the committed transactions survive a restart
+    let store = test_store(dir.path());
+    assert_eq!(store.get(b"a").unwrap().unwrap().value, b"5".to_vec());
+    assert_eq!(store.get(b"c").unwrap().unwrap().value, vec![]);
+}
+
+#[test]
+fn test_partitions() {
+    use std::fs;
+    use std::sync::Arc;
+    use std::thread;
+    use futures01::Future;
+    use tempfile::tempdir;
+    use crate::store::lsm::{LsmTree, LsmOptions};
+    use crate::store::redo_log::LogOptions;
+
+    let dir = tempdir().unwrap();
+    let log_options = LogOptions { segment_size: 4096, checkpoint_segments: 0, ..LogOptions::default() };
+    let lsm_options = LsmOptions { memtable_size: 64, ..LsmOptions::default() };
+    let open = || {
+        let partitions = (0..4)
+            .map(|i| LsmTree::open(dir.path().join("data").join(i.to_string()), lsm_options.clone()).unwrap())
+            .map(|it| Box::new(it) as Box<dyn MemStore>)
+            .collect();
+        Store::with_partitions(partitions, test_log(&dir.path().join("log"), log_options))
+    };
+    let store = Arc::new(open());
+    let writers: Vec<_> = (0..4u8).map(|i| {
+        let store = store.clone();
+        thread::spawn(move || {
+            for j in 0..100u8 {
+                store.put(vec![j, i], vec![i; 64]).unwrap().1.wait().unwrap();
+            }
+            // the keys of a batch are in different partitions
+            store.batch_put(vec![(vec![255, i], vec![i]), (vec![255, i + 4], vec![i])]).unwrap().1.wait().unwrap();
+        })
+    }).collect();
+    for writer in writers {
+        writer.join().unwrap();
+    }
+    // the pairs of the partitions are merged in key order
+    let (all, _) = store.range_scan(b"", None, 1000, false, LATEST);
+    assert_eq!(all.len(), 408);
+    assert!(all.windows(2).all(|it| it[0].0 < it[1].0));
+    let (pairs, next_key) = store.range_scan(&[255], None, 4, true, LATEST);
+    let keys: Vec<_> = pairs.into_iter().map(|(key, _)| key[1]).collect();
+    assert_eq!(keys, vec![7, 6, 5, 4]);
+    assert_eq!(next_key, Some(vec![255, 4]));
+    // the lsm-tree of each partition reports its flushes
+    let stats = store.stats();
+    assert_eq!(stats.len(), 4);
+    assert!(stats.iter().all(|it| it.as_ref().unwrap().flushed_bytes > 0));
+    // the segments are removed after all the partitions persist the writes in them
+    let segments = fs::read_dir(dir.path().join("log")).unwrap().count();
+    assert!(segments < 8, "{} segments left", segments);
+    drop(store);
+
+    let store = open();
+    // the writes not persisted are replayed into their partitions
+    assert_eq!(store.range_scan(b"", None, 1000, false, LATEST).0, all);
+    assert_eq!(store.get(&[99, 3]).unwrap().unwrap().value, vec![3; 64]);
+    assert_eq!(store.get(&[255, 7]).unwrap().unwrap().value, vec![3]);
+
+    // a scan at a snapshot reads the values at it, even if the partitions are written between its pages
+    let ts = store.open_snapshot(0).unwrap();
+    let (first, next_key) = store.range_scan(&[255], None, 4, false, ts);
+    store.batch_delete(&[vec![255, 0], vec![255, 7]]).unwrap().1.wait().unwrap();
+    store.put(vec![255, 5], vec![42]).unwrap().1.wait().unwrap();
+    let (second, next_key) = store.range_scan(&next_key.unwrap(), None, 4, false, ts);
+    assert!(next_key.is_none());
+    let pairs: Vec<_> = first.into_iter().chain(second).map(|(key, value)| (key[1], value)).collect();
+    let expected: Vec<_> = (0..8).map(|i| (i, vec![i % 4])).collect();
+    assert_eq!(pairs, expected);
+    store.release_snapshot(ts, true);
+}
+
+#[test]
+fn test_shared_partitions() {
+    use std::sync::Arc;
+    use std::sync::mpsc::channel;
+    use std::thread;
+    use std::time::Duration;
+    use futures01::Future;
+    use tempfile::tempdir;
+    use crate::store::mem_store::ConcurrentMemStore;
+    use crate::store::redo_log::LogOptions;
+    use crate::store::skiplist::SkipList;
+
+    let dir = tempdir().unwrap();
+    let partitions = (0..4)
+        .map(|_| Box::new(Arc::new(SkipList::new()) as Arc<dyn ConcurrentMemStore>) as Box<dyn MemStore>)
+        .collect();
+    let store = Arc::new(Store::with_partitions(partitions, test_log(dir.path(), LogOptions::default())));
+    let keys: Vec<_> = (0..50u8).map(|i| vec![i]).collect();
+    store.batch_put(keys.iter().map(|key| (key.clone(), vec![0])).collect()).unwrap().1.wait().unwrap();
+
+    // a reader doesn't wait for the writer holding the partition of the key it reads, even to open a snapshot
+    let ts = store.open_snapshot(0).unwrap();
+    let locked = store.lock(&[&[0]]);
+    let (sender, receiver) = channel();
+    let reader = {
+        let store = store.clone();
+        thread::spawn(move || {
+            let value = store.get(&[0]).unwrap().unwrap().value;
+            let (pairs, _) = store.range_scan(b"", None, 100, true, ts);
+            let (latest, count) = store.read_at(0, |store, ts| store.range_scan(b"", None, 100, false, ts).0.len()).unwrap();
+            sender.send((value, pairs.len(), latest, count)).unwrap();
+        })
+    };
+    assert_eq!(receiver.recv_timeout(Duration::from_secs(10)).unwrap(), (vec![0], 50, ts, 50));
+    reader.join().unwrap();
+    store.put_locked(locked, vec![0], vec![1], None).unwrap().1.wait().unwrap();
+    assert_eq!(store.get_at(&[0], ts).unwrap().unwrap().value, vec![0]);
+    assert_eq!(store.get(&[0]).unwrap().unwrap().value, vec![1]);
+    store.release_snapshot(ts, true);
+    store.put(vec![0], vec![0]).unwrap().1.wait().unwrap();
+
+    // the scans at a snapshot read the values at it, while the batches are applied without locking the readers out
+    let writers: Vec<_> = (0..2u8).map(|i| {
+        let store = store.clone();
+        let keys = keys.clone();
+        thread::spawn(move || {
+            for j in 0..50u8 {
+                let value = vec![i, j];
+                store.batch_put(keys.iter().map(|key| (key.clone(), value.clone())).collect()).unwrap().1.wait().unwrap();
+            }
+        })
+    }).collect();
+    let readers: Vec<_> = (0..2).map(|_| {
+        let store = store.clone();
+        thread::spawn(move || {
+            for _ in 0..50 {
+                let ts = store.open_snapshot(0).unwrap();
+                let mut values = vec![];
+                let mut start = Some(vec![]);
+                while let Some(key) = start {
+                    let (pairs, next_key) = store.range_scan(&key, None, 7, false, ts);
+                    values.extend(pairs.into_iter().map(|(_, value)| value));
+                    start = next_key;
+                }
+                store.release_snapshot(ts, true);
+                assert_eq!(values.len(), 50);
+                assert!(values.iter().all(|it| *it == values[0]), "{:?}", values);
+            }
+        })
+    }).collect();
+    for thread in writers.into_iter().chain(readers) {
+        thread.join().unwrap();
+    }
+}
+
+#[test]
+fn test_failed_log() {
+    use futures01::Future;
+    use futures01::sync::oneshot;
+
+    /// a redo log which fails to write every record
+    struct BrokenLog {
+        failed: bool,
+    }
+
+    impl RedoLog for BrokenLog {
+        fn log_put(&mut self, _key: &[u8], _value: &[u8]) {
+            self.failed = true;
+        }
+        fn log_delete(&mut self, _key: &[u8]) {
+            self.failed = true;
+        }
+        fn log_batch(&mut self, _batch: &WriteBatch) {
+            self.failed = true;
+        }
+        fn redo(&mut self, _store: &mut dyn MemStore) {}
+        fn position(&mut self) -> u64 {
+            0
+        }
+        fn clear_before(&mut self, _position: u64) {}
+        fn checkpoint(&mut self, _snapshot: Snapshot) -> io::Result<()> {
+            Err(io::Error::new(io::ErrorKind::PermissionDenied, "broken"))
+        }
+        fn durable(&mut self) -> Durable {
+            oneshot::channel().1
+        }
+        fn failed(&self) -> bool {
+            self.failed
+        }
+    }
+
+    let store = Store::new(BTreeMap::new(), BrokenLog { failed: false });
+    let mut entries = BTreeMap::new();
+    entries.insert(b"b".to_vec(), Versioned { version: 1, expire_at: None, value: vec![] }.encode());
+    // the data isn't replaced if the checkpoint of it fails
+    assert!(store.restore(entries).is_err());
+    assert!(store.get(b"b").unwrap().is_none());
+    let (version, durable) = store.put(b"a".to_vec(), b"1".to_vec()).unwrap();
+    assert!(durable.wait().is_err());
+    // the value not logged is still read, but no more writes are taken on top of it
+    assert_eq!(store.get(b"a").unwrap().unwrap().version, version);
+    assert!(store.put(b"a".to_vec(), b"2".to_vec()).is_err());
+    assert!(store.delete(b"a").is_err());
+    assert!(store.batch_put(vec![(b"c".to_vec(), vec![])]).is_err());
+    assert_eq!(store.get(b"a").unwrap().unwrap().value, b"1".to_vec());
 }
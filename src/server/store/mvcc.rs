@@ -0,0 +1,268 @@
+//! Multi-version concurrency control
+//!
+//! The `MemStore` only holds the latest value of each key. While some snapshots are open,
+//! the value a write replaces is kept here with the timestamp it is replaced at,
+//! so a read at the timestamp of a snapshot sees the values as they were at that time,
+//! no matter what is written after it.
+//!
+//! The timestamps are taken from the revision clock which gives the versions of the values.
+//! A snapshot is pinned while a request reads from it, and leased for a while after that if it's read again,
+//! like when a client reads several pages at the same timestamp.
+//! The kept values which no open snapshot can read are dropped, and reading before them is no longer possible.
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, VecDeque};
+use std::iter::Peekable;
+use std::ops::Bound;
+use std::time::{Duration, Instant};
+
+/// the values replaced of a key with the timestamps they are replaced at, `None` if the key didn't exist
+type Chain = VecDeque<(u64, Option<Vec<u8>>)>;
+
+/// (key, value) pairs in some order
+type PairIter<'a> = Box<dyn Iterator<Item=(Vec<u8>, Vec<u8>)> + 'a>;
+
+/// (key, value) pairs kept in the history in some order, the values are `None` if the keys didn't exist
+type KeptIter = Box<dyn Iterator<Item=(Vec<u8>, Option<Vec<u8>>)>>;
+
+/// An open snapshot
+struct Lease {
+    /// how many requests are reading from the snapshot
+    pinned: usize,
+    /// the snapshot can be dropped after this if it's not pinned
+    until: Instant,
+}
+
+pub struct History {
+    /// the values replaced of each key from the oldest to the newest
+    chains: BTreeMap<Vec<u8>, Chain>,
+    /// the timestamps and the keys of all the values in `chains` from the oldest to the newest
+    order: VecDeque<(u64, Vec<u8>)>,
+    /// the open snapshots by their timestamps
+    snapshots: BTreeMap<u64, Lease>,
+    /// the values replaced at or before this are not kept, so no snapshot can be older than it
+    safe_point: u64,
+    /// how long a snapshot is kept after the last read from it
+    lease: Duration,
+}
+
+impl History {
+    /// create an empty history, no snapshot can be older than `safe_point`
+    pub fn new(safe_point: u64, lease: Duration) -> Self {
+        Self {
+            chains: BTreeMap::new(),
+            order: VecDeque::new(),
+            snapshots: BTreeMap::new(),
+            safe_point,
+            lease,
+        }
+    }
+
+    pub fn set_lease(&mut self, lease: Duration) {
+        self.lease = lease;
+    }
+
+    /// open a snapshot at `ts` and pin it, or pin it again if it's open already
+    /// return `false` if it's older than the safe point
+    pub fn open(&mut self, ts: u64) -> bool {
+        if ts < self.safe_point {
+            return false;
+        }
+        let lease = self.snapshots.entry(ts).or_insert(Lease { pinned: 0, until: Instant::now() });
+        lease.pinned += 1;
+        true
+    }
+
+    /// open a snapshot at `published`, or at the safe point if it's later, and pin it like `open`, return its timestamp
+    /// the writes after it keep the values they replace from now on, the ones until it must be applied before it's read
+    pub fn open_latest(&mut self, published: u64) -> u64 {
+        let ts = published.max(self.safe_point);
+        self.open(ts);
+        ts
+    }
+
+    /// unpin the snapshot at `ts`, it's kept until its lease ends if `lease` is `true`,
+    /// otherwise it's dropped once it's not pinned, unless it's still leased before
+    pub fn release(&mut self, ts: u64, lease: bool) {
+        if let Some(leased) = self.snapshots.get_mut(&ts) {
+            leased.pinned = leased.pinned.saturating_sub(1);
+            if lease {
+                leased.until = leased.until.max(Instant::now() + self.lease);
+            }
+        }
+        self.collect();
+    }
+
+    /// whether the value replaced at `ts` should be kept, which is when some snapshots are open
+    pub fn keeps(&mut self, ts: u64) -> bool {
+        self.collect();
+        if self.snapshots.is_empty() {
+            self.safe_point = self.safe_point.max(ts);
+            false
+        } else {
+            true
+        }
+    }
+
+    /// keep `value` of `key` replaced at `ts`, only call this if `keeps(ts)` returns `true`
+    pub fn push(&mut self, key: Vec<u8>, ts: u64, value: Option<Vec<u8>>) {
+        self.chains.entry(key.clone()).or_default().push_back((ts, value));
+        self.order.push_back((ts, key));
+    }
+
+    /// the value of `key` at `ts` if it's replaced after `ts`, `Some(None)` if the key didn't exist at `ts`,
+    /// or `None` if the current value is the one at `ts`
+    pub fn get(&self, key: &[u8], ts: u64) -> Option<Option<Vec<u8>>> {
+        self.chains.get(key)?
+            .iter()
+            .find(|(replaced_at, _)| *replaced_at > ts)
+            .map(|(_, value)| value.clone())
+    }
+
+    /// the values at `ts` of the keys in [`start`, `end`) which are replaced after `ts`, like `get`
+    pub fn range(&self, start: &[u8], end: Option<&[u8]>, ts: u64) -> BTreeMap<Vec<u8>, Option<Vec<u8>>> {
+        if end.map(|end| end <= start).unwrap_or(false) {
+            return BTreeMap::new();
+        }
+        let end = end.map(Bound::Excluded).unwrap_or(Bound::Unbounded);
+        self.chains.range::<[u8], _>((Bound::Included(start), end))
+            .filter_map(|(key, _)| self.get(key, ts).map(|value| (key.clone(), value)))
+            .collect()
+    }
+
+    /// close the snapshots which are not pinned and whose leases have ended,
+    /// and drop the values which the remaining snapshots can't read
+    fn collect(&mut self) {
+        let now = Instant::now();
+        let closed: Vec<_> = self.snapshots.iter()
+            .filter(|(_, lease)| lease.pinned == 0 && lease.until <= now)
+            .map(|(ts, _)| *ts)
+            .collect();
+        for ts in closed {
+            self.snapshots.remove(&ts);
+        }
+        // a snapshot reads the oldest value replaced after it
+        let oldest = self.snapshots.keys().next().cloned().unwrap_or(u64::MAX);
+        while self.order.front().map(|(ts, _)| *ts <= oldest).unwrap_or(false) {
+            let (ts, key) = self.order.pop_front().unwrap();
+            let chain = self.chains.get_mut(&key).unwrap();
+            chain.pop_front();
+            if chain.is_empty() {
+                self.chains.remove(&key);
+            }
+            self.safe_point = self.safe_point.max(ts);
+        }
+    }
+}
+
+/// Iterate over the (key, value) pairs at a snapshot,
+/// the kept values replace the current values of the same keys, and the keys kept as absent are skipped
+pub struct SnapshotIter<'a> {
+    current: Peekable<PairIter<'a>>,
+    kept: Peekable<KeptIter>,
+    /// both are in reverse key order
+    reverse: bool,
+}
+
+impl<'a> SnapshotIter<'a> {
+    /// `current` are the current pairs in key order, or in reverse key order if `reverse`,
+    /// `kept` are the values returned by `History::range` for the same range
+    pub fn new(
+        current: PairIter<'a>,
+        kept: BTreeMap<Vec<u8>, Option<Vec<u8>>>,
+        reverse: bool,
+    ) -> Self {
+        let kept: KeptIter = if reverse {
+            Box::new(kept.into_iter().rev())
+        } else {
+            Box::new(kept.into_iter())
+        };
+        Self {
+            current: current.peekable(),
+            kept: kept.peekable(),
+            reverse,
+        }
+    }
+}
+
+impl<'a> Iterator for SnapshotIter<'a> {
+    type Item = (Vec<u8>, Vec<u8>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let order = match (self.current.peek(), self.kept.peek()) {
+                (None, None) => return None,
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (Some((current, _)), Some((kept, _))) if self.reverse => kept.cmp(current),
+                (Some((current, _)), Some((kept, _))) => current.cmp(kept),
+            };
+            if order == Ordering::Less {
+                return self.current.next();
+            }
+            if order == Ordering::Equal {
+                self.current.next();
+            }
+            if let Some((key, Some(value))) = self.kept.next() {
+                return Some((key, value));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_history() {
+    let mut history = History::new(10, Duration::from_secs(0));
+    assert!(!history.open(9));
+    // nothing is kept without open snapshots
+    assert!(!history.keeps(11));
+    assert!(!history.open(10));
+    assert!(history.open(11));
+    assert!(history.keeps(12));
+    history.push(b"a".to_vec(), 12, Some(b"1".to_vec()));
+    assert!(history.open(12));
+    history.push(b"a".to_vec(), 13, Some(b"2".to_vec()));
+    history.push(b"b".to_vec(), 13, None);
+    assert_eq!(history.get(b"a", 11), Some(Some(b"1".to_vec())));
+    assert_eq!(history.get(b"a", 12), Some(Some(b"2".to_vec())));
+    assert_eq!(history.get(b"a", 13), None);
+    assert_eq!(history.get(b"b", 12), Some(None));
+    assert_eq!(history.range(b"", None, 12).len(), 2);
+    assert_eq!(history.range(b"b", None, 11), vec![(b"b".to_vec(), None)].into_iter().collect());
+
+    // the values replaced before the oldest snapshot are dropped
+    history.release(11, true);
+    assert_eq!(history.order.len(), 2);
+    assert!(!history.open(11));
+    assert_eq!(history.get(b"a", 12), Some(Some(b"2".to_vec())));
+    history.release(12, true);
+    assert_eq!(history.order.len(), 0);
+    assert!(!history.open(12));
+    assert!(history.open(13));
+
+    // a snapshot is kept until its lease ends
+    let mut history = History::new(0, Duration::from_secs(3600));
+    assert!(history.open(1));
+    history.release(1, true);
+    assert!(history.keeps(2));
+    // a snapshot which is not leased is dropped once it's released, even behind a leased one
+    assert!(history.open(3));
+    assert!(history.open(3));
+    history.release(3, false);
+    assert_eq!(history.snapshots.len(), 2);
+    history.release(3, false);
+    assert_eq!(history.snapshots.keys().collect::<Vec<_>>(), vec![&1]);
+
+    let current = vec![(b"a".to_vec(), b"1".to_vec()), (b"c".to_vec(), b"3".to_vec()), (b"d".to_vec(), b"4".to_vec())];
+    let kept: BTreeMap<_, _> = vec![
+        (b"b".to_vec(), Some(b"2".to_vec())),
+        (b"c".to_vec(), None),
+        (b"d".to_vec(), Some(b"0".to_vec())),
+        (b"e".to_vec(), None),
+    ].into_iter().collect();
+    let pairs = |iter: SnapshotIter| -> Vec<_> { iter.map(|(key, value)| [key, value].concat()).collect() };
+    let iter = SnapshotIter::new(Box::new(current.clone().into_iter()), kept.clone(), false);
+    assert_eq!(pairs(iter), vec![b"a1".to_vec(), b"b2".to_vec(), b"d0".to_vec()]);
+    let iter = SnapshotIter::new(Box::new(current.into_iter().rev()), kept, true);
+    assert_eq!(pairs(iter), vec![b"d0".to_vec(), b"b2".to_vec(), b"a1".to_vec()]);
+}
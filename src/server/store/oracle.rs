@@ -0,0 +1,125 @@
+//! The timestamp oracle of Percolator transactions
+//!
+//! The timestamps must keep increasing after a restart, or a transaction may start before the ones committed already,
+//! then it reads stale values, and its prewrite misses their writes.
+//! They are given by a `RevisionClock`, which only keeps increasing after a restart if the wall clock doesn't go back,
+//! so the oracle saves a limit of the timestamps into a file before giving them, and starts after it when it's opened again.
+//! The limit is reserved some time ahead, so it's only saved once in a while instead of for every timestamp.
+//!
+//! A `Store` gives the versions of its values the same way, with the limit saved in another file next to its redo log.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use crate::store::versioned::{RevisionClock, MAX_VERSION, read_u64};
+
+/// the file in the directory of the oracle which holds the limit of the transaction timestamps
+pub const TIMESTAMP_LIMIT: &str = "TIMESTAMP_LIMIT";
+/// the file in the directory of the redo log of a `Store` which holds the limit of the versions
+pub const VERSION_LIMIT: &str = "VERSION_LIMIT";
+/// how far ahead the limit is reserved, in microseconds like the timestamps
+const RESERVE_AHEAD: u64 = 3_000_000;
+
+#[derive(Clone)]
+pub struct TimestampOracle {
+    clock: RevisionClock,
+    /// the timestamps given are not larger than this, which is saved
+    limit: u64,
+    /// where the limit is saved, it's never saved if it's `None`
+    file: Option<PathBuf>,
+}
+
+impl TimestampOracle {
+    /// open the oracle which saves its limit in `dir`,
+    /// if no limit is saved yet, like for the transactions written by an older version, it starts after `last()`
+    pub fn open<P: AsRef<Path>, F: FnOnce() -> u64>(dir: P, last: F) -> io::Result<Self> {
+        Self::open_file(dir, TIMESTAMP_LIMIT, last)
+    }
+
+    /// open the oracle which saves its limit in the file `name` in `dir`, it starts after `last()` if there is no such file
+    pub fn open_file<P: AsRef<Path>, F: FnOnce() -> u64>(dir: P, name: &str, last: F) -> io::Result<Self> {
+        let file = dir.as_ref().join(name);
+        let limit = match fs::read(&file) {
+            Ok(bytes) if bytes.len() == 8 => read_u64(&bytes),
+            Ok(_) => return Err(io::Error::new(io::ErrorKind::InvalidData, format!("invalid {} file", name))),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => last(),
+            Err(err) => return Err(err),
+        };
+        let mut clock = RevisionClock::default();
+        clock.observe(limit)?;
+        info!("{} starts after {}", name, limit);
+        Ok(Self { clock, limit, file: Some(file) })
+    }
+
+    /// an oracle starting after `last`, which doesn't save its limit, so it only keeps increasing while it's running
+    pub fn unsaved(last: u64) -> io::Result<Self> {
+        let mut clock = RevisionClock::default();
+        clock.observe(last)?;
+        Ok(Self { clock, limit: last, file: None })
+    }
+
+    /// a timestamp larger than all the ones given before, even the ones given before a restart
+    /// return an error if the limit can't be saved, then no timestamp is given
+    pub fn next(&mut self) -> io::Result<u64> {
+        let ts = self.clock.next()?;
+        if ts > self.limit {
+            // the clock can't be opened after a limit which isn't below `MAX_VERSION`
+            let limit = ts.saturating_add(RESERVE_AHEAD).min(MAX_VERSION - 1);
+            self.save(limit)?;
+            self.limit = limit;
+        }
+        Ok(ts)
+    }
+
+    /// the last timestamp given, or the one it starts after
+    pub fn last(&self) -> u64 {
+        self.clock.last()
+    }
+
+    /// make sure the next timestamp is larger than `ts`, like `RevisionClock::observe`
+    pub fn observe(&mut self, ts: u64) -> io::Result<()> {
+        self.clock.observe(ts)
+    }
+
+    /// the limit is written into a temporary file first, so it's never half written
+    fn save(&self, limit: u64) -> io::Result<()> {
+        let file = match &self.file {
+            Some(file) => file,
+            None => return Ok(()),
+        };
+        let tmp_path = file.with_extension("tmp");
+        let mut tmp_file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(&tmp_path)?;
+        tmp_file.write_all(&limit.to_le_bytes())?;
+        tmp_file.sync_all()?;
+        fs::rename(tmp_path, file)?;
+        File::open(file.parent().unwrap_or_else(|| Path::new(".")))?.sync_all()
+    }
+}
+
+#[test]
+fn test_oracle() {
+    use tempfile::tempdir;
+
+    let dir = tempdir().unwrap();
+    // the wall clock is far behind the transactions written before
+    let mut oracle = TimestampOracle::open(dir.path(), || MAX_VERSION / 2).unwrap();
+    let first = oracle.next().unwrap();
+    assert!(first > MAX_VERSION / 2);
+    let second = oracle.next().unwrap();
+    assert!(second > first);
+    drop(oracle);
+
+    // the limit is saved, so the timestamps keep increasing after a restart
+    let mut oracle = TimestampOracle::open(dir.path(), || 0).unwrap();
+    assert!(oracle.next().unwrap() > second);
+    // another file has a limit of its own
+    let mut versions = TimestampOracle::open_file(dir.path(), VERSION_LIMIT, || 0).unwrap();
+    assert!(versions.next().unwrap() < MAX_VERSION / 2);
+    fs::write(dir.path().join(TIMESTAMP_LIMIT), b"limit").unwrap();
+    assert!(TimestampOracle::open(dir.path(), || 0).is_err());
+    assert!(TimestampOracle::open_file(dir.path(), VERSION_LIMIT, || 0).is_ok());
+}
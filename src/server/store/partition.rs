@@ -0,0 +1,234 @@
+//! The keys of a `Store` are spread over several partitions by their hashes,
+//! each partition is a `MemStore` locked on its own, so the writes to different partitions run in parallel.
+//! A partition on a `ConcurrentMemStore` is read without locking it, only its writers take turns.
+//!
+//! The hash decides where the keys are on disk, so it must never change.
+
+use std::io;
+use std::iter::Peekable;
+use std::ops::{Deref, DerefMut};
+use std::sync::{Arc, Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use crate::store::mem_store::{MemStore, ConcurrentMemStore};
+use crate::store::snapshot::Snapshot;
+use mini_kv::shared::crc32c::crc32c;
+
+/// (key, value) pairs in some order
+type PairIter<'a> = Box<dyn Iterator<Item=(Vec<u8>, Vec<u8>)> + 'a>;
+
+/// the index of the partition `key` is in, out of `count` partitions
+pub fn partition_of(key: &[u8], count: usize) -> usize {
+    if count == 1 {
+        0
+    } else {
+        crc32c(key) as usize % count
+    }
+}
+
+/// Merge the (key, value) pairs from several partitions into one sorted iterator
+/// No key is in more than one partition, so nothing is dropped
+pub struct MergedIter<'a> {
+    sources: Vec<Peekable<PairIter<'a>>>,
+    /// the sources are sorted in reverse key order
+    reverse: bool,
+}
+
+impl<'a> MergedIter<'a> {
+    pub fn new(sources: Vec<PairIter<'a>>, reverse: bool) -> Self {
+        Self {
+            sources: sources.into_iter().map(Iterator::peekable).collect(),
+            reverse,
+        }
+    }
+}
+
+impl<'a> Iterator for MergedIter<'a> {
+    type Item = (Vec<u8>, Vec<u8>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let keys = self.sources.iter_mut()
+            .enumerate()
+            .filter_map(|(i, it)| it.peek().map(|(key, _)| (i, key)));
+        let next = if self.reverse {
+            keys.max_by(|(_, a), (_, b)| a.cmp(b))
+        } else {
+            keys.min_by(|(_, a), (_, b)| a.cmp(b))
+        };
+        let (i, _) = next?;
+        self.sources[i].next()
+    }
+}
+
+/// A partition of a `Store`
+pub enum Partition {
+    /// read and written with its lock held, like an lsm-tree, which may switch its memtables in a write
+    Locked(RwLock<Box<dyn MemStore>>),
+    /// read without any lock, the writers still take turns with `writer` held,
+    /// so the writes of a key are logged and applied in the same order
+    Shared {
+        store: Arc<dyn ConcurrentMemStore>,
+        writer: Mutex<()>,
+    },
+}
+
+impl Partition {
+    /// a partition on `store`, which is shared if it's a `ConcurrentMemStore`
+    pub fn new(store: Box<dyn MemStore>) -> Self {
+        match store.shared() {
+            Some(store) => Partition::Shared { store, writer: Mutex::new(()) },
+            None => Partition::Locked(RwLock::new(store)),
+        }
+    }
+
+    /// lock the partition for writing, the readers of a shared partition don't wait for it
+    pub fn write(&self) -> WriteGuard<'_> {
+        match self {
+            Partition::Locked(store) => WriteGuard::Locked(store.write().unwrap()),
+            Partition::Shared { store, writer } => WriteGuard::Shared { _writer: writer.lock().unwrap(), store: store.clone() },
+        }
+    }
+
+    /// lock the partition against the writes
+    pub fn read(&self) -> ReadGuard<'_> {
+        match self {
+            Partition::Locked(store) => ReadGuard::Locked(store.read().unwrap()),
+            Partition::Shared { store, writer } => ReadGuard::Shared { _writer: writer.lock().unwrap(), store },
+        }
+    }
+
+    /// call `f` with the store of the partition, it's only locked for reading if it's not shared
+    pub fn with_store<T, F: FnOnce(&dyn MemStore) -> T>(&self, f: F) -> T {
+        match self {
+            Partition::Locked(store) => f(store.read().unwrap().as_ref()),
+            Partition::Shared { store, .. } => f(store),
+        }
+    }
+}
+
+/// A partition locked for writing
+pub enum WriteGuard<'a> {
+    Locked(RwLockWriteGuard<'a, Box<dyn MemStore>>),
+    /// the writer lock is only held, the store is written through its `Arc`
+    Shared { _writer: MutexGuard<'a, ()>, store: Arc<dyn ConcurrentMemStore> },
+}
+
+impl Deref for WriteGuard<'_> {
+    type Target = dyn MemStore;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            WriteGuard::Locked(store) => store.as_ref(),
+            WriteGuard::Shared { store, .. } => store,
+        }
+    }
+}
+
+impl DerefMut for WriteGuard<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            WriteGuard::Locked(store) => store.as_mut(),
+            WriteGuard::Shared { store, .. } => store,
+        }
+    }
+}
+
+/// A partition locked against the writes
+pub enum ReadGuard<'a> {
+    Locked(RwLockReadGuard<'a, Box<dyn MemStore>>),
+    Shared { _writer: MutexGuard<'a, ()>, store: &'a Arc<dyn ConcurrentMemStore> },
+}
+
+impl Deref for ReadGuard<'_> {
+    type Target = dyn MemStore;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            ReadGuard::Locked(store) => store.as_ref(),
+            ReadGuard::Shared { store, .. } => *store,
+        }
+    }
+}
+
+/// All the partitions of a store, used as one `MemStore` before they are locked apart,
+/// so the redo log can be replayed on them
+pub struct Partitions {
+    pub stores: Vec<Box<dyn MemStore>>,
+    /// whether each partition is written, so it has data not persisted
+    pub written: Vec<bool>,
+}
+
+impl Partitions {
+    pub fn new(stores: Vec<Box<dyn MemStore>>) -> Self {
+        Self {
+            written: vec![false; stores.len()],
+            stores,
+        }
+    }
+
+    fn of(&self, key: &[u8]) -> usize {
+        partition_of(key, self.stores.len())
+    }
+}
+
+impl MemStore for Partitions {
+    fn put(&mut self, key: Vec<u8>, value: Vec<u8>) {
+        let i = self.of(&key);
+        self.written[i] = true;
+        self.stores[i].put(key, value);
+    }
+
+    fn get(&self, key: &[u8]) -> io::Result<Option<Vec<u8>>> {
+        self.stores[self.of(key)].get(key)
+    }
+
+    fn delete(&mut self, key: &[u8]) -> io::Result<Option<Vec<u8>>> {
+        let i = self.of(key);
+        self.written[i] = true;
+        self.stores[i].delete(key)
+    }
+
+    fn range<'a>(&'a self, start: &[u8], end: Option<&[u8]>) -> Box<dyn Iterator<Item=(Vec<u8>, Vec<u8>)> + 'a> {
+        Box::new(MergedIter::new(self.stores.iter().map(|it| it.range(start, end)).collect(), false))
+    }
+
+    fn range_rev<'a>(&'a self, start: &[u8], end: Option<&[u8]>) -> Box<dyn Iterator<Item=(Vec<u8>, Vec<u8>)> + 'a> {
+        Box::new(MergedIter::new(self.stores.iter().map(|it| it.range_rev(start, end)).collect(), true))
+    }
+
+    /// the entries of the partitions one after another, they are not in key order
+    fn snapshot(&self) -> Snapshot {
+        let snapshots: Vec<_> = self.stores.iter().map(|it| it.snapshot()).collect();
+        Snapshot::new(snapshots.into_iter().flatten())
+    }
+
+    fn clear(&mut self) {
+        self.written.iter_mut().for_each(|it| *it = true);
+        self.stores.iter_mut().for_each(|it| it.clear());
+    }
+
+    fn persist(&mut self) -> bool {
+        // every partition gets the chance to persist its data
+        self.stores.iter_mut().map(|it| it.persist()).filter(|persisted| !persisted).count() == 0
+    }
+}
+
+#[test]
+fn test_partitions() {
+    use std::collections::{BTreeMap, HashMap};
+    use crate::store::mem_store::do_test;
+
+    assert_eq!(partition_of(b"a", 1), 0);
+    assert_eq!(partition_of(b"a", 3), partition_of(b"a", 3));
+    let mut partitions = Partitions::new(vec![Box::new(BTreeMap::new()), Box::new(HashMap::new()), Box::new(BTreeMap::new())]);
+    do_test(&mut partitions);
+
+    for i in 0..100u8 {
+        partitions.put(vec![i], vec![i]);
+    }
+    // the keys are spread over all the partitions, and merged back in order
+    assert!(partitions.stores.iter().all(|it| it.range(b"", None).count() > 10));
+    let keys: Vec<_> = partitions.range(&[10], Some(&[20])).map(|(key, _)| key[0]).collect();
+    assert_eq!(keys, (10..20).collect::<Vec<_>>());
+    let keys: Vec<_> = partitions.range_rev(&[10], Some(&[20])).map(|(key, _)| key[0]).collect();
+    assert_eq!(keys, (10..20).rev().collect::<Vec<_>>());
+    assert_eq!(partitions.snapshot().count(), 101);
+}
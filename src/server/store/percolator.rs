@@ -0,0 +1,461 @@
+//! Percolator transactions
+//!
+//! A client takes a start_ts from the timestamp oracle, prewrites all its writes by locking the keys,
+//! then takes a commit_ts and commits the primary key, which is the point the transaction is committed at,
+//! and the secondary keys after that. The locks and the values written are kept in a `Store` of their own,
+//! in three columns told apart by the first byte of the keys:
+//! - lock: `l + key` is the lock on `key`, with the primary key and the start_ts of the transaction holding it
+//! - data: `d + key + start_ts` is the value put by the transaction started at start_ts,
+//!   the timestamp has a fixed size, so no two (key, start_ts) pairs share a data key
+//! - write: `w + encoded key + !commit_ts` is the start_ts of the transaction committed on `key` at commit_ts,
+//!   the write records of a key are next to each other from the newest, see `encode_key`
+//!
+//! A transaction started at start_ts reads the newest value committed at or before start_ts,
+//! and fails on a lock from an older transaction, which may commit before start_ts.
+//! The lock of a client which crashed is resolved by checking the status of its primary key,
+//! which rolls the transaction back if its lock is expired, then committing or rolling back the lock like the primary.
+
+use std::convert::TryInto;
+use std::fmt;
+use std::io;
+use std::time::Duration;
+use crate::store::{Store, LATEST, prefix_end};
+use crate::store::lsm::LsmStats;
+use crate::store::redo_log::{Durable, durable_now};
+use crate::store::versioned::{now_millis, read_u64};
+use crate::store::write_batch::{WriteBatch, WriteOp};
+
+const LOCK: u8 = b'l';
+const DATA: u8 = b'd';
+const WRITE: u8 = b'w';
+
+/// how many write records are read each time when looking for one
+const WRITE_PAGE_SIZE: usize = 16;
+/// how many records are read each time when walking a whole column
+const SCAN_PAGE_SIZE: usize = 1024;
+
+/// encode `key` so the encoded keys are in the same order as the keys, and none of them is a prefix of another,
+/// each 0 byte becomes `0, 0xff`, and `0, 1` ends the key
+fn encode_key(key: &[u8]) -> Vec<u8> {
+    let mut encoded = Vec::with_capacity(key.len() + 2);
+    for &byte in key {
+        encoded.push(byte);
+        if byte == 0 {
+            encoded.push(0xff);
+        }
+    }
+    encoded.extend_from_slice(&[0, 1]);
+    encoded
+}
+
+fn lock_key(key: &[u8]) -> Vec<u8> {
+    [&[LOCK][..], key].concat()
+}
+
+fn data_key(key: &[u8], start_ts: u64) -> Vec<u8> {
+    [&[DATA][..], key, &start_ts.to_be_bytes()].concat()
+}
+
+/// the write records of a key start with this
+fn write_prefix(key: &[u8]) -> Vec<u8> {
+    [&[WRITE][..], &encode_key(key)].concat()
+}
+
+/// the newer records come first
+fn write_key(prefix: &[u8], commit_ts: u64) -> Vec<u8> {
+    [prefix, &(!commit_ts).to_be_bytes()].concat()
+}
+
+/// What a transaction did to a key
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WriteKind {
+    Put,
+    Delete,
+    /// the transaction is rolled back, nothing is written
+    Rollback,
+}
+
+impl WriteKind {
+    fn to_byte(self) -> u8 {
+        match self {
+            WriteKind::Put => 0,
+            WriteKind::Delete => 1,
+            WriteKind::Rollback => 2,
+        }
+    }
+
+    fn from_byte(byte: u8) -> Self {
+        match byte {
+            0 => WriteKind::Put,
+            1 => WriteKind::Delete,
+            _ => WriteKind::Rollback,
+        }
+    }
+}
+
+/// the commit_ts in `write_key`
+fn commit_ts_of(write_key: &[u8]) -> u64 {
+    !u64::from_be_bytes(write_key[write_key.len() - 8..].try_into().unwrap())
+}
+
+/// The lock a transaction holds on a key it prewrote
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lock {
+    /// the transaction is committed once its primary key is
+    pub primary: Vec<u8>,
+    pub start_ts: u64,
+    pub kind: WriteKind,
+    /// in milliseconds since the UNIX epoch, others can roll back the transaction after it
+    pub expire_at: u64,
+}
+
+impl Lock {
+    /// stored as `kind(1 byte) + start_ts(8 bytes) + expire_at(8 bytes) + primary`, little endian
+    fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(17 + self.primary.len());
+        bytes.push(self.kind.to_byte());
+        bytes.extend_from_slice(&self.start_ts.to_le_bytes());
+        bytes.extend_from_slice(&self.expire_at.to_le_bytes());
+        bytes.extend_from_slice(&self.primary);
+        bytes
+    }
+
+    fn decode(bytes: &[u8]) -> Self {
+        Self {
+            kind: WriteKind::from_byte(bytes[0]),
+            start_ts: read_u64(&bytes[1..]),
+            expire_at: read_u64(&bytes[9..]),
+            primary: bytes[17..].to_vec(),
+        }
+    }
+
+    /// the time left before the lock expires
+    pub fn ttl(&self) -> Duration {
+        Duration::from_millis(self.expire_at.saturating_sub(now_millis()))
+    }
+}
+
+/// The record of a transaction committed or rolled back on a key
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Write {
+    pub start_ts: u64,
+    pub kind: WriteKind,
+}
+
+impl Write {
+    /// stored as `kind(1 byte) + start_ts(8 bytes, little endian)`
+    fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![self.kind.to_byte()];
+        bytes.extend_from_slice(&self.start_ts.to_le_bytes());
+        bytes
+    }
+
+    fn decode(bytes: &[u8]) -> Self {
+        Self {
+            kind: WriteKind::from_byte(bytes[0]),
+            start_ts: read_u64(&bytes[1..]),
+        }
+    }
+}
+
+/// Why a request of a transaction failed
+#[derive(Clone, Debug, PartialEq)]
+pub enum PercolatorError {
+    /// the key is locked by another transaction, retry after the lock is resolved
+    Locked(Vec<u8>, Lock),
+    /// the key is committed by another transaction at the timestamp, which is not before the start_ts
+    WriteConflict(Vec<u8>, u64),
+    /// the transaction holds no lock on the key, it's rolled back, by others resolving its locks probably
+    LockNotFound(Vec<u8>),
+    /// the transaction is committed on the key at the timestamp, so it can't be rolled back
+    Committed(Vec<u8>, u64),
+}
+
+impl fmt::Display for PercolatorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PercolatorError::Locked(key, lock) => write!(
+                f, "key {} is locked by the transaction started at {}", String::from_utf8_lossy(key), lock.start_ts
+            ),
+            PercolatorError::WriteConflict(key, commit_ts) => write!(
+                f, "key {} is committed by another transaction at {}", String::from_utf8_lossy(key), commit_ts
+            ),
+            PercolatorError::LockNotFound(key) => write!(
+                f, "lock on key {} is not found, the transaction is rolled back", String::from_utf8_lossy(key)
+            ),
+            PercolatorError::Committed(key, commit_ts) => write!(
+                f, "the transaction is committed on key {} at {}", String::from_utf8_lossy(key), commit_ts
+            ),
+        }
+    }
+}
+
+/// The status of a transaction, decided by its primary key
+#[derive(Clone, Debug, PartialEq)]
+pub enum TxnStatus {
+    /// the transaction may still commit, its primary lock expires after this
+    Locked(Duration),
+    /// the transaction is committed at the timestamp
+    Committed(u64),
+    RolledBack,
+}
+
+pub struct Percolator {
+    /// the lock, data and write columns
+    store: Store,
+}
+
+impl Percolator {
+    pub fn new(store: Store) -> Self {
+        Self { store }
+    }
+
+    /// statistics of the lsm-tree the transactions are stored in, if they are stored in one
+    pub fn stats(&self) -> Option<LsmStats> {
+        self.store.stats().into_iter().next().flatten()
+    }
+
+    fn lock(&self, key: &[u8]) -> io::Result<Option<Lock>> {
+        Ok(self.store.get(&lock_key(key))?.map(|it| Lock::decode(&it.value)))
+    }
+
+    /// the newest write record of `key` committed in [`min_ts`, `max_ts`] which `matches`, and its commit_ts
+    fn find_write<F: Fn(&Write) -> bool>(&self, key: &[u8], min_ts: u64, max_ts: u64, matches: F) -> Option<(u64, Write)> {
+        let prefix = write_prefix(key);
+        let mut start = write_key(&prefix, max_ts);
+        let end = if min_ts == 0 {
+            prefix_end(&prefix)
+        } else {
+            Some(write_key(&prefix, min_ts - 1))
+        };
+        loop {
+            let (pairs, next_key) = self.store.range_scan(&start, end.as_deref(), WRITE_PAGE_SIZE, false, LATEST);
+            for (write_key, value) in pairs {
+                let write = Write::decode(&value);
+                if matches(&write) {
+                    return Some((commit_ts_of(&write_key), write));
+                }
+            }
+            start = next_key?;
+        }
+    }
+
+    /// the largest timestamp of the locks and the write records, the start_ts of the values written are in one of them
+    pub fn max_timestamp(&self) -> u64 {
+        let mut max = 0;
+        for &column in &[LOCK, WRITE] {
+            let mut start = vec![column];
+            let end = prefix_end(&start);
+            loop {
+                let (pairs, next_key) = self.store.range_scan(&start, end.as_deref(), SCAN_PAGE_SIZE, false, LATEST);
+                for (key, value) in pairs {
+                    // a commit_ts is larger than the start_ts of its transaction
+                    let ts = if column == LOCK { Lock::decode(&value).start_ts } else { commit_ts_of(&key) };
+                    max = max.max(ts);
+                }
+                match next_key {
+                    Some(key) => start = key,
+                    None => break,
+                }
+            }
+        }
+        max
+    }
+
+    /// the record of the transaction started at `start_ts` on `key`, and its commit_ts
+    fn txn_write(&self, key: &[u8], start_ts: u64) -> Option<(u64, Write)> {
+        self.find_write(key, start_ts, u64::MAX, |write| write.start_ts == start_ts)
+    }
+
+    /// get the value of `key` committed last at or before `start_ts`
+    pub fn get(&self, key: &[u8], start_ts: u64) -> io::Result<Result<Option<Vec<u8>>, PercolatorError>> {
+        // a transaction started later doesn't affect the value, but an older one may commit before start_ts
+        if let Some(lock) = self.lock(key)?.filter(|it| it.start_ts <= start_ts) {
+            return Ok(Err(PercolatorError::Locked(key.to_vec(), lock)));
+        }
+        match self.find_write(key, 0, start_ts, |write| write.kind != WriteKind::Rollback) {
+            Some((_, Write { start_ts, kind: WriteKind::Put })) => {
+                Ok(Ok(self.store.get(&data_key(key, start_ts))?.map(|it| it.value)))
+            }
+            _ => Ok(Ok(None)),
+        }
+    }
+
+    /// lock the keys of `mutations` for the transaction started at `start_ts` and write the values put,
+    /// the locks expire after `ttl`, nothing is written if any key is locked by others or committed after `start_ts`
+    /// the returned `Durable` resolves when the locks are persisted
+    pub fn prewrite(&mut self, mutations: Vec<WriteOp>, primary: &[u8], start_ts: u64, ttl: Duration) -> io::Result<(Result<(), PercolatorError>, Durable)> {
+        for mutation in &mutations {
+            let key = mutation.key();
+            // a rollback of the transaction is found here too, so a prewrite arriving after it fails
+            if let Some((commit_ts, _)) = self.find_write(key, start_ts, u64::MAX, |_| true) {
+                return Ok((Err(PercolatorError::WriteConflict(key.to_vec(), commit_ts)), durable_now()));
+            }
+            // the lock of the transaction itself is there if the prewrite is retried
+            if let Some(lock) = self.lock(key)?.filter(|it| it.start_ts != start_ts) {
+                return Ok((Err(PercolatorError::Locked(key.to_vec(), lock)), durable_now()));
+            }
+        }
+        let expire_at = now_millis().saturating_add(ttl.as_millis() as u64);
+        let mut batch = WriteBatch::new();
+        for mutation in mutations {
+            let (key, kind) = match mutation {
+                WriteOp::Put(key, value) => {
+                    batch.put(data_key(&key, start_ts), value);
+                    (key, WriteKind::Put)
+                }
+                WriteOp::Delete(key) => (key, WriteKind::Delete),
+            };
+            let lock = Lock { primary: primary.to_vec(), start_ts, kind, expire_at };
+            batch.put(lock_key(&key), lock.encode());
+        }
+        let (_, durable) = self.store.write_batch(batch)?;
+        Ok((Ok(()), durable))
+    }
+
+    /// commit `keys` locked by the transaction started at `start_ts` at `commit_ts`, the keys committed already are skipped
+    /// nothing is committed if any key is not locked by the transaction
+    /// the returned `Durable` resolves when the commit is persisted
+    pub fn commit(&mut self, keys: &[Vec<u8>], start_ts: u64, commit_ts: u64) -> io::Result<(Result<(), PercolatorError>, Durable)> {
+        let mut batch = WriteBatch::new();
+        for key in keys {
+            match self.lock(key)? {
+                Some(lock) if lock.start_ts == start_ts => {
+                    let write = Write { start_ts, kind: lock.kind };
+                    batch.put(write_key(&write_prefix(key), commit_ts), write.encode());
+                    batch.delete(lock_key(key));
+                }
+                _ => match self.txn_write(key, start_ts) {
+                    Some((_, write)) if write.kind != WriteKind::Rollback => {}
+                    _ => return Ok((Err(PercolatorError::LockNotFound(key.clone())), durable_now())),
+                },
+            }
+        }
+        let (_, durable) = self.store.write_batch(batch)?;
+        Ok((Ok(()), durable))
+    }
+
+    /// roll back the transaction started at `start_ts` on `keys`, remove its locks and values,
+    /// and leave a record so it can't prewrite or commit the keys later
+    /// nothing is rolled back if the transaction is committed on any key
+    /// the returned `Durable` resolves when the rollback is persisted
+    pub fn rollback(&mut self, keys: &[Vec<u8>], start_ts: u64) -> io::Result<(Result<(), PercolatorError>, Durable)> {
+        let mut batch = WriteBatch::new();
+        for key in keys {
+            match self.txn_write(key, start_ts) {
+                Some((commit_ts, write)) if write.kind != WriteKind::Rollback => {
+                    return Ok((Err(PercolatorError::Committed(key.clone(), commit_ts)), durable_now()));
+                }
+                Some(_) => {}
+                None => {
+                    if self.lock(key)?.map(|it| it.start_ts == start_ts).unwrap_or(false) {
+                        batch.delete(lock_key(key));
+                        batch.delete(data_key(key, start_ts));
+                    }
+                    // the timestamps are unique, so no transaction commits at start_ts
+                    let write = Write { start_ts, kind: WriteKind::Rollback };
+                    batch.put(write_key(&write_prefix(key), start_ts), write.encode());
+                }
+            }
+        }
+        let (_, durable) = self.store.write_batch(batch)?;
+        Ok((Ok(()), durable))
+    }
+
+    /// the status of the transaction started at `start_ts` with the primary key `primary`,
+    /// it's rolled back if its primary lock is expired or missing, so it can't commit after that
+    /// the returned `Durable` resolves when the rollback is persisted
+    pub fn check_txn_status(&mut self, primary: &[u8], start_ts: u64) -> io::Result<(TxnStatus, Durable)> {
+        if let Some(lock) = self.lock(primary)?.filter(|it| it.start_ts == start_ts) {
+            if lock.expire_at > now_millis() {
+                return Ok((TxnStatus::Locked(lock.ttl()), durable_now()));
+            }
+        }
+        match self.txn_write(primary, start_ts) {
+            Some((commit_ts, write)) if write.kind != WriteKind::Rollback => Ok((TxnStatus::Committed(commit_ts), durable_now())),
+            Some(_) => Ok((TxnStatus::RolledBack, durable_now())),
+            None => {
+                // the client crashed before committing, or its prewrite hasn't arrived yet
+                let (_, durable) = self.rollback(&[primary.to_vec()], start_ts)?;
+                Ok((TxnStatus::RolledBack, durable))
+            }
+        }
+    }
+}
+
+#[test]
+fn test_encode_key() {
+    let keys = vec![&b""[..], b"\0", b"\0\0", b"\0\x01", b"a", b"a\0", b"a\0b", b"ab", b"b"];
+    let encoded: Vec<_> = keys.iter().map(|key| encode_key(key)).collect();
+    for pair in encoded.windows(2) {
+        assert!(pair[0] < pair[1]);
+        assert!(!pair[1].starts_with(&pair[0]));
+    }
+    let lock = Lock { primary: b"p".to_vec(), start_ts: 3, kind: WriteKind::Delete, expire_at: 5 };
+    assert_eq!(Lock::decode(&lock.encode()), lock);
+    let write = Write { start_ts: 3, kind: WriteKind::Rollback };
+    assert_eq!(Write::decode(&write.encode()), write);
+}
+
+#[test]
+fn test_percolator() {
+    use std::collections::BTreeMap;
+    use futures01::Future;
+    use tempfile::tempdir;
+    use crate::store::redo_log::{SegmentedLog, LogOptions, Durability};
+
+    let dir = tempdir().unwrap();
+    let options = LogOptions { durability: Durability::Os, ..LogOptions::default() };
+    let mut txns = Percolator::new(Store::new(BTreeMap::new(), SegmentedLog::open(dir.path(), options).unwrap()));
+    let ttl = Duration::from_secs(3600);
+    let mutations = vec![WriteOp::Put(b"a".to_vec(), b"1".to_vec()), WriteOp::Put(b"b".to_vec(), b"1".to_vec())];
+    txns.prewrite(mutations, b"a", 10, ttl).unwrap().1.wait().unwrap();
+    assert_eq!(txns.get(b"a", 9).unwrap(), Ok(None));
+    assert!(matches!(txns.get(b"b", 11).unwrap(), Err(PercolatorError::Locked(_, Lock { start_ts: 10, .. }))));
+    let (result, _) = txns.prewrite(vec![WriteOp::Delete(b"b".to_vec())], b"b", 12, ttl).unwrap();
+    assert!(matches!(result, Err(PercolatorError::Locked(_, _))));
+    assert!(matches!(txns.check_txn_status(b"a", 10).unwrap().0, TxnStatus::Locked(_)));
+
+    // the client commits the primary key, then crashes before committing the secondary one
+    txns.commit(&[b"a".to_vec()], 10, 15).unwrap().1.wait().unwrap();
+    assert_eq!(txns.get(b"a", 15).unwrap(), Ok(Some(b"1".to_vec())));
+    assert_eq!(txns.get(b"a", 14).unwrap(), Ok(None));
+    let lock = match txns.get(b"b", 20).unwrap() {
+        Err(PercolatorError::Locked(_, lock)) => lock,
+        result => panic!("{:?}", result),
+    };
+    assert_eq!(txns.check_txn_status(&lock.primary, lock.start_ts).unwrap().0, TxnStatus::Committed(15));
+    txns.commit(&[b"b".to_vec()], lock.start_ts, 15).unwrap().1.wait().unwrap();
+    assert_eq!(txns.get(b"b", 20).unwrap(), Ok(Some(b"1".to_vec())));
+    assert_eq!(txns.rollback(&[b"a".to_vec()], 10).unwrap().0, Err(PercolatorError::Committed(b"a".to_vec(), 15)));
+    // committing again is fine
+    assert_eq!(txns.commit(&[b"a".to_vec(), b"b".to_vec()], 10, 15).unwrap().0, Ok(()));
+    let (result, _) = txns.prewrite(vec![WriteOp::Delete(b"a".to_vec())], b"a", 13, ttl).unwrap();
+    assert_eq!(result, Err(PercolatorError::WriteConflict(b"a".to_vec(), 15)));
+
+    // the client crashes before committing the primary key, its locks are rolled back after they expire
+    let mutations = vec![WriteOp::Delete(b"a".to_vec()), WriteOp::Put(b"c".to_vec(), b"3".to_vec())];
+    txns.prewrite(mutations, b"c", 30, Duration::from_millis(0)).unwrap().1.wait().unwrap();
+    let (status, durable) = txns.check_txn_status(b"c", 30).unwrap();
+    durable.wait().unwrap();
+    assert_eq!(status, TxnStatus::RolledBack);
+    assert_eq!(txns.get(b"c", 40).unwrap(), Ok(None));
+    txns.rollback(&[b"a".to_vec()], 30).unwrap().1.wait().unwrap();
+    assert_eq!(txns.get(b"a", 40).unwrap(), Ok(Some(b"1".to_vec())));
+    assert_eq!(txns.commit(&[b"c".to_vec()], 30, 35).unwrap().0, Err(PercolatorError::LockNotFound(b"c".to_vec())));
+    let (result, _) = txns.prewrite(vec![WriteOp::Put(b"c".to_vec(), vec![])], b"c", 30, ttl).unwrap();
+    assert_eq!(result, Err(PercolatorError::WriteConflict(b"c".to_vec(), 30)));
+    // a transaction never prewritten can't be committed after its status is checked
+    assert_eq!(txns.check_txn_status(b"d", 50).unwrap().0, TxnStatus::RolledBack);
+
+    txns.prewrite(vec![WriteOp::Delete(b"a".to_vec())], b"a", 60, ttl).unwrap().1.wait().unwrap();
+    txns.commit(&[b"a".to_vec()], 60, 61).unwrap().1.wait().unwrap();
+    drop(txns);
+
+    // the locks and the writes survive a restart
+    let txns = Percolator::new(Store::new(BTreeMap::new(), SegmentedLog::open(dir.path(), options).unwrap()));
+    assert_eq!(txns.max_timestamp(), 61);
+    assert_eq!(txns.get(b"a", 60).unwrap(), Ok(Some(b"1".to_vec())));
+    assert_eq!(txns.get(b"a", 61).unwrap(), Ok(None));
+    assert_eq!(txns.get(b"b", 61).unwrap(), Ok(Some(b"1".to_vec())));
+}
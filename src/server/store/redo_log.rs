@@ -1,16 +1,459 @@
-use std::io::{Write, Read};
+//! The redo log is split into segment files `<id>.log` in a directory, with increasing ids
+//!
+//! A segment file is made up of a header and a sequence of records.
+//! The header is the magic number `MKVLOG03`, the last two bytes is the version of the format.
+//! Each record is `length(4 bytes) + crc32c(4 bytes) + payload(length bytes)`,
+//! `crc32c` is the checksum of the payload, and the payload is one of
+//! - `OP_PUT(1 byte) + key length(4 bytes) + key + value`
+//! - `OP_DELETE(1 byte) + key`
+//! - `OP_BATCH(1 byte) + (OP_PUT or OP_DELETE(1 byte) + key length(4 bytes) + value length(4 bytes) + key + value) * n`,
+//!   the value of a delete is empty
+//!
+//! The writes in a batch record are redone all together or not at all.
+//!
+//! The values begin with their versions since `MKVLOG03`, see `store::versioned`.
+//!
+//! All integers are in little endian.
+//!
+//! A crash can leave a partly written record at the end of the file, it is detected by the
+//! length and the checksum, and is truncated when redoing.
+//!
+//! When the records reach the disk is controlled by `Durability`.
+//!
+//! A new segment is started when the current one is large enough. After some segments,
+//! the contents of the store are written into a checkpoint `<id>.checkpoint`,
+//! which takes the place of all the segments before segment `<id>`, so these segments are removed,
+//! and only the checkpoint and the segments after it are replayed when starting.
+//! The checkpoint is written into `<id>.tmp` first, without the log locked, and renamed when it's complete.
+//! The segments are removed as well once the store has persisted all the writes in them.
+
+use std::io::{self, Write, Read, BufReader};
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+use futures01::Future;
+use futures01::sync::oneshot;
+use crate::store::snapshot::{Snapshot, load_snapshot};
+use crate::store::group_commit::CommitQueue;
 use crate::store::mem_store::MemStore;
+use crate::store::write_batch::{WriteBatch, WriteOp};
+use crate::store::MAX_ENTRY_SIZE;
+use mini_kv::shared::crc32c::crc32c;
+
+const MAGIC: &[u8; 8] = b"MKVLOG03";
+/// length(4 bytes) + crc32c(4 bytes)
+const RECORD_HEADER_SIZE: usize = 8;
+/// records larger than this must be corrupted, batches are limited by `MAX_ENTRY_SIZE` too
+const MAX_RECORD_SIZE: u32 = (MAX_ENTRY_SIZE + 1 + 4) as u32;
+
+const OP_PUT: u8 = 1;
+const OP_DELETE: u8 = 2;
+const OP_BATCH: u8 = 3;
 
 /// An abstraction of RedoLog written by this kv store service
 pub trait RedoLog: Send {
     /// write a put command into log
-    fn log_put(&mut self, key: [u8; 8], value: &[u8; 256]);
+    fn log_put(&mut self, key: &[u8], value: &[u8]);
 
     /// write a delete command into log
-    fn log_delete(&mut self, key: [u8; 8]);
+    fn log_delete(&mut self, key: &[u8]);
+
+    /// write all the writes in `batch` into log as one command, so they are redone all together or not at all
+    fn log_batch(&mut self, batch: &WriteBatch);
 
     /// redo all the logs on store
     fn redo(&mut self, store: &mut dyn MemStore);
+
+    /// the position the logs written after are at, it only grows
+    fn position(&mut self) -> u64;
+
+    /// remove the logs before `position`, called when the data they wrote has been persisted by the store
+    fn clear_before(&mut self, position: u64);
+
+    /// whether it's time to call `checkpoint`
+    fn should_checkpoint(&self) -> bool {
+        false
+    }
+
+    /// save `snapshot` of the store, so the logs written before can be removed
+    fn checkpoint(&mut self, _snapshot: Snapshot) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// begin a checkpoint at the current position if it's time for one and no other one is being written,
+    /// the snapshot of the store taken now is written with `PendingCheckpoint::write` without the log locked
+    fn begin_checkpoint(&mut self) -> Option<PendingCheckpoint> {
+        None
+    }
+
+    /// save `checkpoint` after its snapshot is written, so the logs before it can be removed
+    /// return `false` if it's not saved, like when it failed or the logs since it are already removed
+    fn finish_checkpoint(&mut self, _checkpoint: PendingCheckpoint) -> bool {
+        false
+    }
+
+    /// return a `Durable` which resolves when all the logs written before are persisted
+    fn durable(&mut self) -> Durable;
+
+    /// whether any log has failed to be written, so the writes in it can't be redone
+    fn failed(&self) -> bool {
+        false
+    }
+
+    /// the directory the logs are written in, where the store saves the limit of its versions,
+    /// `None` if they are not written into one
+    fn dir(&self) -> Option<&Path> {
+        None
+    }
+}
+
+/// Resolves when the logs written before it are persisted as required by `Durability`
+/// Fails if the logs cannot be persisted
+pub type Durable = oneshot::Receiver<()>;
+
+/// a `Durable` which is already resolved
+pub fn durable_now() -> Durable {
+    let (sender, receiver) = oneshot::channel();
+    sender.send(()).unwrap_or(());
+    receiver
+}
+
+/// When are the logs synced to disk
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Durability {
+    /// fsync after every write, the safest and the slowest
+    Fsync,
+    /// fsync all the writes during the interval together
+    GroupCommit(Duration),
+    /// leave it to the OS, acknowledged writes may be lost on power loss
+    Os,
+}
+
+impl FromStr for Durability {
+    type Err = String;
+
+    /// parse "fsync", "os" or "group-commit:<interval in ms>"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "fsync" => Ok(Durability::Fsync),
+            "os" => Ok(Durability::Os),
+            _ if s.starts_with("group-commit:") => u64::from_str(&s["group-commit:".len()..])
+                .map(|ms| Durability::GroupCommit(Duration::from_millis(ms)))
+                .map_err(|_| format!("invalid group commit interval in {}", s)),
+            _ => Err(format!("unknown durability {}", s)),
+        }
+    }
+}
+
+impl fmt::Display for Durability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Durability::Fsync => write!(f, "fsync on every write"),
+            Durability::GroupCommit(interval) => write!(f, "group commit every {}ms", interval.as_millis()),
+            Durability::Os => write!(f, "OS-managed"),
+        }
+    }
+}
+
+/// Options of `SegmentedLog`
+#[derive(Clone, Copy, Debug)]
+pub struct LogOptions {
+    /// when are the records synced to disk
+    pub durability: Durability,
+    /// max count of records written and synced together
+    pub max_batch: usize,
+    /// start a new segment when the current one grows larger than this
+    pub segment_size: u64,
+    /// write a checkpoint when this many segments are filled since the last checkpoint,
+    /// 0 to disable checkpoints
+    pub checkpoint_segments: usize,
+}
+
+impl Default for LogOptions {
+    fn default() -> Self {
+        Self {
+            durability: Durability::Fsync,
+            max_batch: 1024,
+            segment_size: 16 * 1024 * 1024,
+            checkpoint_segments: 4,
+        }
+    }
+}
+
+/// A checkpoint begun by `RedoLog::begin_checkpoint`
+pub struct PendingCheckpoint {
+    /// the position the checkpoint is at
+    position: u64,
+    tmp_path: PathBuf,
+    /// the count of entries written, `None` if the snapshot is not written yet
+    count: Option<u64>,
+}
+
+impl PendingCheckpoint {
+    /// the position the checkpoint is at, the logs after it are not in the checkpoint
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// write `snapshot` into the temporary file of the checkpoint, and sync it
+    pub fn write(&mut self, snapshot: Snapshot) -> io::Result<()> {
+        self.count = Some(snapshot.write_file(&self.tmp_path)?);
+        Ok(())
+    }
+}
+
+/// A redo log stored in segment files in a directory
+/// The records are written by the writer thread of `CommitQueue`, so concurrent writes are
+/// written and synced together
+pub struct SegmentedLog {
+    dir: PathBuf,
+    options: LogOptions,
+    /// ids of the segments on disk, the last one is being written
+    segments: Vec<u64>,
+    /// size of the last segment, including the records still in the queue
+    current_size: u64,
+    /// id of the latest checkpoint
+    checkpoint: Option<u64>,
+    /// id of the checkpoint being written
+    pending_checkpoint: Option<u64>,
+    queue: CommitQueue,
+}
+
+fn segment_path(dir: &Path, id: u64) -> PathBuf {
+    dir.join(format!("{:06}.log", id))
+}
+
+fn checkpoint_path(dir: &Path, id: u64) -> PathBuf {
+    dir.join(format!("{:06}.checkpoint", id))
+}
+
+fn open_segment(path: &Path) -> io::Result<File> {
+    OpenOptions::new().read(true).append(true).open(path)
+}
+
+/// create a new segment file with only the header in it
+fn create_segment(dir: &Path, id: u64) -> io::Result<File> {
+    let mut file = OpenOptions::new()
+        .create_new(true)
+        .read(true)
+        .append(true)
+        .open(segment_path(dir, id))?;
+    file.write_all(MAGIC)?;
+    file.sync_all()?;
+    File::open(dir)?.sync_all()?;
+    Ok(file)
+}
+
+/// the sorted ids of the files named `<id>.<extension>` in `dir`
+fn list_files(dir: &Path, extension: &str) -> io::Result<Vec<u64>> {
+    let mut ids = vec![];
+    for dir_entry in fs::read_dir(dir)? {
+        let path = dir_entry?.path();
+        if path.extension().map(|it| it != extension).unwrap_or(true) {
+            continue;
+        }
+        let id = path.file_stem()
+            .and_then(|it| it.to_str())
+            .and_then(|it| u64::from_str(it).ok());
+        if let Some(id) = id {
+            ids.push(id);
+        }
+    }
+    ids.sort();
+    Ok(ids)
+}
+
+/// replay the records in the segment file at `path` on `store`
+/// return the size of the valid part of the file and the size of the file
+fn replay_segment(path: &Path, store: &mut dyn MemStore) -> io::Result<(u64, u64)> {
+    let file = File::open(path)?;
+    let file_size = file.metadata()?.len();
+    if file_size < MAGIC.len() as u64 {
+        // crashed when creating the segment
+        return Ok((0, file_size));
+    }
+    let mut reader = BufReader::new(file);
+    let mut magic = [0u8; 8];
+    reader.read_exact(&mut magic)?;
+    if &magic != MAGIC {
+        panic!("Unsupported redo log format!");
+    }
+    let mut valid_size = MAGIC.len() as u64;
+    while let Some(payload) = read_record(&mut reader)? {
+        if !apply_payload(&payload, store) {
+            break;
+        }
+        valid_size += (RECORD_HEADER_SIZE + payload.len()) as u64;
+    }
+    Ok((valid_size, file_size))
+}
+
+impl SegmentedLog {
+    /// open the redo log in `dir`, create it if not exists
+    pub fn open<P: AsRef<Path>>(dir: P, options: LogOptions) -> io::Result<Self> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+        info!(
+            "redo log durability: {}, at most {} records in a batch, {} bytes in a segment",
+            options.durability, options.max_batch, options.segment_size
+        );
+        // a checkpoint left by a crash when writing it
+        for id in list_files(dir, "tmp")? {
+            fs::remove_file(dir.join(format!("{:06}.tmp", id)))?;
+        }
+        let checkpoints = list_files(dir, "checkpoint")?;
+        let checkpoint = checkpoints.last().cloned();
+        let mut segments = list_files(dir, "log")?;
+        if let Some(checkpoint) = checkpoint {
+            // files left by a crash when removing the ones replaced by the checkpoint
+            for id in checkpoints.iter().filter(|it| **it < checkpoint) {
+                fs::remove_file(checkpoint_path(dir, *id))?;
+            }
+            for id in segments.iter().filter(|it| **it < checkpoint) {
+                fs::remove_file(segment_path(dir, *id))?;
+            }
+            segments.retain(|it| *it >= checkpoint);
+        }
+        let file = match segments.last() {
+            Some(id) => open_segment(&segment_path(dir, *id))?,
+            None => {
+                let id = checkpoint.unwrap_or(1);
+                segments.push(id);
+                create_segment(dir, id)?
+            }
+        };
+        let current_size = file.metadata()?.len();
+        let queue = CommitQueue::new(file, options.durability, options.max_batch)?;
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            options,
+            segments,
+            current_size,
+            checkpoint,
+            pending_checkpoint: None,
+            queue,
+        })
+    }
+
+    fn current(&self) -> u64 {
+        *self.segments.last().unwrap()
+    }
+
+    fn write_record(&mut self, payload: &[u8]) {
+        let record = encode_record(payload);
+        self.current_size += record.len() as u64;
+        self.queue.write(record);
+        if self.current_size >= self.options.segment_size {
+            self.rotate().unwrap_or_else(log_error_handler);
+        }
+    }
+
+    /// write the records after into a new segment
+    fn rotate(&mut self) -> io::Result<()> {
+        let id = self.current() + 1;
+        let file = create_segment(&self.dir, id)?;
+        self.current_size = MAGIC.len() as u64;
+        self.queue.rotate(file);
+        self.segments.push(id);
+        info!("redo log rotated to segment {}", id);
+        Ok(())
+    }
+
+    /// write `snapshot` into a checkpoint before a new segment, and remove the files before it
+    /// return an error only if the checkpoint isn't saved
+    fn write_checkpoint(&mut self, snapshot: Snapshot) -> io::Result<()> {
+        self.rotate()?;
+        let id = self.current();
+        let count = snapshot.save(&checkpoint_path(&self.dir, id))?;
+        self.replace_checkpoint(id, count).unwrap_or_else(log_error_handler);
+        Ok(())
+    }
+
+    /// begin a checkpoint before a new segment, its snapshot is written into a temporary file
+    fn new_checkpoint(&mut self) -> io::Result<PendingCheckpoint> {
+        self.rotate()?;
+        let id = self.current();
+        Ok(PendingCheckpoint {
+            position: id,
+            tmp_path: self.dir.join(format!("{:06}.tmp", id)),
+            count: None,
+        })
+    }
+
+    /// rename the written `checkpoint` into place, and remove the files before it
+    /// it's dropped if the segments since it are removed, the data in them is persisted by the store,
+    /// or a newer checkpoint is saved, so the data in it is older than the one replayed after it
+    fn save_checkpoint(&mut self, checkpoint: PendingCheckpoint) -> io::Result<bool> {
+        let id = checkpoint.position;
+        let count = match checkpoint.count {
+            Some(count) if self.segments.first().map(|it| *it <= id).unwrap_or(false) => count,
+            _ => {
+                info!("checkpoint {} is dropped", id);
+                return match fs::remove_file(&checkpoint.tmp_path) {
+                    Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+                    _ => Ok(false),
+                };
+            }
+        };
+        fs::rename(&checkpoint.tmp_path, checkpoint_path(&self.dir, id))?;
+        File::open(&self.dir)?.sync_all()?;
+        self.replace_checkpoint(id, count).map(|_| true)
+    }
+
+    /// take checkpoint `id` with `count` entries as the latest one, and remove the files before it
+    fn replace_checkpoint(&mut self, id: u64, count: u64) -> io::Result<()> {
+        let old_checkpoint = self.checkpoint.replace(id);
+        let (removed, kept): (Vec<_>, Vec<_>) = self.segments.iter().partition(|it| **it < id);
+        self.segments = kept;
+        info!("checkpoint {} written with {} entries, removing {} segments before it", id, count, removed.len());
+        // the records still in the queue may be written into the removed segment, it doesn't matter
+        // since they are in the checkpoint
+        for segment in removed {
+            fs::remove_file(segment_path(&self.dir, segment))?;
+        }
+        if let Some(old_checkpoint) = old_checkpoint {
+            fs::remove_file(checkpoint_path(&self.dir, old_checkpoint))?;
+        }
+        Ok(())
+    }
+
+    /// remove the segments before `id`, and the checkpoint if it's before `id` too
+    fn remove_before(&mut self, id: u64) -> io::Result<()> {
+        // the checkpoint goes first, it must not be replayed without the segments after it
+        if let Some(checkpoint) = self.checkpoint.filter(|it| *it < id) {
+            fs::remove_file(checkpoint_path(&self.dir, checkpoint))?;
+            self.checkpoint = None;
+        }
+        let (removed, kept): (Vec<_>, Vec<_>) = self.segments.iter().partition(|it| **it < id);
+        self.segments = kept;
+        if !removed.is_empty() {
+            info!("removing {} redo log segments before {}", removed.len(), id);
+        }
+        for segment in removed {
+            fs::remove_file(segment_path(&self.dir, segment))?;
+        }
+        Ok(())
+    }
+
+    /// truncate segment `id` to `size` and write the records after into it,
+    /// the segments after it are removed
+    fn truncate(&mut self, id: u64, size: u64) -> io::Result<()> {
+        while self.current() != id {
+            let segment = self.segments.pop().unwrap();
+            warn!("removing redo log segment {} after the corrupted one", segment);
+            fs::remove_file(segment_path(&self.dir, segment))?;
+        }
+        let mut file = open_segment(&segment_path(&self.dir, id))?;
+        file.set_len(size)?;
+        if size == 0 {
+            file.write_all(MAGIC)?;
+        }
+        self.current_size = file.metadata()?.len();
+        self.queue.rotate(file);
+        Ok(())
+    }
 }
 
 fn log_error_handler(err: std::io::Error) {
@@ -21,77 +464,541 @@ fn redo_error_handler(err: std::io::Error) {
     error!("read redolog failed: {:?}", err)
 }
 
-impl<T: Read + Write + Send> RedoLog for T {
-    fn log_put(&mut self, key: [u8; 8], value: &[u8; 256]) {
-        self.write_all(b"   put").unwrap_or_else(log_error_handler);
-        self.write_all(&key).unwrap_or_else(log_error_handler);
-        self.write_all(value).unwrap_or_else(log_error_handler);
-        self.flush().unwrap_or(());
+/// frame `payload` as a record
+fn encode_record(payload: &[u8]) -> Vec<u8> {
+    let mut record = Vec::with_capacity(RECORD_HEADER_SIZE + payload.len());
+    record.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    record.extend_from_slice(&crc32c(payload).to_le_bytes());
+    record.extend_from_slice(payload);
+    record
+}
+
+fn read_u32(buffer: &[u8]) -> u32 {
+    let mut bytes = [0u8; 4];
+    bytes.copy_from_slice(&buffer[..4]);
+    u32::from_le_bytes(bytes)
+}
+
+/// decode the payload of `OP_BATCH`, return `None` if it's invalid
+fn decode_batch(mut rest: &[u8]) -> Option<WriteBatch> {
+    let mut ops = vec![];
+    while !rest.is_empty() {
+        if rest.len() < 9 {
+            return None;
+        }
+        let op = rest[0];
+        let key_len = read_u32(&rest[1..]) as usize;
+        let value_len = read_u32(&rest[5..]) as usize;
+        rest = &rest[9..];
+        if rest.len() < key_len + value_len {
+            return None;
+        }
+        let (key, value) = rest[..key_len + value_len].split_at(key_len);
+        ops.push(match op {
+            OP_PUT => WriteOp::Put(key.to_vec(), value.to_vec()),
+            OP_DELETE if value.is_empty() => WriteOp::Delete(key.to_vec()),
+            _ => return None,
+        });
+        rest = &rest[key_len + value_len..];
     }
+    Some(ops.into())
+}
 
-    fn log_delete(&mut self, key: [u8; 8]) {
-        self.write_all(b"delete").unwrap_or_else(log_error_handler);
-        self.write_all(&key).unwrap_or_else(log_error_handler);
-        self.flush().unwrap_or_else(log_error_handler);
+/// apply the operation in `payload` on `store`, return `false` if the payload is invalid
+fn apply_payload(payload: &[u8], store: &mut dyn MemStore) -> bool {
+    match payload.split_first() {
+        Some((&OP_PUT, rest)) if rest.len() >= 4 => {
+            let mut key_len = [0u8; 4];
+            key_len.copy_from_slice(&rest[..4]);
+            let key_len = u32::from_le_bytes(key_len) as usize;
+            if rest.len() - 4 < key_len {
+                return false;
+            }
+            let (key, value) = rest[4..].split_at(key_len);
+            store.put(key.to_vec(), value.to_vec());
+            true
+        }
+        Some((&OP_DELETE, key)) => {
+            // the key is deleted even if the value it had can't be read, which isn't needed here
+            let _ = store.delete(key);
+            true
+        }
+        // check the whole batch before applying any of it
+        Some((&OP_BATCH, rest)) => match decode_batch(rest) {
+            Some(batch) => {
+                batch.apply(store);
+                true
+            }
+            None => false,
+        },
+        _ => false,
+    }
+}
+
+/// read the payload of next record, return `Ok(None)` if there's no complete and valid record left
+fn read_record<R: Read>(reader: &mut R) -> io::Result<Option<Vec<u8>>> {
+    let mut header = [0u8; RECORD_HEADER_SIZE];
+    if let Err(err) = reader.read_exact(&mut header) {
+        return if err.kind() == io::ErrorKind::UnexpectedEof { Ok(None) } else { Err(err) };
+    }
+    let mut length = [0u8; 4];
+    length.copy_from_slice(&header[..4]);
+    let length = u32::from_le_bytes(length);
+    let mut checksum = [0u8; 4];
+    checksum.copy_from_slice(&header[4..]);
+    let checksum = u32::from_le_bytes(checksum);
+    if length > MAX_RECORD_SIZE {
+        return Ok(None);
+    }
+    let mut payload = vec![0u8; length as usize];
+    if let Err(err) = reader.read_exact(&mut payload) {
+        return if err.kind() == io::ErrorKind::UnexpectedEof { Ok(None) } else { Err(err) };
+    }
+    if crc32c(&payload) != checksum {
+        return Ok(None);
+    }
+    Ok(Some(payload))
+}
+
+impl RedoLog for SegmentedLog {
+    fn log_put(&mut self, key: &[u8], value: &[u8]) {
+        let mut payload = Vec::with_capacity(1 + 4 + key.len() + value.len());
+        payload.push(OP_PUT);
+        payload.extend_from_slice(&(key.len() as u32).to_le_bytes());
+        payload.extend_from_slice(key);
+        payload.extend_from_slice(value);
+        self.write_record(&payload);
+    }
+
+    fn log_delete(&mut self, key: &[u8]) {
+        let mut payload = Vec::with_capacity(1 + key.len());
+        payload.push(OP_DELETE);
+        payload.extend_from_slice(key);
+        self.write_record(&payload);
+    }
+
+    fn log_batch(&mut self, batch: &WriteBatch) {
+        let mut payload = Vec::with_capacity(1 + batch.encoded_size());
+        payload.push(OP_BATCH);
+        for op in batch.ops() {
+            let (op, key, value) = match op {
+                WriteOp::Put(key, value) => (OP_PUT, key, &value[..]),
+                WriteOp::Delete(key) => (OP_DELETE, key, &[][..]),
+            };
+            payload.push(op);
+            payload.extend_from_slice(&(key.len() as u32).to_le_bytes());
+            payload.extend_from_slice(&(value.len() as u32).to_le_bytes());
+            payload.extend_from_slice(key);
+            payload.extend_from_slice(value);
+        }
+        self.write_record(&payload);
     }
 
     fn redo(&mut self, store: &mut dyn MemStore) {
-        let mut op = [0u8; 6];
-        while self.read_exact(&mut op).is_ok() {
-            match &op {
-                b"   put" => {
-                    let mut key = [0u8; 8];
-                    self.read_exact(&mut key).unwrap_or_else(redo_error_handler);
-                    let mut value = [0u8; 256];
-                    self.read_exact(&mut value).unwrap_or_else(redo_error_handler);
-                    store.put(key, value);
-                }
-                b"delete" => {
-                    let mut key = [0u8; 8];
-                    self.read_exact(&mut key).unwrap_or_else(redo_error_handler);
-                    store.delete(key);
-                }
-                _ => {
-                    error!("invalid op in redo_log!");
+        // wait for the records still in the queue
+        self.queue.durable().wait().unwrap_or_else(|_| error!("write redolog failed before redo"));
+        if let Some(id) = self.checkpoint {
+            let count = load_snapshot(&checkpoint_path(&self.dir, id), store)
+                .unwrap_or_else(|err| panic!("Cannot load checkpoint {}: {:?}", id, err));
+            info!("loaded checkpoint {} with {} entries", id, count);
+        }
+        let segments = self.segments.clone();
+        for (i, id) in segments.iter().cloned().enumerate() {
+            let (valid_size, file_size) = match replay_segment(&segment_path(&self.dir, id), store) {
+                Ok(sizes) => sizes,
+                Err(err) => {
+                    redo_error_handler(err);
                     return;
                 }
+            };
+            if valid_size < file_size {
+                // a crash only tears the tail of the log, the segments after it were just created then,
+                // the records after a corrupted one in the middle were acknowledged, so they must not be cut off
+                let records_after = segments[i + 1..].iter()
+                    .map(|it| fs::metadata(segment_path(&self.dir, *it)).map(|it| it.len() > MAGIC.len() as u64))
+                    .collect::<io::Result<Vec<_>>>()
+                    .unwrap_or_else(|err| panic!("Cannot read the redo log segments after {}: {:?}", id, err));
+                if records_after.into_iter().any(|it| it) {
+                    panic!(
+                        "redo log segment {} is corrupted at offset {}, and there are records in the segments after it",
+                        id, valid_size
+                    );
+                }
+                warn!(
+                    "redo log segment {} is corrupted at offset {}, truncating {} bytes after it",
+                    id, valid_size, file_size - valid_size
+                );
+                self.truncate(id, valid_size).unwrap_or_else(log_error_handler);
+                return;
             }
         }
     }
+
+    /// the id of the segment being written
+    fn position(&mut self) -> u64 {
+        self.current()
+    }
+
+    fn clear_before(&mut self, position: u64) {
+        self.remove_before(position).unwrap_or_else(log_error_handler);
+    }
+
+    fn should_checkpoint(&self) -> bool {
+        self.options.checkpoint_segments != 0
+            && self.segments.len() > self.options.checkpoint_segments
+            && self.pending_checkpoint.is_none()
+    }
+
+    fn checkpoint(&mut self, snapshot: Snapshot) -> io::Result<()> {
+        self.write_checkpoint(snapshot)
+    }
+
+    fn begin_checkpoint(&mut self) -> Option<PendingCheckpoint> {
+        if !self.should_checkpoint() {
+            return None;
+        }
+        match self.new_checkpoint() {
+            Ok(checkpoint) => {
+                self.pending_checkpoint = Some(checkpoint.position);
+                Some(checkpoint)
+            }
+            Err(err) => {
+                log_error_handler(err);
+                None
+            }
+        }
+    }
+
+    fn finish_checkpoint(&mut self, checkpoint: PendingCheckpoint) -> bool {
+        self.pending_checkpoint = None;
+        self.save_checkpoint(checkpoint).unwrap_or_else(|err| {
+            log_error_handler(err);
+            false
+        })
+    }
+
+    fn durable(&mut self) -> Durable {
+        self.queue.durable()
+    }
+
+    fn failed(&self) -> bool {
+        self.queue.failed()
+    }
+
+    fn dir(&self) -> Option<&Path> {
+        Some(&self.dir)
+    }
 }
 
 #[test]
 fn test_file() {
-    use tempfile::NamedTempFile;
-    use mini_kv::shared::bytes::copy_bytes;
-    use mini_kv::shared::bytes::bytes_equal;
+    use tempfile::tempdir;
+    use std::collections::HashMap;
+
+    let dir = tempdir().unwrap();
+    let options = LogOptions { durability: Durability::Os, ..LogOptions::default() };
+    let mut file = SegmentedLog::open(dir.path(), options).unwrap();
+    file.redo(&mut HashMap::new());
+
+    file.log_put(b"1", b"a");
+    file.log_put(b"00000002", b"b");
+    file.log_put(b"1", &[b'c'; 1024]);
+    file.log_put(b"", b"");
+    file.log_delete(b"00000002");
+
+    let mut store = HashMap::new();
+    file.redo(&mut store);
+    assert_eq!(store.len(), 2);
+    assert_eq!(store.get(&b"1"[..]).unwrap(), &vec![b'c'; 1024]);
+    assert!(store.get(&b""[..]).unwrap().is_empty());
+}
+
+#[test]
+fn test_clear_before() {
+    use tempfile::tempdir;
+    use std::collections::HashMap;
+
+    let dir = tempdir().unwrap();
+    let options = LogOptions { durability: Durability::Os, segment_size: 1024, ..LogOptions::default() };
+    let mut file = SegmentedLog::open(dir.path(), options).unwrap();
+    file.redo(&mut HashMap::new());
+    file.log_put(b"00000001", &[0u8; 2048]);
+    // the segment is full
+    let position = file.position();
+    assert_eq!(position, 2);
+    file.log_put(b"00000002", &[0u8; 256]);
+    file.clear_before(position);
+    assert_eq!(file.segments, vec![position]);
+
+    let mut store = HashMap::new();
+    file.redo(&mut store);
+    assert!(!store.contains_key(&b"00000001"[..]));
+    assert!(store.contains_key(&b"00000002"[..]));
+}
+
+#[test]
+fn test_truncate_corrupted_tail() {
+    use tempfile::tempdir;
+    use std::collections::HashMap;
+
+    let dir = tempdir().unwrap();
+    let options = LogOptions { durability: Durability::Os, ..LogOptions::default() };
+    let mut file = SegmentedLog::open(dir.path(), options).unwrap();
+    file.redo(&mut HashMap::new());
+    file.log_put(b"00000001", &[1u8; 256]);
+    file.durable().wait().unwrap();
+    let path = segment_path(dir.path(), 1);
+    let valid_size = fs::metadata(&path).unwrap().len();
+    // a torn write
+    open_segment(&path).unwrap()
+        .write_all(&encode_record(b"\x0200000001")[..10]).unwrap();
+
+    let mut store = HashMap::new();
+    file.redo(&mut store);
+    assert_eq!(store.len(), 1);
+    assert_eq!(fs::metadata(&path).unwrap().len(), valid_size);
+
+    file.log_put(b"00000002", &[2u8; 256]);
+    file.log_put(b"00000003", &[3u8; 256]);
+    file.durable().wait().unwrap();
+    // flip a byte in the value of 00000002
+    let mut content = fs::read(&path).unwrap();
+    content[valid_size as usize + 100] ^= 0xff;
+    fs::write(&path, &content).unwrap();
+
+    let mut store = HashMap::new();
+    file.redo(&mut store);
+    assert_eq!(store.len(), 1);
+    assert!(store.contains_key(&b"00000001"[..]));
+    assert_eq!(fs::metadata(&path).unwrap().len(), valid_size);
+    file.log_put(b"00000004", &[4u8; 256]);
+    let mut store = HashMap::new();
+    file.redo(&mut store);
+    assert_eq!(store.len(), 2);
+    assert!(store.contains_key(&b"00000004"[..]));
+}
+
+#[test]
+fn test_corrupted_middle_segment() {
+    use tempfile::tempdir;
     use std::collections::HashMap;
+    use std::panic::{self, AssertUnwindSafe};
+
+    let dir = tempdir().unwrap();
+    let options = LogOptions { durability: Durability::Os, segment_size: 1024, ..LogOptions::default() };
+    let mut file = SegmentedLog::open(dir.path(), options).unwrap();
+    file.redo(&mut HashMap::new());
+    file.log_put(b"00000001", &[1u8; 2048]);
+    file.log_put(b"00000002", &[2u8; 256]);
+    file.durable().wait().unwrap();
+    assert_eq!(file.segments, vec![1, 2]);
+    let path = segment_path(dir.path(), 1);
+    let mut content = fs::read(&path).unwrap();
+    content[100] ^= 0xff;
+    fs::write(&path, &content).unwrap();
 
-    let mut file = NamedTempFile::new().unwrap();
-
-    let key = *b"00000001";
-    let mut value = [0u8; 256];
-    copy_bytes(b"a", &mut value);
-    file.log_put(key, &value);
-    let key = *b"00000002";
-    let mut value = [0u8; 256];
-    copy_bytes(b"b", &mut value);
-    file.log_put(key, &value);
-    let key = *b"00000001";
-    let mut value = [0u8; 256];
-    copy_bytes(b"c", &mut value);
-    file.log_put(key, &value);
-    let key = *b"00000002";
-    file.log_delete(key);
+    // the segment after it is intact, so the log is not truncated
+    let redo = panic::catch_unwind(AssertUnwindSafe(|| file.redo(&mut HashMap::new())));
+    assert!(redo.is_err());
+    assert_eq!(fs::read(&path).unwrap(), content);
+    assert!(fs::metadata(segment_path(dir.path(), 2)).unwrap().len() > MAGIC.len() as u64);
 
+    // a torn tail in a segment followed by one without records yet is cut off
+    let dir = tempdir().unwrap();
+    let mut file = SegmentedLog::open(dir.path(), options).unwrap();
+    file.redo(&mut HashMap::new());
+    file.log_put(b"00000001", &[1u8; 2048]);
+    file.durable().wait().unwrap();
+    let path = segment_path(dir.path(), 1);
+    let valid_size = fs::metadata(&path).unwrap().len();
+    open_segment(&path).unwrap()
+        .write_all(&encode_record(b"\x0200000001")[..10]).unwrap();
     let mut store = HashMap::new();
-    let mut file = file.reopen().unwrap();
     file.redo(&mut store);
-    let scan_result = store.scan(0, 16);
-    assert_eq!(scan_result.0, 0);
-    assert_eq!(scan_result.1.len(), 1);
-    let key = *b"00000001";
-    let mut value = [0u8; 256];
-    copy_bytes(b"c", &mut value);
-    assert!(bytes_equal(store.get(&key).unwrap(), &value));
-}
\ No newline at end of file
+    assert_eq!(store.len(), 1);
+    assert_eq!(fs::metadata(&path).unwrap().len(), valid_size);
+}
+
+#[test]
+fn test_write_batch() {
+    use tempfile::tempdir;
+    use std::collections::HashMap;
+
+    let dir = tempdir().unwrap();
+    let options = LogOptions { durability: Durability::Os, ..LogOptions::default() };
+    let mut file = SegmentedLog::open(dir.path(), options).unwrap();
+    file.redo(&mut HashMap::new());
+    let mut batch = WriteBatch::new();
+    batch.put(b"1".to_vec(), b"a".to_vec()).put(vec![], vec![]).put(b"1".to_vec(), b"b".to_vec());
+    file.log_batch(&batch);
+    file.durable().wait().unwrap();
+    let path = segment_path(dir.path(), 1);
+    let valid_size = fs::metadata(&path).unwrap().len();
+    let mut batch = WriteBatch::new();
+    batch.delete(vec![]).put(b"2".to_vec(), vec![2u8; 100]).put(b"3".to_vec(), vec![3u8; 100]);
+    file.log_batch(&batch);
+    file.durable().wait().unwrap();
+
+    let mut store = HashMap::new();
+    file.redo(&mut store);
+    assert_eq!(store.len(), 3);
+    assert_eq!(store[&b"1".to_vec()], b"b".to_vec());
+    assert!(!store.contains_key(&b""[..]));
+
+    // a batch torn in the middle of the second put is discarded as a whole
+    let content = fs::read(&path).unwrap();
+    fs::write(&path, &content[..valid_size as usize + 150]).unwrap();
+    let mut store = HashMap::new();
+    file.redo(&mut store);
+    assert_eq!(store.len(), 2);
+    assert!(store.contains_key(&b""[..]));
+    assert!(!store.contains_key(&b"2"[..]));
+    assert_eq!(fs::metadata(&path).unwrap().len(), valid_size);
+}
+
+#[test]
+fn test_durability() {
+    use tempfile::tempdir;
+    use std::collections::HashMap;
+    use std::time::Instant;
+    use futures01::Future;
+
+    assert_eq!(Durability::from_str("fsync"), Ok(Durability::Fsync));
+    assert_eq!(Durability::from_str("os"), Ok(Durability::Os));
+    assert_eq!(
+        Durability::from_str("group-commit:10"),
+        Ok(Durability::GroupCommit(Duration::from_millis(10)))
+    );
+    assert!(Durability::from_str("group-commit:").is_err());
+    assert!(Durability::from_str("sometimes").is_err());
+
+    let dir = tempdir().unwrap();
+    let options = LogOptions { max_batch: 1, ..LogOptions::default() };
+    let mut file = SegmentedLog::open(dir.path(), options).unwrap();
+    file.redo(&mut HashMap::new());
+    file.log_put(b"00000001", &[1u8; 256]);
+    assert!(file.durable().wait().is_ok());
+
+    let interval = Duration::from_millis(50);
+    let dir = tempdir().unwrap();
+    let options = LogOptions { durability: Durability::GroupCommit(interval), ..LogOptions::default() };
+    let mut file = SegmentedLog::open(dir.path(), options).unwrap();
+    file.redo(&mut HashMap::new());
+    let start = Instant::now();
+    file.log_put(b"00000001", &[1u8; 256]);
+    let first = file.durable();
+    file.log_delete(b"00000001");
+    let second = file.durable();
+    assert!(first.wait().is_ok());
+    assert!(second.wait().is_ok());
+    assert!(start.elapsed() >= interval);
+}
+
+#[test]
+fn test_segment_and_checkpoint() {
+    use tempfile::tempdir;
+    use std::collections::HashMap;
+    use mini_kv::shared::bytes::bytes_equal;
+
+    let dir = tempdir().unwrap();
+    let options = LogOptions {
+        durability: Durability::Os,
+        max_batch: 1024,
+        // about 3 puts in a segment
+        segment_size: 800,
+        checkpoint_segments: 2,
+    };
+    let mut file = SegmentedLog::open(dir.path(), options).unwrap();
+    let mut store = HashMap::new();
+    file.redo(&mut store);
+    for i in 0..20u8 {
+        store.put(vec![i % 8; 8], vec![i; 256]);
+        file.log_put(&[i % 8; 8], &[i; 256]);
+        if i % 3 == 0 {
+            store.remove(&vec![i % 8; 8]);
+            file.log_delete(&[i % 8; 8]);
+        }
+        if file.should_checkpoint() {
+            file.checkpoint(store.snapshot()).unwrap();
+        }
+    }
+    file.durable().wait().unwrap();
+    assert!(file.checkpoint.is_some());
+    assert!(file.segments.len() <= 3);
+    assert_eq!(list_files(dir.path(), "log").unwrap(), file.segments);
+    assert_eq!(list_files(dir.path(), "checkpoint").unwrap().len(), 1);
+    drop(file);
+
+    let mut file = SegmentedLog::open(dir.path(), options).unwrap();
+    let mut recovered = HashMap::new();
+    file.redo(&mut recovered);
+    assert_eq!(recovered.len(), store.len());
+    for (key, value) in store.iter() {
+        assert!(bytes_equal(&recovered[key], value));
+    }
+
+    // everything before is persisted, the checkpoint is no longer needed
+    file.log_put(b"00000001", &[0u8; 1024]);
+    let position = file.position();
+    file.log_put(b"00000002", &[0u8; 256]);
+    file.clear_before(position);
+    assert!(file.checkpoint.is_none());
+    assert_eq!(list_files(dir.path(), "checkpoint").unwrap().len(), 0);
+    drop(file);
+    let mut file = SegmentedLog::open(dir.path(), options).unwrap();
+    let mut recovered = HashMap::new();
+    file.redo(&mut recovered);
+    assert_eq!(recovered.len(), 1);
+}
+
+#[test]
+fn test_pending_checkpoint() {
+    use tempfile::tempdir;
+    use std::collections::HashMap;
+
+    let dir = tempdir().unwrap();
+    let options = LogOptions {
+        durability: Durability::Os,
+        max_batch: 1024,
+        segment_size: 800,
+        checkpoint_segments: 2,
+    };
+    let mut file = SegmentedLog::open(dir.path(), options).unwrap();
+    let mut store = HashMap::new();
+    file.redo(&mut store);
+    for i in 0..10u8 {
+        store.put(vec![i; 8], vec![i; 256]);
+        file.log_put(&[i; 8], &[i; 256]);
+    }
+    let mut checkpoint = file.begin_checkpoint().unwrap();
+    // only one checkpoint is written at a time
+    assert!(!file.should_checkpoint());
+    assert!(file.begin_checkpoint().is_none());
+    let snapshot = store.snapshot();
+    // the logs written while the snapshot is written are after the checkpoint
+    store.put(vec![10u8; 8], vec![10u8; 256]);
+    file.log_put(&[10u8; 8], &[10u8; 256]);
+    checkpoint.write(snapshot).unwrap();
+    assert!(file.finish_checkpoint(checkpoint));
+    assert_eq!(file.checkpoint, Some(file.segments[0]));
+    file.durable().wait().unwrap();
+    drop(file);
+    let mut file = SegmentedLog::open(dir.path(), options).unwrap();
+    let mut recovered = HashMap::new();
+    file.redo(&mut recovered);
+    assert_eq!(recovered, store);
+
+    // the store persisted the logs since the checkpoint while it's written, it's older than the data
+    for i in 0..10u8 {
+        file.log_put(&[i; 8], &[i; 256]);
+    }
+    let mut checkpoint = file.begin_checkpoint().unwrap();
+    checkpoint.write(store.snapshot()).unwrap();
+    file.log_put(b"00000001", &[0u8; 1024]);
+    let position = file.position();
+    file.clear_before(position);
+    assert!(!file.finish_checkpoint(checkpoint));
+    assert!(file.checkpoint.is_none());
+    assert_eq!(list_files(dir.path(), "checkpoint").unwrap().len(), 0);
+    assert_eq!(list_files(dir.path(), "tmp").unwrap().len(), 0);
+}
@@ -0,0 +1,486 @@
+//! A lock-free skiplist, which several threads can read and write at the same time
+//!
+//! A new node is linked into the bottom level with a compare-and-swap first, which decides its place in the key order,
+//! then into the levels above it one by one, which only make the searches faster.
+//! The readers never wait, and the writers only retry when another write changes the same place of a level.
+//!
+//! A key is deleted by swapping its value for a null pointer tagged `REMOVED`, then its node is unlinked like in
+//! the lists of Harris: its next pointers are tagged from the top level down, so nothing is linked after it any more,
+//! and the writers searching past it unlink it from each level. The readers pass over it without writing anything.
+//! A node unlinked from all the levels and the values replaced are freed after all the threads which may be reading
+//! them are done, so the memory is taken by the keys in the list, and the ones deleted while some reads are running.
+
+use std::io;
+use std::marker::PhantomData;
+use std::ptr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crossbeam_epoch::{self as epoch, Atomic, Guard, Owned, Shared};
+use rand::Rng;
+use crate::store::mem_store::{MemStore, ConcurrentMemStore};
+use crate::store::snapshot::Snapshot;
+
+/// max count of levels a node is in
+const MAX_HEIGHT: usize = 12;
+/// a node in a level is in the level above it too with a chance of 1 / `BRANCHING`
+const BRANCHING: u32 = 4;
+
+/// the tag of the value of a deleted key, and of the next pointers of its node
+const REMOVED: usize = 1;
+
+/// the writer of a node is linking it into the levels
+const LINKING: usize = 0;
+/// the writer of a node has linked it into all its levels
+const LINKED: usize = 1;
+/// a node is deleted while its writer is linking it, the writer unlinks and frees it when it's done
+const UNLINKING: usize = 2;
+
+/// the next nodes in each level, from the bottom level
+type Tower = [Atomic<Node>];
+
+struct Node {
+    key: Vec<u8>,
+    /// null and tagged `REMOVED` once the key is deleted
+    value: Atomic<Vec<u8>>,
+    next: Box<Tower>,
+    /// `LINKING`, `LINKED` or `UNLINKING`, decides whether its writer or its deleter frees it
+    state: AtomicUsize,
+}
+
+impl Drop for Node {
+    fn drop(&mut self) {
+        // SAFETY: a node is dropped when no one else can read it
+        unsafe {
+            let value = self.value.load(Ordering::Relaxed, epoch::unprotected());
+            if !value.is_null() {
+                drop(value.into_owned());
+            }
+        }
+    }
+}
+
+fn new_tower(height: usize) -> Box<Tower> {
+    (0..height).map(|_| Atomic::null()).collect()
+}
+
+fn random_height() -> usize {
+    let mut rng = rand::thread_rng();
+    let mut height = 1;
+    while height < MAX_HEIGHT && rng.gen_range(0, BRANCHING) == 0 {
+        height += 1;
+    }
+    height
+}
+
+/// the node `ptr` points to, the tag is ignored
+/// # Safety
+/// `ptr` must be loaded from the list, then the node is not freed before the guard it's loaded with is dropped
+unsafe fn node<'g>(ptr: Shared<'g, Node>) -> Option<&'g Node> {
+    ptr.as_ref()
+}
+
+/// the value of `node`, `None` if the key is deleted
+fn value_of(node: &Node, guard: &Guard) -> Option<Vec<u8>> {
+    // SAFETY: the value is not freed before `guard` is dropped
+    unsafe { node.value.load(Ordering::Acquire, guard).as_ref() }.cloned()
+}
+
+/// tag the next pointers of the deleted `node` from the top level down,
+/// so no node is linked after it, and the searches unlink it from the levels
+fn mark_tower(node: &Node, guard: &Guard) {
+    for level in (0..node.next.len()).rev() {
+        node.next[level].fetch_or(REMOVED, Ordering::AcqRel, guard);
+    }
+}
+
+pub struct SkipList {
+    /// the first nodes in each level
+    head: Box<Tower>,
+}
+
+impl SkipList {
+    pub fn new() -> Self {
+        Self {
+            head: new_tower(MAX_HEIGHT),
+        }
+    }
+
+    /// the place of `key` in each level, which is the tower of the last node before it, and the first node not less than it,
+    /// the deleted nodes on the way are unlinked, so none of them is in the place
+    fn search<'g>(&'g self, key: &[u8], guard: &'g Guard) -> ([&'g Tower; MAX_HEIGHT], [Shared<'g, Node>; MAX_HEIGHT]) {
+        'search: loop {
+            let mut preds = [&*self.head; MAX_HEIGHT];
+            let mut succs = [Shared::null(); MAX_HEIGHT];
+            let mut pred = &*self.head;
+            for level in (0..MAX_HEIGHT).rev() {
+                let mut curr = pred[level].load(Ordering::Acquire, guard);
+                if curr.tag() == REMOVED {
+                    // the node before is deleted too
+                    continue 'search;
+                }
+                // SAFETY: the nodes are loaded from the list with `guard` pinned
+                while let Some(current) = unsafe { node(curr) } {
+                    let next = current.next[level].load(Ordering::Acquire, guard);
+                    if next.tag() == REMOVED {
+                        match pred[level].compare_and_set(curr, next.with_tag(0), Ordering::AcqRel, guard) {
+                            Ok(_) => curr = next.with_tag(0),
+                            // the node before is changed, start over
+                            Err(_) => continue 'search,
+                        }
+                    } else if current.key.as_slice() < key {
+                        pred = &current.next;
+                        curr = next;
+                    } else {
+                        break;
+                    }
+                }
+                preds[level] = pred;
+                succs[level] = curr;
+            }
+            return (preds, succs);
+        }
+    }
+
+    /// the last node before `key` and the first node not before it in the bottom level, `None` `key` is after all the keys
+    /// the deleted nodes being unlinked are passed over, and nothing is written, so the readers never wait for the writers
+    fn seek<'g>(&'g self, key: Option<&[u8]>, guard: &'g Guard) -> (Option<&'g Node>, Option<&'g Node>) {
+        let mut last = None;
+        let mut pred = &*self.head;
+        let mut curr = Shared::null();
+        for level in (0..MAX_HEIGHT).rev() {
+            curr = pred[level].load(Ordering::Acquire, guard);
+            // SAFETY: the nodes are loaded from the list with `guard` pinned
+            while let Some(current) = unsafe { node(curr) } {
+                let next = current.next[level].load(Ordering::Acquire, guard);
+                if next.tag() == REMOVED {
+                    curr = next;
+                } else if key.map(|key| current.key.as_slice() < key).unwrap_or(true) {
+                    last = Some(current);
+                    pred = &current.next;
+                    curr = next;
+                } else {
+                    break;
+                }
+            }
+        }
+        // SAFETY: `curr` is loaded from the list with `guard` pinned
+        (last, unsafe { node(curr) })
+    }
+
+    /// link the node `new` points to into the levels above the bottom level,
+    /// `preds` and `succs` are its place when it's linked into the bottom level
+    /// it stops once the node is deleted, and frees it if its deleter has left it to the writer
+    fn link_above<'g>(
+        &'g self,
+        new: Shared<'g, Node>,
+        mut preds: [&'g Tower; MAX_HEIGHT],
+        mut succs: [Shared<'g, Node>; MAX_HEIGHT],
+        guard: &'g Guard,
+    ) {
+        // SAFETY: the new node is only freed by this thread after it's deleted
+        let new_node = unsafe { new.deref() };
+        'levels: for level in 1..new_node.next.len() {
+            loop {
+                let next = new_node.next[level].load(Ordering::Acquire, guard);
+                // the node is deleted, it's not linked into more levels
+                if next.tag() == REMOVED
+                    || new_node.next[level].compare_and_set(next, succs[level], Ordering::AcqRel, guard).is_err() {
+                    break 'levels;
+                }
+                if preds[level][level].compare_and_set(succs[level], new, Ordering::AcqRel, guard).is_ok() {
+                    break;
+                }
+                // a node is inserted at the place, the new node isn't in this level yet, so it's not found here
+                let (new_preds, new_succs) = self.search(&new_node.key, guard);
+                preds = new_preds;
+                succs = new_succs;
+            }
+        }
+        if new_node.state.compare_exchange(LINKING, LINKED, Ordering::SeqCst, Ordering::SeqCst).is_err() {
+            // deleted while it's linked, unlink it from the levels it's linked into since
+            self.search(&new_node.key, guard);
+            // SAFETY: it's unlinked from all the levels, and freed after the readers are done
+            unsafe { guard.defer_destroy(new) };
+        }
+    }
+
+    /// the count of nodes in the bottom level, including the deleted ones not unlinked yet
+    #[cfg(test)]
+    fn node_count(&self) -> usize {
+        let guard = &epoch::pin();
+        let mut count = 0;
+        let mut next = self.head[0].load(Ordering::Acquire, guard);
+        // SAFETY: the nodes are loaded from the list with `guard` pinned
+        while let Some(current) = unsafe { node(next) } {
+            count += 1;
+            next = current.next[0].load(Ordering::Acquire, guard);
+        }
+        count
+    }
+}
+
+impl Default for SkipList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for SkipList {
+    fn drop(&mut self) {
+        MemStore::clear(self);
+    }
+}
+
+impl ConcurrentMemStore for SkipList {
+    fn put_shared(&self, key: Vec<u8>, value: Vec<u8>) {
+        let guard = &epoch::pin();
+        let new = Owned::new(Node {
+            key,
+            value: Atomic::new(value),
+            next: new_tower(random_height()),
+            state: AtomicUsize::new(LINKING),
+        }).into_shared(guard);
+        // SAFETY: the new node is only read by this thread until it's linked
+        let new_node = unsafe { new.deref() };
+        loop {
+            let (preds, succs) = self.search(&new_node.key, guard);
+            // SAFETY: `succs` are loaded from the list with `guard` pinned
+            if let Some(found) = unsafe { node(succs[0]) }.filter(|it| it.key == new_node.key) {
+                let current = found.value.load(Ordering::Acquire, guard);
+                if current.tag() == REMOVED {
+                    // help its deleter, so it's unlinked by the next search
+                    mark_tower(found, guard);
+                    continue;
+                }
+                // the key is there already, replace its value instead
+                let value = new_node.value.load(Ordering::Relaxed, guard);
+                if found.value.compare_and_set(current, value, Ordering::AcqRel, guard).is_ok() {
+                    new_node.value.store(Shared::null(), Ordering::Relaxed);
+                    // SAFETY: the new node is never linked, and the old value is freed after the readers are done
+                    unsafe {
+                        drop(new.into_owned());
+                        guard.defer_destroy(current);
+                    }
+                    return;
+                }
+                continue;
+            }
+            new_node.next[0].store(succs[0], Ordering::Relaxed);
+            if preds[0][0].compare_and_set(succs[0], new, Ordering::AcqRel, guard).is_ok() {
+                self.link_above(new, preds, succs, guard);
+                return;
+            }
+        }
+    }
+
+    fn delete_shared(&self, key: &[u8]) -> Option<Vec<u8>> {
+        let guard = &epoch::pin();
+        loop {
+            let (_, succs) = self.search(key, guard);
+            // SAFETY: `succs` are loaded from the list with `guard` pinned
+            let found = unsafe { node(succs[0]) }.filter(|it| it.key.as_slice() == key)?;
+            let current = found.value.load(Ordering::Acquire, guard);
+            if current.tag() == REMOVED {
+                // deleted by another thread, a new node of the key may be linked after it's unlinked
+                mark_tower(found, guard);
+                continue;
+            }
+            let removed = Shared::null().with_tag(REMOVED);
+            if found.value.compare_and_set(current, removed, Ordering::AcqRel, guard).is_err() {
+                continue;
+            }
+            // SAFETY: others may be reading the value, so it's copied, and freed after they are done
+            let value = unsafe { current.as_ref() }.cloned();
+            unsafe { guard.defer_destroy(current) };
+            mark_tower(found, guard);
+            if found.state.swap(UNLINKING, Ordering::SeqCst) == LINKED {
+                self.search(key, guard);
+                // SAFETY: it's unlinked from all the levels, and freed after the readers are done
+                unsafe { guard.defer_destroy(succs[0]) };
+            }
+            return value;
+        }
+    }
+}
+
+/// Iterate over the (key, value) pairs from a node to `end` in key order, the deleted keys are skipped
+/// It's pinned until it's dropped, so the nodes it passes are not freed
+struct Iter<'a> {
+    guard: Guard,
+    /// loaded from the list with `guard` pinned
+    next: *const Node,
+    end: Option<Vec<u8>>,
+    /// the nodes are freed by `clear`, which can't be called while the list is borrowed
+    list: PhantomData<&'a SkipList>,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = (Vec<u8>, Vec<u8>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFETY: `next` is loaded from the list with `self.guard` pinned
+        while let Some(current) = unsafe { self.next.as_ref() } {
+            if self.end.as_ref().map(|end| current.key >= *end).unwrap_or(false) {
+                self.next = ptr::null();
+                break;
+            }
+            self.next = current.next[0].load(Ordering::Acquire, &self.guard).as_raw();
+            if let Some(value) = value_of(current, &self.guard) {
+                return Some((current.key.clone(), value));
+            }
+        }
+        None
+    }
+}
+
+/// Iterate over the (key, value) pairs in [`start`, `end`) in reverse key order, the deleted keys are skipped
+/// The nodes only link to the next ones, so each step searches for the node before the last one
+struct RevIter<'a> {
+    list: &'a SkipList,
+    start: Vec<u8>,
+    end: Option<Vec<u8>>,
+}
+
+impl<'a> Iterator for RevIter<'a> {
+    type Item = (Vec<u8>, Vec<u8>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let guard = &epoch::pin();
+        loop {
+            let current = self.list.seek(self.end.as_deref(), guard).0
+                .filter(|it| it.key >= self.start)?;
+            self.end = Some(current.key.clone());
+            if let Some(value) = value_of(current, guard) {
+                return Some((current.key.clone(), value));
+            }
+        }
+    }
+}
+
+impl MemStore for SkipList {
+    fn put(&mut self, key: Vec<u8>, value: Vec<u8>) {
+        self.put_shared(key, value);
+    }
+
+    fn get(&self, key: &[u8]) -> io::Result<Option<Vec<u8>>> {
+        let guard = &epoch::pin();
+        let found = self.seek(Some(key), guard).1.filter(|it| it.key.as_slice() == key);
+        Ok(found.and_then(|it| value_of(it, guard)))
+    }
+
+    fn delete(&mut self, key: &[u8]) -> io::Result<Option<Vec<u8>>> {
+        Ok(self.delete_shared(key))
+    }
+
+    fn range<'a>(&'a self, start: &[u8], end: Option<&[u8]>) -> Box<dyn Iterator<Item=(Vec<u8>, Vec<u8>)> + 'a> {
+        if end.map(|end| end <= start).unwrap_or(false) {
+            return Box::new(std::iter::empty());
+        }
+        let guard = epoch::pin();
+        let next = self.seek(Some(start), &guard).1.map(|it| it as *const Node).unwrap_or(ptr::null());
+        Box::new(Iter {
+            guard,
+            next,
+            end: end.map(|it| it.to_vec()),
+            list: PhantomData,
+        })
+    }
+
+    fn range_rev<'a>(&'a self, start: &[u8], end: Option<&[u8]>) -> Box<dyn Iterator<Item=(Vec<u8>, Vec<u8>)> + 'a> {
+        if end.map(|end| end <= start).unwrap_or(false) {
+            return Box::new(std::iter::empty());
+        }
+        Box::new(RevIter {
+            list: self,
+            start: start.to_vec(),
+            end: end.map(|it| it.to_vec()),
+        })
+    }
+
+    /// the pairs are copied, the writes while copying may be in the snapshot or not
+    fn snapshot(&self) -> Snapshot {
+        let pairs: Vec<_> = self.range(b"", None).collect();
+        Snapshot::new(pairs.into_iter())
+    }
+
+    /// free all the nodes, no one else can read them since the list is borrowed mutably
+    fn clear(&mut self) {
+        // SAFETY: the nodes in the bottom level are only freed here, the deleted ones are unlinked already
+        unsafe {
+            let guard = epoch::unprotected();
+            let mut next = self.head[0].load(Ordering::Relaxed, guard);
+            while !next.is_null() {
+                let node = next.into_owned();
+                next = node.next[0].load(Ordering::Relaxed, guard);
+            }
+        }
+        for it in self.head.iter() {
+            it.store(Shared::null(), Ordering::Relaxed);
+        }
+    }
+}
+
+#[test]
+fn test_skiplist_store() {
+    use crate::store::mem_store::do_test;
+
+    let mut store = SkipList::new();
+    do_test(&mut store);
+    for i in (0..200u8).rev() {
+        store.put(vec![i], vec![i]);
+    }
+    store.delete(&[100]).unwrap();
+    let keys: Vec<_> = store.range(&[98], Some(&[103])).map(|(key, _)| key[0]).collect();
+    assert_eq!(keys, vec![98, 99, 101, 102]);
+    let keys: Vec<_> = store.range_rev(&[98], Some(&[103])).map(|(key, _)| key[0]).collect();
+    assert_eq!(keys, vec![102, 101, 99, 98]);
+    assert_eq!(store.range_rev(b"", None).next(), Some((vec![199], vec![199])));
+    // the nodes of the deleted keys are unlinked
+    assert_eq!(store.node_count(), 200);
+    store.put(vec![100], vec![]);
+    assert_eq!(store.get(&[100]).unwrap(), Some(vec![]));
+    assert_eq!(store.snapshot().count(), 201);
+    for i in 0..200u8 {
+        store.delete(&[i]).unwrap();
+    }
+    assert_eq!(store.node_count(), 1);
+    store.clear();
+    assert_eq!(store.range(b"", None).count(), 0);
+}
+
+#[test]
+fn test_concurrent_skiplist() {
+    use std::sync::Arc;
+    use std::thread;
+
+    let store = Arc::new(SkipList::new());
+    let threads: Vec<_> = (0..4u8).map(|i| {
+        let store = store.clone();
+        thread::spawn(move || {
+            for j in 0..250u8 {
+                // the threads write the same keys at the same time
+                store.put_shared(vec![j], vec![i]);
+                store.put_shared(vec![j, i], vec![i]);
+                if j % 2 == 0 {
+                    store.delete_shared(&[j, i]);
+                }
+                // and delete the same keys at the same time
+                store.put_shared(vec![j, 255], vec![i]);
+                store.delete_shared(&[j, 255]);
+                // a scan always sees the keys in order
+                let keys: Vec<_> = store.range(&[j], Some(&[j + 1])).map(|(key, _)| key).collect();
+                assert!(keys.windows(2).all(|it| it[0] < it[1]));
+            }
+        })
+    }).collect();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+    let keys: Vec<_> = store.range(b"", None).map(|(key, _)| key).collect();
+    assert_eq!(keys.iter().filter(|it| it.len() == 1).count(), 250);
+    assert_eq!(keys.iter().filter(|it| it.len() == 2 && it[1] < 4).count(), 125 * 4);
+    assert!(store.get(&[7]).unwrap().unwrap()[0] < 4);
+    assert_eq!(store.get(&[7, 3]).unwrap(), Some(vec![3]));
+    assert_eq!(store.get(&[8, 3]).unwrap(), None);
+    // the deleted nodes are unlinked once all the writes are done
+    assert_eq!(store.node_count(), store.range(b"", None).count());
+}
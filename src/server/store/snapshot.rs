@@ -0,0 +1,306 @@
+//! A snapshot is a file holding all the (key, value) pairs of a `MemStore` at some point
+//!
+//! The file is `magic(8 bytes) + entries + end mark(1 byte) + entry count(8 bytes) + crc32c(4 bytes)`,
+//! each entry is `entry mark(1 byte) + key length(4 bytes) + value length(4 bytes) + key + value`,
+//! and `crc32c` is the checksum of all the bytes before it.
+//! The entry count is put after the entries, so a snapshot can be written in one pass.
+//!
+//! All integers are in little endian.
+//!
+//! The values begin with their versions since `MKVSNP03`, see `store::versioned`.
+//!
+//! The checkpoints of the redo log are snapshots too.
+
+use std::collections::BTreeMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufWriter, Read, Write};
+use std::path::Path;
+use crate::store::mem_store::MemStore;
+use crate::store::MAX_ENTRY_SIZE;
+use mini_kv::shared::crc32c::{crc32c, crc32c_update};
+
+const MAGIC: &[u8; 8] = b"MKVSNP03";
+const MARK_END: u8 = 0;
+const MARK_ENTRY: u8 = 1;
+/// entry mark(1 byte) + key length(4 bytes) + value length(4 bytes)
+const ENTRY_HEADER_SIZE: usize = 1 + 4 + 4;
+/// end mark(1 byte) + entry count(8 bytes) + crc32c(4 bytes)
+const FOOTER_SIZE: usize = 1 + 8 + 4;
+/// size of the pieces a snapshot file is read in
+const READ_SIZE: usize = 64 * 1024;
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn read_u32(buffer: &[u8]) -> u32 {
+    let mut bytes = [0u8; 4];
+    bytes.copy_from_slice(&buffer[..4]);
+    u32::from_le_bytes(bytes)
+}
+
+/// A point-in-time image of all the (key, value) pairs in a store
+/// It owns everything it needs, so the store can be modified while it's written out
+pub struct Snapshot {
+    entries: Box<dyn Iterator<Item=(Vec<u8>, Vec<u8>)> + Send>,
+}
+
+impl Snapshot {
+    /// `entries` must not be affected by the writes to the store after the snapshot is taken
+    pub fn new<I: 'static + Iterator<Item=(Vec<u8>, Vec<u8>)> + Send>(entries: I) -> Self {
+        Self {
+            entries: Box::new(entries),
+        }
+    }
+
+    /// write the snapshot into `writer`, return the count of entries written
+    pub fn write_to<W: Write>(self, writer: W) -> io::Result<u64> {
+        let mut writer = BufWriter::new(writer);
+        writer.write_all(MAGIC)?;
+        let mut checksum = crc32c(MAGIC);
+        let mut count = 0u64;
+        for (key, value) in self.entries {
+            let mut header = [MARK_ENTRY; ENTRY_HEADER_SIZE];
+            header[1..5].copy_from_slice(&(key.len() as u32).to_le_bytes());
+            header[5..].copy_from_slice(&(value.len() as u32).to_le_bytes());
+            writer.write_all(&header)?;
+            writer.write_all(&key)?;
+            writer.write_all(&value)?;
+            checksum = crc32c_update(crc32c_update(crc32c_update(checksum, &header), &key), &value);
+            count += 1;
+        }
+        writer.write_all(&[MARK_END])?;
+        writer.write_all(&count.to_le_bytes())?;
+        checksum = crc32c_update(crc32c_update(checksum, &[MARK_END]), &count.to_le_bytes());
+        writer.write_all(&checksum.to_le_bytes())?;
+        writer.flush()?;
+        Ok(count)
+    }
+
+    /// write the snapshot into a new file at `path` and sync it, return the count of entries written
+    pub fn write_file(self, path: &Path) -> io::Result<u64> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .truncate(true)
+            .write(true)
+            .open(path)?;
+        let count = self.write_to(&mut file)?;
+        file.sync_all()?;
+        Ok(count)
+    }
+
+    /// write the snapshot into a file at `path`, return the count of entries written
+    /// the file is written into a temporary file first, so `path` is either absent or complete
+    pub fn save(self, path: &Path) -> io::Result<u64> {
+        let tmp_path = path.with_extension("tmp");
+        let count = self.write_file(&tmp_path)?;
+        fs::rename(&tmp_path, path)?;
+        if let Some(dir) = path.parent() {
+            File::open(dir)?.sync_all()?;
+        }
+        Ok(count)
+    }
+}
+
+impl Iterator for Snapshot {
+    type Item = (Vec<u8>, Vec<u8>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.entries.next()
+    }
+}
+
+/// decode the snapshot file at `path` piece by piece, and pass the entries decoded from each piece to `f`
+/// return the count of entries in it, or an error if the file is not a complete snapshot
+fn read_snapshot<F: FnMut(BTreeMap<Vec<u8>, Vec<u8>>)>(path: &Path, mut f: F) -> io::Result<u64> {
+    let mut file = File::open(path)?;
+    let mut decoder = SnapshotDecoder::new();
+    let mut buffer = vec![0u8; READ_SIZE];
+    loop {
+        let size = file.read(&mut buffer)?;
+        if size == 0 {
+            break;
+        }
+        decoder.feed(&buffer[..size])?;
+        f(std::mem::take(&mut decoder.entries));
+    }
+    decoder.check_end()?;
+    Ok(decoder.count)
+}
+
+/// put all the entries in the snapshot file at `path` into `store`, return the count of entries
+/// nothing is put if the file is invalid
+pub fn load_snapshot(path: &Path, store: &mut dyn MemStore) -> io::Result<u64> {
+    // check the whole file first
+    read_snapshot(path, |_| {})?;
+    read_snapshot(path, |entries| {
+        for (key, value) in entries {
+            store.put(key, value);
+        }
+    })
+}
+
+/// Decode a snapshot arriving piece by piece, like from a network stream
+/// The entries are only returned after the whole snapshot is checked
+#[derive(Default)]
+pub struct SnapshotDecoder {
+    /// bytes not decoded yet
+    buffer: Vec<u8>,
+    magic_checked: bool,
+    /// whether the end of the snapshot is decoded and checked
+    ended: bool,
+    checksum: u32,
+    count: u64,
+    entries: BTreeMap<Vec<u8>, Vec<u8>>,
+}
+
+impl SnapshotDecoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// decode the next piece of the snapshot
+    pub fn feed(&mut self, data: &[u8]) -> io::Result<()> {
+        if self.ended {
+            return Err(invalid_data("data after the end of the snapshot"));
+        }
+        self.buffer.extend_from_slice(data);
+        if !self.magic_checked {
+            if self.buffer.len() < MAGIC.len() {
+                return Ok(());
+            }
+            if &self.buffer[..MAGIC.len()] != MAGIC {
+                return Err(invalid_data("not a snapshot"));
+            }
+            self.buffer.drain(..MAGIC.len());
+            self.checksum = crc32c(MAGIC);
+            self.magic_checked = true;
+        }
+        let mut offset = 0;
+        while let Some(&mark) = self.buffer.get(offset) {
+            let rest = &self.buffer[offset..];
+            match mark {
+                MARK_ENTRY if rest.len() >= ENTRY_HEADER_SIZE => {
+                    let key_len = read_u32(&rest[1..]) as usize;
+                    let value_len = read_u32(&rest[5..]) as usize;
+                    if key_len + value_len > MAX_ENTRY_SIZE {
+                        return Err(invalid_data("snapshot entry is too large"));
+                    }
+                    let size = ENTRY_HEADER_SIZE + key_len + value_len;
+                    if rest.len() < size {
+                        break;
+                    }
+                    self.checksum = crc32c_update(self.checksum, &rest[..size]);
+                    let (key, value) = rest[ENTRY_HEADER_SIZE..size].split_at(key_len);
+                    self.entries.insert(key.to_vec(), value.to_vec());
+                    self.count += 1;
+                    offset += size;
+                }
+                MARK_END if rest.len() >= FOOTER_SIZE => {
+                    if rest.len() > FOOTER_SIZE {
+                        return Err(invalid_data("data after the end of the snapshot"));
+                    }
+                    let checksum = crc32c_update(self.checksum, &rest[..9]);
+                    if read_u32(&rest[9..]) != checksum {
+                        return Err(invalid_data("snapshot checksum mismatch"));
+                    }
+                    let mut count = [0u8; 8];
+                    count.copy_from_slice(&rest[1..9]);
+                    if u64::from_le_bytes(count) != self.count {
+                        return Err(invalid_data("snapshot entry count mismatch"));
+                    }
+                    self.ended = true;
+                    offset += FOOTER_SIZE;
+                }
+                MARK_ENTRY | MARK_END => break,
+                _ => return Err(invalid_data("invalid entry in snapshot")),
+            }
+        }
+        self.buffer.drain(..offset);
+        Ok(())
+    }
+
+    fn check_end(&self) -> io::Result<()> {
+        if self.ended {
+            Ok(())
+        } else {
+            Err(invalid_data("snapshot is truncated"))
+        }
+    }
+
+    /// check the end of the snapshot, and return all the entries in it
+    pub fn finish(self) -> io::Result<BTreeMap<Vec<u8>, Vec<u8>>> {
+        self.check_end()?;
+        Ok(self.entries)
+    }
+}
+
+#[test]
+fn test_snapshot() {
+    use tempfile::tempdir;
+    use std::collections::HashMap;
+
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("backup.snapshot");
+    let mut store = HashMap::new();
+    for i in 0..100u8 {
+        store.insert(vec![i; i as usize], vec![i; 256 - i as usize]);
+    }
+    let snapshot = store.snapshot();
+    // the writes after the snapshot is taken are not in it
+    store.insert(vec![200u8; 8], vec![200u8; 256]);
+    store.remove(&vec![]);
+    assert_eq!(snapshot.save(&path).unwrap(), 100);
+    assert!(!path.with_extension("tmp").exists());
+
+    let mut loaded = HashMap::new();
+    assert_eq!(load_snapshot(&path, &mut loaded).unwrap(), 100);
+    assert_eq!(loaded.len(), 100);
+    assert_eq!(loaded[&vec![]], vec![0u8; 256]);
+    assert_eq!(loaded[&vec![42u8; 42]], vec![42u8; 214]);
+    assert!(!loaded.contains_key(&vec![200u8; 8]));
+
+    let mut content = fs::read(&path).unwrap();
+    content[100] ^= 0xff;
+    fs::write(&path, &content).unwrap();
+    let mut loaded = HashMap::new();
+    assert!(load_snapshot(&path, &mut loaded).is_err());
+    assert!(loaded.is_empty());
+
+    Snapshot::new(Vec::new().into_iter()).save(&path).unwrap();
+    assert_eq!(load_snapshot(&path, &mut loaded).unwrap(), 0);
+}
+
+#[test]
+fn test_snapshot_decoder() {
+    let entries: Vec<_> = (0..100u8).map(|i| (vec![i; 8], vec![i; i as usize])).collect();
+    let mut content = vec![];
+    Snapshot::new(entries.into_iter()).write_to(&mut content).unwrap();
+
+    let mut decoder = SnapshotDecoder::new();
+    // pieces not aligned to the entries
+    for piece in content.chunks(100) {
+        decoder.feed(piece).unwrap();
+    }
+    let decoded = decoder.finish().unwrap();
+    assert_eq!(decoded.len(), 100);
+    assert_eq!(decoded[&vec![42u8; 8]], vec![42u8; 42]);
+
+    let mut decoder = SnapshotDecoder::new();
+    decoder.feed(&content[..content.len() - 1]).unwrap();
+    assert!(decoder.finish().is_err());
+
+    let mut corrupted = content.clone();
+    corrupted[1000] ^= 0xff;
+    // a corrupted length or mark may be found before the end
+    let mut decoder = SnapshotDecoder::new();
+    assert!(decoder.feed(&corrupted).and_then(|_| decoder.finish()).is_err());
+
+    let mut decoder = SnapshotDecoder::new();
+    let mut extended = content.clone();
+    extended.push(0);
+    assert!(decoder.feed(&extended).is_err());
+
+    let mut decoder = SnapshotDecoder::new();
+    assert!(decoder.feed(b"MKVLOG03").is_err());
+}
@@ -0,0 +1,96 @@
+//! Multi-key transactions
+//!
+//! A transaction reads at a snapshot, then commits the keys it read, its conditions and its writes at once.
+//! The commit checks that none of the keys read or written is written by others after the snapshot,
+//! and all the conditions hold on the current values, then writes all the writes as one write batch,
+//! everything under the write locks of the partitions of its keys.
+//! So the committed transactions are serializable, and survive a crash all together or not at all.
+
+use crate::store::condition::Condition;
+use crate::store::versioned::Versioned;
+use crate::store::write_batch::WriteBatch;
+
+/// The keys read, the conditions and the writes of a transaction
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Txn {
+    /// the timestamp of the snapshot the transaction read at, 0 if it read nothing
+    start_ts: u64,
+    read_keys: Vec<Vec<u8>>,
+    conditions: Vec<(Vec<u8>, Condition)>,
+    writes: WriteBatch,
+}
+
+/// Why a transaction is not committed
+#[derive(Clone, Debug, PartialEq)]
+pub enum TxnError {
+    /// the snapshot of the transaction can't be read, so the conflicts can't be checked
+    Snapshot(String),
+    /// the key is written by others after the snapshot of the transaction
+    Conflict(Vec<u8>),
+    /// the condition at the index doesn't hold, with the current value of its key
+    ConditionFailed(usize, Option<Versioned>),
+}
+
+impl Txn {
+    /// a transaction which read at the snapshot `start_ts`, 0 if it reads nothing
+    pub fn new(start_ts: u64) -> Self {
+        Self {
+            start_ts,
+            ..Self::default()
+        }
+    }
+
+    /// the transaction read `key` at its snapshot
+    pub fn read(&mut self, key: Vec<u8>) -> &mut Self {
+        self.read_keys.push(key);
+        self
+    }
+
+    /// the transaction is only committed if `condition` holds on the current value of `key`
+    pub fn check(&mut self, key: Vec<u8>, condition: Condition) -> &mut Self {
+        self.conditions.push((key, condition));
+        self
+    }
+
+    pub fn put(&mut self, key: Vec<u8>, value: Vec<u8>) -> &mut Self {
+        self.writes.put(key, value);
+        self
+    }
+
+    pub fn delete(&mut self, key: Vec<u8>) -> &mut Self {
+        self.writes.delete(key);
+        self
+    }
+
+    pub fn start_ts(&self) -> u64 {
+        self.start_ts
+    }
+
+    pub fn conditions(&self) -> &[(Vec<u8>, Condition)] {
+        &self.conditions
+    }
+
+    pub fn into_writes(self) -> WriteBatch {
+        self.writes
+    }
+
+    /// the keys read or written, which must not be written by others after the snapshot
+    pub fn keys(&self) -> impl Iterator<Item=&[u8]> {
+        self.read_keys.iter()
+            .map(|it| it.as_slice())
+            .chain(self.writes.ops().iter().map(|it| it.key()))
+    }
+}
+
+#[test]
+fn test_txn() {
+    let mut txn = Txn::new(42);
+    txn.read(b"a".to_vec())
+        .check(b"b".to_vec(), Condition::Absent)
+        .put(b"b".to_vec(), b"1".to_vec())
+        .delete(b"a".to_vec());
+    assert_eq!(txn.start_ts(), 42);
+    assert_eq!(txn.keys().collect::<Vec<_>>(), vec![&b"a"[..], b"b", b"a"]);
+    assert_eq!(txn.conditions(), &[(b"b".to_vec(), Condition::Absent)]);
+    assert_eq!(txn.into_writes().ops().len(), 2);
+}
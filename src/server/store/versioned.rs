@@ -0,0 +1,159 @@
+//! The values in the `MemStore` carry the version they are written at,
+//! they are stored as `version(8 bytes, little endian) + value`,
+//! or `version with the highest bit set + deadline(8 bytes, little endian) + value` if they expire
+//!
+//! Versions are given by a revision clock shared by all the keys, so the versions of a key
+//! keep increasing, even if it is deleted and put again.
+
+use std::io;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// size of the version before each stored value
+pub const VERSION_SIZE: usize = 8;
+/// max size of the version and the deadline before each stored value
+pub const MAX_HEADER_SIZE: usize = VERSION_SIZE + 8;
+/// set in the stored version if a deadline follows it
+const DEADLINE_FLAG: u64 = 1 << 63;
+/// the largest version, a larger one would have `DEADLINE_FLAG` set
+pub const MAX_VERSION: u64 = DEADLINE_FLAG - 1;
+
+/// milliseconds since the UNIX epoch
+pub fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|it| it.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// read a little endian u64 from the start of `bytes`, the missing bytes are taken as 0
+pub fn read_u64(bytes: &[u8]) -> u64 {
+    let mut buffer = [0u8; 8];
+    let size = bytes.len().min(8);
+    buffer[..size].copy_from_slice(&bytes[..size]);
+    u64::from_le_bytes(buffer)
+}
+
+/// A value, the version it's written at, and when it expires
+#[derive(Clone, Debug, PartialEq)]
+pub struct Versioned {
+    pub version: u64,
+    /// the value is invisible from this time on, in milliseconds since the UNIX epoch
+    pub expire_at: Option<u64>,
+    pub value: Vec<u8>,
+}
+
+impl Versioned {
+    /// encode into the bytes stored in the `MemStore`
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(MAX_HEADER_SIZE + self.value.len());
+        match self.expire_at {
+            Some(expire_at) => {
+                bytes.extend_from_slice(&(self.version | DEADLINE_FLAG).to_le_bytes());
+                bytes.extend_from_slice(&expire_at.to_le_bytes());
+            }
+            None => bytes.extend_from_slice(&self.version.to_le_bytes()),
+        }
+        bytes.extend_from_slice(&self.value);
+        bytes
+    }
+
+    /// decode the bytes stored in the `MemStore`
+    pub fn decode(mut bytes: Vec<u8>) -> Self {
+        let version = read_u64(&bytes);
+        let (header_size, expire_at) = if version & DEADLINE_FLAG != 0 {
+            (MAX_HEADER_SIZE, Some(read_u64(bytes.get(VERSION_SIZE..).unwrap_or_default())))
+        } else {
+            (VERSION_SIZE, None)
+        };
+        bytes.drain(..header_size.min(bytes.len()));
+        Self {
+            version: version & !DEADLINE_FLAG,
+            expire_at,
+            value: bytes,
+        }
+    }
+
+    /// the version of the value stored as `bytes`, without decoding the rest
+    pub fn version_of(bytes: &[u8]) -> u64 {
+        read_u64(bytes) & !DEADLINE_FLAG
+    }
+
+    /// whether the value has expired at `now`, in milliseconds since the UNIX epoch
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expire_at.map(|it| it <= now).unwrap_or(false)
+    }
+}
+
+/// Gives the versions of the writes
+/// The versions are timestamps in microseconds, unless more than one write happens in a microsecond,
+/// or the clock is behind the versions it has observed, like the ones stored before a restart when the clock went back
+/// They stay below `MAX_VERSION`, which is far in the future unless a version close to it is observed,
+/// then the clock is exhausted and gives no more versions
+#[derive(Clone, Default)]
+pub struct RevisionClock {
+    last: u64,
+}
+
+impl RevisionClock {
+    /// the version of the next write, or an error if the clock is exhausted
+    pub fn next(&mut self) -> io::Result<u64> {
+        if self.last + 1 >= MAX_VERSION {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                format!("the revision clock is exhausted at {}, no more versions can be given", self.last),
+            ));
+        }
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|it| it.as_micros() as u64)
+            .unwrap_or(0);
+        self.last = (self.last + 1).max(now.min(MAX_VERSION - 1));
+        Ok(self.last)
+    }
+
+    /// the version of the last write
+    pub fn last(&self) -> u64 {
+        self.last
+    }
+
+    /// make sure the next version is larger than `version`
+    /// return an error if it's not below `MAX_VERSION`, which the clock never gives
+    pub fn observe(&mut self, version: u64) -> io::Result<()> {
+        if version >= MAX_VERSION {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, format!("version {} is out of range", version)));
+        }
+        self.last = self.last.max(version);
+        Ok(())
+    }
+}
+
+#[test]
+fn test_versioned() {
+    let versioned = Versioned { version: 42, expire_at: None, value: b"value".to_vec() };
+    let encoded = versioned.encode();
+    assert_eq!(encoded.len(), VERSION_SIZE + 5);
+    assert_eq!(Versioned::decode(encoded), versioned);
+    assert!(!versioned.is_expired(u64::MAX));
+    let versioned = Versioned { version: 42, expire_at: Some(1000), value: vec![] };
+    let encoded = versioned.encode();
+    assert_eq!(encoded.len(), MAX_HEADER_SIZE);
+    assert_eq!(Versioned::decode(encoded), versioned);
+    assert!(!versioned.is_expired(999));
+    assert!(versioned.is_expired(1000));
+
+    let mut clock = RevisionClock::default();
+    let first = clock.next().unwrap();
+    assert!(first > 0);
+    assert!(clock.next().unwrap() > first);
+    clock.observe(MAX_VERSION - 2).unwrap();
+    let last = clock.next().unwrap();
+    assert_eq!(last, MAX_VERSION - 1);
+    // the version never runs into the deadline flag, and never repeats
+    assert!(clock.next().is_err());
+    assert_eq!(clock.last(), last);
+    assert!(clock.observe(MAX_VERSION).is_err());
+    assert!(clock.observe(u64::MAX).is_err());
+    let versioned = Versioned { version: MAX_VERSION, expire_at: None, value: vec![] };
+    assert_eq!(Versioned::version_of(&versioned.encode()), MAX_VERSION);
+    assert_eq!(Versioned::decode(versioned.encode()), versioned);
+}
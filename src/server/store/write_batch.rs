@@ -0,0 +1,100 @@
+//! A batch of puts and deletes written into the redo log as one record,
+//! so after a crash either all or none of them are redone
+
+use crate::store::mem_store::MemStore;
+
+/// One write in a `WriteBatch`
+#[derive(Clone, Debug, PartialEq)]
+pub enum WriteOp {
+    Put(Vec<u8>, Vec<u8>),
+    Delete(Vec<u8>),
+}
+
+impl WriteOp {
+    pub fn key(&self) -> &[u8] {
+        match self {
+            WriteOp::Put(key, _) | WriteOp::Delete(key) => key,
+        }
+    }
+}
+
+/// Writes applied to the store in order and atomically
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WriteBatch {
+    ops: Vec<WriteOp>,
+}
+
+impl WriteBatch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn put(&mut self, key: Vec<u8>, value: Vec<u8>) -> &mut Self {
+        self.ops.push(WriteOp::Put(key, value));
+        self
+    }
+
+    pub fn delete(&mut self, key: Vec<u8>) -> &mut Self {
+        self.ops.push(WriteOp::Delete(key));
+        self
+    }
+
+    pub fn ops(&self) -> &[WriteOp] {
+        &self.ops
+    }
+
+    pub fn into_ops(self) -> Vec<WriteOp> {
+        self.ops
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    /// size of the batch in the redo log, it must be at most `MAX_ENTRY_SIZE`
+    pub fn encoded_size(&self) -> usize {
+        self.ops.iter()
+            .map(|op| match op {
+                WriteOp::Put(key, value) => 9 + key.len() + value.len(),
+                WriteOp::Delete(key) => 9 + key.len(),
+            })
+            .sum()
+    }
+
+    /// apply all the writes on `store` in order
+    pub fn apply(self, store: &mut dyn MemStore) {
+        for op in self.ops {
+            match op {
+                WriteOp::Put(key, value) => store.put(key, value),
+                WriteOp::Delete(key) => {
+                    // the key is deleted even if the value it had can't be read, which isn't needed here
+                    let _ = store.delete(&key);
+                }
+            }
+        }
+    }
+}
+
+impl From<Vec<WriteOp>> for WriteBatch {
+    fn from(ops: Vec<WriteOp>) -> Self {
+        Self { ops }
+    }
+}
+
+#[test]
+fn test_write_batch() {
+    use std::collections::HashMap;
+
+    let mut store = HashMap::new();
+    store.insert(b"a".to_vec(), b"0".to_vec());
+    let mut batch = WriteBatch::new();
+    batch.put(b"b".to_vec(), b"1".to_vec())
+        .delete(b"a".to_vec())
+        .put(b"a".to_vec(), b"2".to_vec())
+        .delete(b"b".to_vec());
+    assert_eq!(batch.ops().len(), 4);
+    assert_eq!(batch.encoded_size(), 4 * 9 + 4 + 2);
+    batch.apply(&mut store);
+    assert_eq!(store.len(), 1);
+    assert_eq!(store[&b"a".to_vec()], b"2".to_vec());
+}
@@ -1,3 +1,4 @@
 //! Shared tools across server and client
 
 pub mod bytes;
+pub mod crc32c;
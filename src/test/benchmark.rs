@@ -2,7 +2,7 @@ use std::collections::HashSet;
 use rand::seq::IteratorRandom;
 use std::sync::atomic::AtomicU64;
 use std::sync::atomic::Ordering;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use std::sync::Arc;
 use std::sync::mpsc::channel;
 use grpcio::{EnvBuilder, ChannelBuilder};
@@ -12,8 +12,57 @@ use rand::Rng;
 use std::{thread, io};
 use std::io::Read;
 
+fn connect() -> MiniKvServerClient {
+    let env = Arc::new(EnvBuilder::new().build());
+    let address = "localhost:5884".to_string();
+    let ch = ChannelBuilder::new(env).connect(&address);
+    MiniKvServerClient::new(ch)
+}
+
+/// put with `concurrency` threads for `duration`, return the QPS
+/// every thread waits for its put, so at most `concurrency` puts can be group committed together
+fn put_pressure(concurrency: usize, duration: Duration) -> u64 {
+    let query_count = Arc::new(AtomicU64::new(0));
+    let start_time = Instant::now();
+    let handles: Vec<_> = (0..concurrency)
+        .map(|_| {
+            let query_count = query_count.clone();
+            thread::spawn(move || {
+                let mut rng = rand::thread_rng();
+                let client = connect();
+                let mut value = [0u8; 256];
+                while start_time.elapsed() < duration {
+                    let mut put_request = PutRequest::new();
+                    let key: [u8; 8] = rng.gen();
+                    rng.fill(&mut value);
+                    put_request.set_key(key.to_vec());
+                    put_request.set_value(value.to_vec());
+                    client.put(&put_request).unwrap();
+                    query_count.fetch_add(1, Ordering::Relaxed);
+                }
+            })
+        })
+        .collect();
+    handles.into_iter().for_each(|it| it.join().unwrap());
+    query_count.load(Ordering::Relaxed) * 1000 / start_time.elapsed().as_millis() as u64
+}
+
+/// show the put throughput against the max size of a group commit batch
+fn group_commit() {
+    println!("concurrency(max batch size)\tQPS");
+    for concurrency in [1, 2, 4, 8, 16, 32, 64].iter() {
+        let qps = put_pressure(*concurrency, Duration::from_secs(10));
+        println!("{}\t{}", concurrency, qps);
+    }
+}
+
 /// simulate pressure for the server
+/// run with argument `group-commit` to benchmark group commit instead
 fn main() {
+    if std::env::args().nth(1).as_deref() == Some("group-commit") {
+        group_commit();
+        return;
+    }
     // assume disturb of request
     let thread = 3;
     let put = 0.4;
@@ -31,10 +80,7 @@ fn main() {
         thread::spawn(move || {
             let mut already_putted = HashSet::new();
             let mut rng = rand::thread_rng();
-            let env = Arc::new(EnvBuilder::new().build());
-            let address = "localhost:5884".to_string();
-            let ch = ChannelBuilder::new(env).connect(&address);
-            let client = MiniKvServerClient::new(ch);
+            let client = connect();
             let mut key = [0u8; 8];
             let mut value = [0u8; 256];
             // fill a few values to prevent first few gets got nothing
@@ -85,9 +131,8 @@ fn main() {
                     client.delete(&delete_request).unwrap();
                     already_putted.remove(&key);
                 } else {
-                    let scan_at = rng.gen_range(0, already_putted.len());
-                    let mut scan_request = ScanRequest::new();
-                    scan_request.set_cursor(scan_at as _);
+                    // scan the first page, the cursors of the other pages come from the responses
+                    let scan_request = ScanRequest::new();
                     client.scan(&scan_request).unwrap();
                 }
             }
@@ -20,13 +20,12 @@ fn test_server() {
         .map(|it| it.split('\n'))
         .flatten()
         .map(|it| it.trim())
-        .map(|it| it.trim_matches('\u{0}'))
         .map(|it| if it.starts_with('(') {
             &it[5..]
         } else {
             it
         })
-        .filter(|it| it != &"" && !it.starts_with("waring"))
+        .filter(|it| it != &"")
         .collect();
     assert_eq!(result[0], "cursor: 0");
     assert!([&result[1], &result[2], &result[3]].contains(&&&"a"));